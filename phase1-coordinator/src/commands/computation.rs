@@ -204,7 +204,7 @@ mod tests {
             debug!("Initializing test chunk {}", chunk_id);
 
             // Run initialization on chunk.
-            Initialization::run(&TEST_ENVIRONMENT_3, &mut storage, round_height, chunk_id).unwrap();
+            Initialization::run(&TEST_ENVIRONMENT_3, &mut storage, round_height, chunk_id, false).unwrap();
         }
 
         // Generate a new challenge for the given parameters.
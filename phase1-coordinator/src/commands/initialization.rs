@@ -4,29 +4,92 @@ use crate::{
     CoordinatorError,
 };
 use phase1::{helpers::CurveKind, Phase1, Phase1Parameters};
-use setup_utils::{blank_hash, calculate_hash, UseCompression};
+use setup_utils::{blank_hash, UseCompression};
 use snarkvm_curves::{bls12_377::Bls12_377, bw6_761::BW6_761};
 
 use snarkvm_curves::PairingEngine as Engine;
 
 use std::{io::Write, time::Instant};
-use tracing::{debug, error, info, trace};
+use tracing::{debug, error, info, trace, warn};
 
 pub(crate) struct Initialization;
 
+/// A summary of the initialization of a single chunk.
+#[derive(Debug, Clone)]
+pub struct InitializationSummary {
+    /// The round height of the chunk.
+    round_height: u64,
+    /// The ID of the chunk.
+    chunk_id: u64,
+    /// The curve of the chunk parameters.
+    curve: CurveKind,
+    /// The expected size of the challenge file in bytes.
+    expected_challenge_size: u64,
+    /// The size in bytes required in storage to initialize the chunk.
+    required_space: u64,
+    /// The size in bytes available in storage prior to initialization.
+    available_space: u64,
+    /// The challenge hash of the chunk, or `None` if this was a dry run.
+    contribution_hash: Option<Vec<u8>>,
+}
+
+impl InitializationSummary {
+    /// Returns the round height of the chunk.
+    pub fn round_height(&self) -> u64 {
+        self.round_height
+    }
+
+    /// Returns the ID of the chunk.
+    pub fn chunk_id(&self) -> u64 {
+        self.chunk_id
+    }
+
+    /// Returns the curve of the chunk parameters.
+    pub fn curve(&self) -> CurveKind {
+        self.curve
+    }
+
+    /// Returns the expected size of the challenge file in bytes.
+    pub fn expected_challenge_size(&self) -> u64 {
+        self.expected_challenge_size
+    }
+
+    /// Returns the size in bytes required in storage to initialize the chunk.
+    pub fn required_space(&self) -> u64 {
+        self.required_space
+    }
+
+    /// Returns the size in bytes available in storage prior to initialization.
+    pub fn available_space(&self) -> u64 {
+        self.available_space
+    }
+
+    /// Returns the challenge hash of the chunk, or `None` if this was a dry run.
+    pub fn contribution_hash(&self) -> Option<&[u8]> {
+        self.contribution_hash.as_deref()
+    }
+}
+
 impl Initialization {
     ///
     /// Runs chunk initialization for a given environment, round height, and chunk ID.
     ///
     /// Executes the round initialization on a given chunk ID.
     ///
-    #[inline]
+    /// If `dry_run` is `true`, this function only computes and logs the plan for
+    /// the chunk, without generating or writing any parameters to storage.
+    ///
+    #[tracing::instrument(
+        skip(environment, storage, round_height, chunk_id, dry_run),
+        fields(round = round_height, chunk = chunk_id)
+    )]
     pub(crate) fn run(
         environment: &Environment,
         storage: &mut Disk,
         round_height: u64,
         chunk_id: u64,
-    ) -> anyhow::Result<Vec<u8>> {
+        dry_run: bool,
+    ) -> anyhow::Result<InitializationSummary> {
         info!("Starting initialization on round {} chunk {}", round_height, chunk_id);
         let start = Instant::now();
 
@@ -34,12 +97,49 @@ impl Initialization {
         let expected_challenge_size = Object::contribution_file_size(environment, chunk_id, true);
         trace!("Expected challenge file size is {}", expected_challenge_size);
 
+        // Determine the storage space required, as the challenge is copied to the next round.
+        let required_space = 2 * expected_challenge_size;
+        let available_space = storage.available_space()?;
+        trace!(
+            "Storage requires {} bytes and has {} bytes available",
+            required_space,
+            available_space
+        );
+
+        let settings = environment.parameters();
+
+        let mut summary = InitializationSummary {
+            round_height,
+            chunk_id,
+            curve: settings.curve(),
+            expected_challenge_size,
+            required_space,
+            available_space,
+            contribution_hash: None,
+        };
+
+        if dry_run {
+            info!(
+                "Dry run of initialization on round {} chunk {} with curve {:?} and a challenge of {} bytes",
+                round_height, chunk_id, summary.curve, expected_challenge_size
+            );
+            if available_space < required_space {
+                warn!(
+                    "Initialization on chunk {} requires {} bytes of storage but only {} bytes are available",
+                    chunk_id, required_space, available_space
+                );
+            }
+            return Ok(summary);
+        }
+
+        // Check that storage has enough space for the challenge and its copy before writing begins.
+        Self::check_available_space(std::slice::from_ref(&summary), available_space)?;
+
         // Initialize and fetch a writer for the contribution locator so the output is saved.
         let contribution_locator = Locator::ContributionFile(ContributionLocator::new(round_height, chunk_id, 0, true));
         storage.initialize(contribution_locator.clone(), expected_challenge_size as u64)?;
 
         // Run ceremony initialization on chunk.
-        let settings = environment.parameters();
 
         if let Err(error) = match settings.curve() {
             CurveKind::Bls12_377 => Self::initialization(
@@ -57,21 +157,44 @@ impl Initialization {
             return Err(CoordinatorError::InitializationFailed.into());
         }
 
-        // Copy the current transcript to the next transcript.
-        // This operation will *overwrite* the contents of `next_transcript`.
+        // Copy the current transcript to the next transcript, and use the
+        // hash of the bytes written by the copy as the contribution hash.
         let next_contribution_locator =
             Locator::ContributionFile(ContributionLocator::new(round_height + 1, chunk_id, 0, true));
-        if let Err(error) = storage.copy(&contribution_locator, &next_contribution_locator) {
-            return Err(error.into());
-        }
-
-        // Check that the current and next contribution hash match.
-        let hash = Self::check_hash(storage, &contribution_locator, &next_contribution_locator)?;
+        let hash = storage.copy(&contribution_locator, &next_contribution_locator)?;
         debug!("The challenge hash of Chunk {} is {}", chunk_id, pretty_hash!(&hash));
 
         let elapsed = Instant::now().duration_since(start);
         info!("Completed initialization on chunk {} in {:?}", chunk_id, elapsed);
-        Ok(hash)
+
+        summary.contribution_hash = Some(hash);
+        Ok(summary)
+    }
+
+    ///
+    /// Checks that the given available space in storage is enough to
+    /// initialize all of the chunks of the given summaries.
+    ///
+    /// On failure, this function returns `CoordinatorError::InsufficientDiskSpace`.
+    ///
+    pub(crate) fn check_available_space(
+        summaries: &[InitializationSummary],
+        available_space: u64,
+    ) -> Result<(), CoordinatorError> {
+        let required_space: u64 = summaries.iter().map(|summary| summary.required_space).sum();
+        if available_space < required_space {
+            error!(
+                "Initialization of {} chunks requires {} bytes of storage but only {} bytes are available",
+                summaries.len(),
+                required_space,
+                available_space
+            );
+            return Err(CoordinatorError::InsufficientDiskSpace {
+                needed: required_space,
+                available: available_space,
+            });
+        }
+        Ok(())
     }
 
     /// Runs Phase 1 initialization on the given parameters.
@@ -96,36 +219,20 @@ impl Initialization {
 
         Ok(())
     }
-
-    /// Compute both contribution hashes and check for equivalence.
-    #[inline]
-    fn check_hash(
-        storage: &Disk,
-        contribution_locator: &Locator,
-        next_contribution_locator: &Locator,
-    ) -> anyhow::Result<Vec<u8>> {
-        let current = storage.reader(contribution_locator)?;
-        let next = storage.reader(next_contribution_locator)?;
-
-        // Compare the contribution hashes of both files to ensure the copy succeeded.
-        let contribution_hash_0 = calculate_hash(current.as_ref());
-        let contribution_hash_1 = calculate_hash(next.as_ref());
-        if contribution_hash_0 != contribution_hash_1 {
-            return Err(CoordinatorError::InitializationTranscriptsDiffer.into());
-        }
-
-        Ok(contribution_hash_1.to_vec())
-    }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::{
         commands::Initialization,
-        storage::{ContributionLocator, Locator, StorageObject},
+        environment::{Environment, Parameters, Testing},
+        storage::{ContributionLocator, Locator, Object, StorageObject},
         testing::prelude::*,
+        CoordinatorError,
     };
-    use setup_utils::{blank_hash, calculate_hash, GenericArray};
+    use phase1::{helpers::CurveKind, Phase1};
+    use setup_utils::{blank_hash, calculate_hash, CheckForCorrectness, GenericArray, UseCompression};
+    use snarkvm_curves::bls12_377::Bls12_377;
 
     use tracing::{debug, trace};
 
@@ -150,7 +257,8 @@ mod tests {
             debug!("Initializing test chunk {}", chunk_id);
 
             // Execute the ceremony initialization
-            let candidate_hash = Initialization::run(&TEST_ENVIRONMENT, &mut storage, round_height, chunk_id).unwrap();
+            let summary = Initialization::run(&TEST_ENVIRONMENT, &mut storage, round_height, chunk_id, false).unwrap();
+            let candidate_hash = summary.contribution_hash().unwrap().to_vec();
 
             // Open the contribution locator file.
             let locator = Locator::ContributionFile(ContributionLocator::new(round_height, chunk_id, 0, true));
@@ -184,4 +292,93 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    #[serial]
+    fn test_initialization_dry_run() {
+        initialize_test_environment(&TEST_ENVIRONMENT);
+
+        // Define test storage.
+        let mut storage = test_storage(&TEST_ENVIRONMENT);
+
+        let round_height = 0;
+        for chunk_id in 0..TEST_ENVIRONMENT.number_of_chunks() {
+            let summary = Initialization::run(&TEST_ENVIRONMENT, &mut storage, round_height, chunk_id, true).unwrap();
+
+            // Check that the plan matches the expected challenge size.
+            let expected_challenge_size = Object::contribution_file_size(&TEST_ENVIRONMENT, chunk_id, true);
+            assert_eq!(expected_challenge_size, summary.expected_challenge_size());
+            assert_eq!(2 * expected_challenge_size, summary.required_space());
+            assert!(summary.contribution_hash().is_none());
+
+            // Check that nothing was written to storage.
+            let locator = Locator::ContributionFile(ContributionLocator::new(round_height, chunk_id, 0, true));
+            assert!(!storage.exists(&locator));
+            let locator = Locator::ContributionFile(ContributionLocator::new(round_height + 1, chunk_id, 0, true));
+            assert!(!storage.exists(&locator));
+        }
+    }
+
+    #[test]
+    #[serial]
+    fn test_initialization_insufficient_space() {
+        initialize_test_environment(&TEST_ENVIRONMENT);
+
+        // Define test storage.
+        let mut storage = test_storage(&TEST_ENVIRONMENT);
+
+        let summaries: Vec<_> = (0..TEST_ENVIRONMENT.number_of_chunks())
+            .map(|chunk_id| Initialization::run(&TEST_ENVIRONMENT, &mut storage, 0, chunk_id, true).unwrap())
+            .collect();
+        let required_space: u64 = summaries.iter().map(|summary| summary.required_space()).sum();
+
+        // Check that the space for every chunk is required, and not only for one of them.
+        Initialization::check_available_space(&summaries, required_space).unwrap();
+        match Initialization::check_available_space(&summaries, required_space - 1) {
+            Err(CoordinatorError::InsufficientDiskSpace { needed, available }) => {
+                assert_eq!(required_space, needed);
+                assert_eq!(required_space - 1, available);
+            }
+            result => panic!("Expected InsufficientDiskSpace, found {:?}", result),
+        }
+        assert!(Initialization::check_available_space(&summaries[..1], required_space - 1).is_ok());
+    }
+
+    #[test]
+    #[serial]
+    fn test_initialization_compressed_round_trip() {
+        let environment: Environment = Testing::from(Parameters::Test8Chunks)
+            .compressed_inputs(UseCompression::Yes)
+            .into();
+        initialize_test_environment(&environment);
+
+        // Define test storage.
+        let mut storage = test_storage(&environment);
+
+        let (round_height, chunk_id) = (0, 0);
+        let summary = Initialization::run(&environment, &mut storage, round_height, chunk_id, false).unwrap();
+
+        // Check that the compressed challenge has the expected size, which is smaller than uncompressed.
+        let locator = Locator::ContributionFile(ContributionLocator::new(round_height, chunk_id, 0, true));
+        let compressed = storage.reader(&locator).unwrap().as_ref().to_vec();
+        assert_eq!(summary.expected_challenge_size(), compressed.len() as u64);
+        assert_eq!(
+            Object::contribution_file_size(&environment, chunk_id, true),
+            compressed.len() as u64
+        );
+        let uncompressed_environment: Environment = Testing::from(Parameters::Test8Chunks).into();
+        assert!(compressed.len() as u64 < Object::contribution_file_size(&uncompressed_environment, chunk_id, true));
+
+        // Check that the challenge decompresses to the uncompressed initialization output.
+        let settings = environment.parameters();
+        assert!(matches!(settings.curve(), CurveKind::Bls12_377));
+        let parameters = phase1_chunked_parameters!(Bls12_377, settings, chunk_id);
+        let mut uncompressed = vec![0u8; parameters.accumulator_size];
+        Initialization::initialization(&mut uncompressed, UseCompression::No, &parameters).unwrap();
+
+        let mut decompressed = vec![0u8; parameters.accumulator_size];
+        decompressed[..64].copy_from_slice(&compressed[..64]);
+        Phase1::decompress(&compressed, &mut decompressed, CheckForCorrectness::Full, &parameters).unwrap();
+        assert_eq!(uncompressed, decompressed);
+    }
 }
@@ -344,14 +344,15 @@ impl Verification {
 
 #[cfg(test)]
 mod tests {
-    use std::sync::Arc;
+    use std::{io::Write, sync::Arc};
 
     use crate::{
         authentication::Dummy,
         commands::{Computation, Seed, Verification, SEED_LENGTH},
-        storage::{ContributionLocator, ContributionSignatureLocator, Locator, Object},
+        storage::{ContributionLocator, ContributionSignatureLocator, Locator, Object, StorageLocator},
         testing::prelude::*,
         Coordinator,
+        CoordinatorError,
     };
 
     use once_cell::sync::Lazy;
@@ -456,4 +457,79 @@ mod tests {
             assert!(storage.exists(&next));
         }
     }
+
+    #[test]
+    #[serial]
+    fn test_verification_rejects_trailing_bytes() {
+        initialize_test_environment(&TEST_ENVIRONMENT_3);
+
+        let mut coordinator = Coordinator::new(TEST_ENVIRONMENT_3.clone(), Arc::new(Dummy)).unwrap();
+
+        let contributor = Lazy::force(&TEST_CONTRIBUTOR_ID).clone();
+        let contributor_signing_key = "secret_key".to_string();
+        let verifier_signing_key = "secret_key".to_string();
+
+        // Initialize the ceremony to round 1.
+        coordinator.run_initialization(OffsetDateTime::now_utc()).unwrap();
+        coordinator.next_round(*TEST_STARTED_AT, vec![contributor]).unwrap();
+
+        let round_height = coordinator.current_round_height().unwrap();
+        let chunk_id = 0;
+
+        let challenge_locator = &Locator::ContributionFile(ContributionLocator::new(round_height, chunk_id, 0, true));
+        let response_locator = &Locator::ContributionFile(ContributionLocator::new(round_height, chunk_id, 1, false));
+        let contribution_file_signature_locator =
+            &Locator::ContributionFileSignature(ContributionSignatureLocator::new(round_height, chunk_id, 1, false));
+
+        let signature = coordinator.signature();
+        let storage = coordinator.storage_mut();
+
+        let expected_filesize = Object::contribution_file_size(&TEST_ENVIRONMENT_3, chunk_id, false);
+        storage.initialize(response_locator.clone(), expected_filesize).unwrap();
+        let expected_filesize = Object::contribution_file_signature_size(false);
+        storage
+            .initialize(contribution_file_signature_locator.clone(), expected_filesize)
+            .unwrap();
+
+        // Run computation on the chunk.
+        let mut seed: Seed = [0; SEED_LENGTH];
+        rand::thread_rng().fill_bytes(&mut seed[..]);
+        Computation::run(
+            &TEST_ENVIRONMENT_3,
+            storage,
+            signature.clone(),
+            &contributor_signing_key,
+            challenge_locator,
+            response_locator,
+            contribution_file_signature_locator,
+            &seed,
+        )
+        .unwrap();
+
+        // Append junk bytes to the otherwise valid response file.
+        std::fs::OpenOptions::new()
+            .append(true)
+            .open(storage.to_path(response_locator).unwrap())
+            .unwrap()
+            .write_all(&[0u8; 32])
+            .unwrap();
+
+        // Check that verification rejects the response file.
+        let result = Verification::run(
+            &TEST_ENVIRONMENT_3,
+            storage,
+            signature,
+            &verifier_signing_key,
+            round_height,
+            chunk_id,
+            1,
+            true,
+        );
+        assert!(matches!(result, Err(CoordinatorError::ContributionFileSizeMismatch)));
+
+        // Check that no next challenge file was written.
+        let next_challenge_locator =
+            Locator::ContributionFile(ContributionLocator::new(round_height + 1, chunk_id, 0, true));
+        assert!(!storage.exists(&next_challenge_locator));
+    }
 }
@@ -4,7 +4,7 @@
 
 use crate::{
     authentication::Signature,
-    commands::{Aggregation, Initialization},
+    commands::{Aggregation, Initialization, InitializationSummary},
     coordinator_state::{
         CeremonyStorageAction,
         CoordinatorState,
@@ -13,8 +13,17 @@ use crate::{
         ResetCurrentRoundStorageAction,
         RoundMetrics,
     },
-    environment::{Deployment, Environment},
-    objects::{participant::*, task::TaskInitializationError, ContributionFileSignature, LockedLocators, Round, Task},
+    environment::{Deployment, Environment, ParticipantAccess},
+    objects::{
+        participant::*,
+        task::TaskInitializationError,
+        ContributionFileSignature,
+        LockedLocators,
+        MerkleProof,
+        MerkleTree,
+        Round,
+        Task,
+    },
     storage::{
         ContributionLocator,
         ContributionSignatureLocator,
@@ -28,22 +37,25 @@ use crate::{
         UpdateAction,
     },
 };
+use serde::{Deserialize, Serialize};
+use setup1_shared::reliability::{ReliabilityCheckResult, ReliabilityReport};
 use setup_utils::calculate_hash;
 
 use std::{
+    collections::HashMap,
     fmt,
     net::IpAddr,
+    path::{Path, PathBuf},
     sync::{Arc, RwLock},
 };
-use time::OffsetDateTime;
+use time::{Duration, OffsetDateTime};
 use tracing::*;
 
-#[cfg(any(test, feature = "operator"))]
-use std::collections::HashMap;
-
 #[derive(Debug)]
 pub enum CoordinatorError {
     AggregateContributionFileSizeMismatch,
+    AlreadyContributing,
+    ChallengeCorrupt { size: u64, expected: u64 },
     ChallengeHashSizeInvalid,
     ChunkAlreadyComplete,
     ChunkAlreadyVerified,
@@ -63,6 +75,7 @@ pub enum CoordinatorError {
     ContributionAlreadyAssignedVerifiedLocator,
     ContributionAlreadyAssignedVerifier,
     ContributionAlreadyVerified,
+    ContributionDeadlineExceeded,
     ContributionFailed,
     ContributionFileSignatureLocatorAlreadyExists,
     ContributionFileSizeMismatch,
@@ -79,6 +92,8 @@ pub enum CoordinatorError {
     ContributionMissingVerifier,
     ContributionShouldNotExist,
     ContributionSignatureFileSizeMismatch,
+    ContributionSignatureMissing,
+    ContributionSignatureResponseMismatch,
     ContributionSignatureSizeMismatch,
     ContributionsComplete,
     ContributorAlreadyContributed,
@@ -96,7 +111,7 @@ pub enum CoordinatorError {
     ExpectedVerifier,
     Error(anyhow::Error),
     InitializationFailed,
-    InitializationTranscriptsDiffer,
+    InsufficientDiskSpace { needed: u64, available: u64 },
     Integer(std::num::ParseIntError),
     IOError(std::io::Error),
     Hex(hex::FromHexError),
@@ -115,6 +130,7 @@ pub enum CoordinatorError {
     NextChallengeHashMissing,
     NextRoundAlreadyInPrecommit,
     NextRoundShouldBeEmpty,
+    NoPendingVerifications,
     NumberOfChunksInvalid,
     NumberOfContributionsDiffer,
     ParticipantAlreadyAdded,
@@ -137,11 +153,12 @@ pub enum CoordinatorError {
     ParticipantHasNotStarted,
     ParticipantHasNoRemainingTasks,
     ParticipantHasRemainingTasks,
-    ParticipantInCurrentRoundCannotJoinQueue,
+    ParticipantInQueue,
     ParticipantLockedChunkWithManyContributions,
     ParticipantMissing,
     ParticipantMissingDisposingTask,
     ParticipantMissingPendingTask { pending_task: Task },
+    ParticipantNotAllowed,
     ParticipantNotFound(Participant),
     ParticipantNotReady,
     ParticipantRoundHeightInvalid,
@@ -155,10 +172,14 @@ pub enum CoordinatorError {
     ParticipantUnauthorized,
     ParticipantUnauthorizedForChunkId { chunk_id: u64 },
     ParticipantWasDropped,
+    Paused,
     PendingTasksMustContainResponseTask { response_task: Task },
     Phase1Setup(setup_utils::Error),
+    QueueFull,
     QueueIsEmpty,
     QueueWaitTimeIncomplete,
+    ReadOnlyMode,
+    ReliabilityCheckRequired,
     ResponseHashSizeInvalid,
     RoundAggregationFailed,
     RoundAlreadyInitialized,
@@ -188,8 +209,13 @@ pub enum CoordinatorError {
     RoundVerifiersMissing,
     RoundVerifiersNotUnique,
     SignatureSchemeIsInsecure,
+    SignedTimeInvalid,
+    SignedTimeNonceMismatch,
+    SnapshotInvalid,
     StorageCopyFailed,
+    StorageCopyTruncated { expected: u64, found: u64 },
     StorageFailed,
+    StorageInconsistent,
     StorageInitializationFailed,
     StorageLocatorAlreadyExists,
     StorageLocatorAlreadyExistsAndOpen,
@@ -201,11 +227,14 @@ pub enum CoordinatorError {
     StorageSizeLookupFailed,
     StorageUpdateFailed,
     TaskInitializationFailed(TaskInitializationError),
+    TaskNotAssigned(Task),
     PreviousContributionMissing { current_task: Task },
     TryFromSliceError(std::array::TryFromSliceError),
     UnauthorizedChunkContributor,
     UnauthorizedChunkVerifier,
+    UploadOffsetMismatch { offset: u64, expected: u64 },
     VerificationFailed,
+    VerificationNotClaimed,
     VerificationOnContributionIdZero,
     VerifierMissing,
     VerifierSignatureInvalid,
@@ -260,6 +289,36 @@ impl From<std::num::ParseIntError> for CoordinatorError {
     }
 }
 
+impl CoordinatorError {
+    ///
+    /// Returns the time after which a request that failed with this error
+    /// may succeed if retried, if the error is temporary.
+    ///
+    /// Temporary errors are caused by contention with other participants or
+    /// by the state of the round, and clear up without any change from the
+    /// participant. All other errors return `None`, and retrying the same
+    /// request is expected to fail again.
+    ///
+    pub fn retry_after(&self) -> Option<std::time::Duration> {
+        match self {
+            CoordinatorError::ChunkLockAlreadyAcquired | CoordinatorError::PreviousContributionMissing { .. } => {
+                Some(std::time::Duration::from_secs(5))
+            }
+            CoordinatorError::CurrentRoundAggregating | CoordinatorError::Paused => {
+                Some(std::time::Duration::from_secs(30))
+            }
+            _ => None,
+        }
+    }
+
+    ///
+    /// Returns `true` if the error is temporary, and the request may be retried.
+    ///
+    pub fn is_temporary(&self) -> bool {
+        self.retry_after().is_some()
+    }
+}
+
 impl fmt::Display for CoordinatorError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         error!("{}", self);
@@ -333,6 +392,198 @@ impl TimeSource for MockTimeSource {
     }
 }
 
+/// The liveness of the coordinator, as reported by [Coordinator::health].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HealthStatus {
+    /// The coordinator can read its current state.
+    Ok,
+    /// The coordinator is unable to read its current state from storage.
+    Unavailable,
+}
+
+/// A lightweight snapshot of the coordinator, for load balancers
+/// and uptime monitors.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CoordinatorHealth {
+    pub status: HealthStatus,
+    pub round_height: Option<u64>,
+    pub queue_len: usize,
+    pub uptime_seconds: i64,
+    pub verification_threads: usize,
+}
+
+/// The status of a chunk in the current round, as reported by [Coordinator::current_round_chunks].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChunkStatus {
+    pub chunk_id: u64,
+    pub current_contribution_id: u64,
+    pub is_verified: bool,
+}
+
+/// The structure of the current round, for contributors and dashboards.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RoundChunks {
+    pub round_height: u64,
+    pub number_of_chunks: u64,
+    pub chunks: Vec<ChunkStatus>,
+}
+
+/// The headline state of the current round, as reported by [Coordinator::current_round_summary].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RoundSummary {
+    pub round_height: u64,
+    /// The number of contributors in the round, including the finished ones.
+    pub number_of_contributors: usize,
+    pub number_of_finished_contributors: usize,
+    /// The number of finished contributors that count towards the minimum,
+    /// which excludes the replacement contributors of the coordinator.
+    pub number_of_qualifying_contributors: usize,
+    /// The minimum number of qualifying contributors to finalize the round.
+    pub minimum_contributors: usize,
+    pub number_of_pending_verifications: usize,
+    /// The setting for whether every contribution of the round is in and verified.
+    pub is_finished: bool,
+    /// The setting for whether the round has been aggregated, and may be finalized.
+    pub is_aggregated: bool,
+}
+
+/// The place of a contributor in the queue, as of their admission to it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueuePosition {
+    /// The position of the contributor in the queue, starting from 1.
+    pub position: u64,
+    /// The number of contributors in the queue.
+    pub queue_size: u64,
+}
+
+/// The current time of the coordinator, signed with the coordinator signing key.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignedTime {
+    /// The nonce sent by the client, so that an older response cannot be replayed.
+    pub nonce: String,
+    /// The current Unix time of the coordinator, in seconds.
+    pub unix_time: i64,
+    /// The signature over the nonce followed by the decimal string of the Unix time.
+    pub signature: String,
+}
+
+impl SignedTime {
+    /// Returns the signed message, which is the nonce followed by the decimal string of the Unix time.
+    pub fn message(nonce: &str, unix_time: i64) -> String {
+        format!("{}{}", nonce, unix_time)
+    }
+
+    ///
+    /// Returns the Unix time of the coordinator, if this answers the given nonce of
+    /// the client and is signed by the given coordinator public key.
+    ///
+    pub fn verify(&self, signature: &dyn Signature, public_key: &str, nonce: &str) -> Result<i64, CoordinatorError> {
+        // Check that this answers the request of the client, rather than an earlier one.
+        if self.nonce != nonce {
+            return Err(CoordinatorError::SignedTimeNonceMismatch);
+        }
+
+        // The message is signed as a response body, as in [Coordinator::sign_response].
+        let message = hex::encode(calculate_hash(Self::message(nonce, self.unix_time).as_bytes()));
+        if !signature.verify(public_key, &message, &self.signature) {
+            return Err(CoordinatorError::SignedTimeInvalid);
+        }
+
+        Ok(self.unix_time)
+    }
+}
+
+/// The latest reliability report of a contributor, as recorded by the coordinator.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReliabilityStatus {
+    /// The percentage of the reliability checks which have passed.
+    pub score: u8,
+    /// The time at which the report was recorded.
+    pub reported_at: OffsetDateTime,
+    /// The setting for whether the report admits the contributor to the queue.
+    pub passed: bool,
+    /// The measurements of each kind of reliability check.
+    pub results: Vec<ReliabilityCheckResult>,
+}
+
+/// An event in the lifecycle of a participant, as recorded in the coordinator state.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum ParticipantEvent {
+    /// The participant joined the queue.
+    JoinedQueue,
+    /// The participant was selected for a round.
+    #[serde(rename_all = "camelCase")]
+    SelectedForRound { round_height: u64 },
+    /// The participant was assigned their tasks in a round.
+    #[serde(rename_all = "camelCase")]
+    StartedRound { round_height: u64 },
+    /// The participant holds the lock on a chunk.
+    #[serde(rename_all = "camelCase")]
+    LockedChunk { round_height: u64, chunk_id: u64 },
+    /// The contribution of the participant to a chunk was accepted.
+    #[serde(rename_all = "camelCase")]
+    ContributionAccepted { round_height: u64, chunk_id: u64 },
+    /// The contribution of the participant to a chunk was verified.
+    #[serde(rename_all = "camelCase")]
+    VerificationCompleted { round_height: u64, chunk_id: u64 },
+    /// The participant finished their tasks in a round.
+    #[serde(rename_all = "camelCase")]
+    FinishedRound { round_height: u64 },
+    /// The participant was dropped from a round.
+    #[serde(rename_all = "camelCase")]
+    Dropped { round_height: u64 },
+}
+
+/// An event in the timeline of a participant, with the time at which it happened.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimelineEntry {
+    pub time: OffsetDateTime,
+    pub event: ParticipantEvent,
+}
+
+/// An event in the lifecycle of a round, as passed to [Coordinator::set_event_callback].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum CeremonyEvent {
+    /// The ceremony advanced to a new round.
+    #[serde(rename_all = "camelCase")]
+    RoundStarted { round_height: u64 },
+    /// A contributor locked a chunk.
+    #[serde(rename_all = "camelCase")]
+    ChunkLocked {
+        round_height: u64,
+        chunk_id: u64,
+        participant: Participant,
+    },
+    /// A contribution to a chunk was accepted.
+    #[serde(rename_all = "camelCase")]
+    ContributionAccepted {
+        round_height: u64,
+        chunk_id: u64,
+        participant: Participant,
+    },
+    /// A contribution to a chunk was verified.
+    #[serde(rename_all = "camelCase")]
+    VerificationCompleted {
+        round_height: u64,
+        chunk_id: u64,
+        participant: Participant,
+    },
+    /// The contributions of the round were aggregated.
+    #[serde(rename_all = "camelCase")]
+    RoundFinalized { round_height: u64 },
+}
+
 /// A core structure for operating the Phase 1 ceremony. This struct
 /// is designed to be [Send] + [Sync]. The state of the ceremony is
 /// stored in a [CoordinatorState] object.
@@ -349,6 +600,15 @@ pub struct Coordinator {
     time: Arc<dyn TimeSource>,
     /// Callback to call after aggregation is done
     aggregation_callback: Arc<dyn Fn(Vec<Participant>) -> () + Send + Sync>,
+    /// Callback to call on every event in the lifecycle of a round
+    event_callback: Arc<dyn Fn(CeremonyEvent) + Send + Sync>,
+    /// The time at which this coordinator was instantiated.
+    started_at: OffsetDateTime,
+    /// The verified challenges of the current round, by round height and chunk ID.
+    challenges: RwLock<HashMap<(u64, u64), Arc<Vec<u8>>>>,
+    /// The thread pool dedicated to verifications, if the environment sizes one.
+    #[cfg(any(test, feature = "operator"))]
+    verification_pool: Option<rayon::ThreadPool>,
 }
 
 impl Coordinator {
@@ -379,16 +639,43 @@ impl Coordinator {
             _ => return Err(CoordinatorError::StorageFailed),
         };
 
+        // The verifiers may have been replaced since the coordinator was configured,
+        // in which case the verifiers saved with the coordinator state take precedence.
+        let mut environment = environment;
+        environment.set_coordinator_verifiers(state.coordinator_verifiers().clone());
+
         Ok(Self {
             environment: environment.clone(),
             signature,
             storage,
             state,
+            started_at: time.now_utc(),
+            challenges: RwLock::new(HashMap::new()),
             time,
             aggregation_callback: Arc::new(|_| ()),
+            event_callback: Arc::new(|_| ()),
+            #[cfg(any(test, feature = "operator"))]
+            verification_pool: Self::build_verification_pool(&environment)?,
         })
     }
 
+    ///
+    /// Builds the thread pool dedicated to verifications, if the environment sizes one.
+    ///
+    #[cfg(any(test, feature = "operator"))]
+    fn build_verification_pool(environment: &Environment) -> Result<Option<rayon::ThreadPool>, CoordinatorError> {
+        environment
+            .verification_threads()
+            .map(|threads| {
+                rayon::ThreadPoolBuilder::new()
+                    .num_threads(threads)
+                    .thread_name(|index| format!("verification-{}", index))
+                    .build()
+                    .map_err(|error| CoordinatorError::Error(anyhow::anyhow!(error)))
+            })
+            .transpose()
+    }
+
     ///
     /// Set a callback which will be called after the round is aggregated.
     /// Current round finished contributors will be passed to a callback
@@ -397,6 +684,15 @@ impl Coordinator {
     pub fn set_aggregation_callback(&mut self, callback: Arc<dyn Fn(Vec<Participant>) -> () + Send + Sync>) {
         self.aggregation_callback = callback;
     }
+
+    ///
+    /// Set a callback which will be called on every event in the lifecycle
+    /// of a round, such as a chunk being locked or a round being aggregated.
+    /// The callback is called from the coordinator, so it should not block.
+    ///
+    pub fn set_event_callback(&mut self, callback: Arc<dyn Fn(CeremonyEvent) + Send + Sync>) {
+        self.event_callback = callback;
+    }
 }
 
 impl Coordinator {
@@ -417,6 +713,9 @@ impl Coordinator {
         {
             // Check if the ceremony has been initialized yet.
             if Self::load_current_round_height(&self.storage).is_err() {
+                // A read-only coordinator serves a ceremony initialized by another.
+                self.check_writable()?;
+
                 info!("Initializing ceremony");
                 let round_height = self.run_initialization(self.time.now_utc())?;
                 info!("Initialized ceremony");
@@ -431,6 +730,29 @@ impl Coordinator {
         // this call will fail if the ceremony was not initialized.
         let current_round_height = self.current_round_height()?;
 
+        // Check that the current round was created with the number of chunks
+        // that the parameters of this environment call for.
+        let number_of_chunks = self.current_round()?.chunks().len() as u64;
+        if number_of_chunks != self.environment.number_of_chunks() {
+            error!(
+                "Round {} has {} chunks, while the environment expects {} chunks",
+                current_round_height,
+                number_of_chunks,
+                self.environment.number_of_chunks()
+            );
+            return Err(CoordinatorError::NumberOfChunksInvalid);
+        }
+
+        // Check that the storage is consistent before serving any requests.
+        let problems = self.self_check()?;
+        for problem in &problems {
+            warn!("Storage self check - {}", problem);
+        }
+        if !problems.is_empty() && self.environment.strict_self_check() {
+            error!("Storage failed the self check with {} problems", problems.len());
+            return Err(CoordinatorError::StorageInconsistent);
+        }
+
         info!("Current round height is {}", current_round_height);
         info!("{}", serde_json::to_string_pretty(&self.current_round()?)?);
         info!("Coordinator has booted up");
@@ -438,16 +760,145 @@ impl Coordinator {
         Ok(())
     }
 
+    ///
+    /// Checks that the storage is internally consistent, and returns
+    /// a description of every problem found.
+    ///
+    /// Every round up to the current round must be readable, every round
+    /// before the current round must be complete, and every contribution
+    /// file of a verified contribution must exist with the expected size.
+    ///
+    pub fn self_check(&self) -> Result<Vec<String>, CoordinatorError> {
+        let current_round_height = self.current_round_height()?;
+
+        let mut problems = vec![];
+        for round_height in 0..=current_round_height {
+            let round = match Self::load_round(&self.storage, round_height) {
+                Ok(round) => round,
+                Err(error) => {
+                    problems.push(format!("round {} could not be read - {}", round_height, error));
+                    continue;
+                }
+            };
+
+            if round.round_height() != round_height {
+                problems.push(format!(
+                    "round {} is stored with the height {}",
+                    round_height,
+                    round.round_height()
+                ));
+            }
+            if round_height < current_round_height && !round.is_complete() {
+                problems.push(format!("round {} is finalized but not complete", round_height));
+            }
+
+            for chunk in round.chunks() {
+                for contribution in chunk.get_contributions().values().filter(|c| c.is_verified()) {
+                    let paths = [
+                        contribution.get_contributed_location(),
+                        contribution.get_verified_location(),
+                    ];
+                    for path in paths.iter().filter_map(|path| path.as_ref()) {
+                        if let Some(problem) = self.check_contribution_file(path)? {
+                            problems.push(problem);
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(problems)
+    }
+
+    /// Returns a description of the problem with the given contribution file, if any.
+    fn check_contribution_file(&self, path: &LocatorPath) -> Result<Option<String>, CoordinatorError> {
+        let locator = self.storage.to_locator(path)?;
+        if !self.storage.exists(&locator) {
+            return Ok(Some(format!("{} is missing", path)));
+        }
+
+        if let Locator::ContributionFile(contribution_locator) = &locator {
+            let size = self.storage.size(&locator)?;
+            let expected = Object::contribution_file_size(
+                &self.environment,
+                contribution_locator.chunk_id(),
+                contribution_locator.is_verified(),
+            );
+            if size != expected {
+                return Ok(Some(format!("{} has size {} instead of {}", path, size, expected)));
+            }
+        }
+
+        Ok(None)
+    }
+
     /// Save the current state of the coordinator to storage.
     pub fn save_state(&mut self) -> Result<(), CoordinatorError> {
+        self.check_writable()?;
         self.state.save(&mut self.storage)
     }
 
+    ///
+    /// Returns the hex-encoded hash of the previous contribution, which the challenge
+    /// at the given locator begins with, for contributors to check the challenge they
+    /// download against.
+    ///
+    /// The challenge is written by the verification of the response with the same
+    /// contribution ID, so its verifier signed this hash as the response hash. Reading
+    /// it from the signature avoids reading the response. The initial challenge of the
+    /// ceremony has no previous contribution, and a signature that cannot be read is
+    /// logged, as the lock does not depend on the hash. In both cases, this returns `None`.
+    ///
+    fn previous_contribution_hash(&self, challenge_locator: &ContributionLocator) -> Option<String> {
+        let signature_locator = ContributionSignatureLocator::new(
+            challenge_locator.round_height(),
+            challenge_locator.chunk_id(),
+            challenge_locator.contribution_id(),
+            true,
+        );
+        match self.load_contribution_file_signature(&signature_locator) {
+            Ok(signature) => signature.map(|signature| signature.get_response_hash().to_string()),
+            Err(error) => {
+                warn!("Could not read the signature {:?} - {}", signature_locator, error);
+                None
+            }
+        }
+    }
+
+    /// Returns `CoordinatorError::ReadOnlyMode` if the coordinator is a read-only replica.
+    fn check_writable(&self) -> Result<(), CoordinatorError> {
+        match self.environment.read_only() {
+            true => Err(CoordinatorError::ReadOnlyMode),
+            false => Ok(()),
+        }
+    }
+
+    /// Replaces the state of the coordinator with the state saved in storage.
+    fn reload_state(&mut self) -> Result<(), CoordinatorError> {
+        self.state = match self.storage.get(&Locator::CoordinatorState)? {
+            Object::CoordinatorState(state) => state,
+            _ => return Err(CoordinatorError::StorageFailed),
+        };
+
+        // The verifiers may have been replaced by the coordinator that serves the writes,
+        // in which case the verifiers saved with the coordinator state take precedence.
+        self.environment
+            .set_coordinator_verifiers(self.state.coordinator_verifiers().clone());
+        Ok(())
+    }
+
     ///
     /// Runs a set of operations to update the coordinator state to reflect
     /// newly finished, dropped, or banned participants.
     ///
+    /// A read-only coordinator instead reloads the state saved in storage
+    /// by the coordinator that serves the writes.
+    ///
     pub fn update(&mut self) -> Result<(), CoordinatorError> {
+        if self.environment.read_only() {
+            return self.reload_state();
+        }
+
         // Process ceremony updates for the current round and queue.
         let (is_current_round_finished, is_current_round_aggregated) = {
             // Acquire the state write lock.
@@ -491,6 +942,31 @@ impl Coordinator {
         let (is_current_round_aggregated, is_precommit_next_round_ready) = {
             // Check if the coordinator should aggregate, and attempt aggregation.
             if is_current_round_finished && !is_current_round_aggregated {
+                // Check that enough contributors finished the round. Otherwise, keep the round
+                // open until the queue can make up for the missing contributors, then reset the
+                // round to restart it with its contributors and the queue.
+                let number_of_contributors = self.state.current_round_number_of_qualifying_contributors();
+                let minimum_contributors = self.environment.minimum_contributors_per_round();
+                if number_of_contributors < minimum_contributors {
+                    let round_height = self.state.current_round_height();
+                    let number_of_queue_contributors = self.state.number_of_queue_contributors();
+                    if number_of_contributors + number_of_queue_contributors < minimum_contributors {
+                        warn!(
+                            "Round {} is finished by {} contributors, but {} are required to finalize it",
+                            round_height, number_of_contributors, minimum_contributors
+                        );
+                        return Ok(());
+                    }
+
+                    warn!(
+                        "Round {} is finished by {} contributors, but {} are required to finalize it. \
+                        Resetting the round to restart it with the {} contributors in the queue.",
+                        round_height, number_of_contributors, minimum_contributors, number_of_queue_contributors
+                    );
+                    self.reset_round()?;
+                    return Ok(());
+                }
+
                 // Aggregate the current round.
                 self.try_aggregate()?;
 
@@ -506,6 +982,10 @@ impl Coordinator {
                         tracing::error!("Failed to get current round finished contributors: {}", e);
                     }
                 }
+
+                (self.event_callback)(CeremonyEvent::RoundFinalized {
+                    round_height: self.state.current_round_height(),
+                });
             }
 
             // Check if the current round is aggregated, and if the precommit for
@@ -538,6 +1018,9 @@ impl Coordinator {
             let next_round_height = self.try_advance(started_at)?;
 
             info!("Advanced ceremony to round {}", next_round_height);
+            (self.event_callback)(CeremonyEvent::RoundStarted {
+                round_height: next_round_height,
+            });
         }
 
         Ok(())
@@ -549,9 +1032,11 @@ impl Coordinator {
     pub fn shutdown(&mut self) -> anyhow::Result<()> {
         warn!("\n\nATTENTION - Coordinator is shutting down...\n");
 
-        // Save the coordinator state to storage.
-        self.save_state().unwrap();
-        debug!("Coordinator has safely shutdown storage");
+        // Save the coordinator state to storage, unless it is saved by another coordinator.
+        if !self.environment.read_only() {
+            self.save_state().unwrap();
+            debug!("Coordinator has safely shutdown storage");
+        }
 
         // Print the final coordinator self.
         let final_state = serde_json::to_string_pretty(&self.state).unwrap();
@@ -562,6 +1047,123 @@ impl Coordinator {
         Ok(())
     }
 
+    ///
+    /// Returns a snapshot of the liveness of the coordinator.
+    ///
+    /// This is intended to be cheap enough to poll frequently, so it only
+    /// reads the current round height from storage and otherwise uses the
+    /// state held in memory. If storage is unreachable, the status is
+    /// [HealthStatus::Unavailable].
+    ///
+    #[inline]
+    pub fn health(&self) -> CoordinatorHealth {
+        let round_height = self.current_round_height().ok();
+        let status = match round_height {
+            Some(_) => HealthStatus::Ok,
+            None => HealthStatus::Unavailable,
+        };
+
+        CoordinatorHealth {
+            status,
+            round_height,
+            queue_len: self.state.number_of_queue_contributors(),
+            uptime_seconds: (self.time.now_utc() - self.started_at).whole_seconds(),
+            verification_threads: self.verification_threads(),
+        }
+    }
+
+    ///
+    /// Returns the number of threads verifications run on.
+    ///
+    /// This is the size of the pool dedicated to verifications if the
+    /// environment sizes one, and the size of the global pool otherwise.
+    ///
+    #[inline]
+    pub fn verification_threads(&self) -> usize {
+        #[cfg(any(test, feature = "operator"))]
+        let threads = self.verification_pool.as_ref().map(|pool| pool.current_num_threads());
+        #[cfg(not(any(test, feature = "operator")))]
+        let threads = None;
+
+        threads.unwrap_or_else(rayon::current_num_threads)
+    }
+
+    ///
+    /// Takes a point-in-time backup of the ceremony into a new timestamped
+    /// directory in the given directory, and returns the path of the backup.
+    ///
+    /// The backup holds the storage together with the coordinator state held
+    /// in memory. It only needs shared access to the coordinator, so it can be
+    /// taken under a read lock while the ceremony keeps running. The backup
+    /// is restored with [Coordinator::restore].
+    ///
+    pub fn snapshot<P: AsRef<Path>>(&self, directory: P) -> Result<PathBuf, CoordinatorError> {
+        let name = format!("snapshot-{}", self.time.now_utc().unix_timestamp());
+        self.storage.snapshot(&self.state, directory.as_ref(), &name)
+    }
+
+    ///
+    /// Restores the storage of the given environment from a backup taken with
+    /// [Coordinator::snapshot]. A coordinator created afterwards for the same
+    /// environment resumes the ceremony from the backup.
+    ///
+    pub fn restore<P: AsRef<Path>>(environment: &Environment, snapshot: P) -> Result<(), CoordinatorError> {
+        Disk::restore(environment, snapshot.as_ref())
+    }
+
+    ///
+    /// Returns the number of chunks in the current round, and whether
+    /// the current contribution of each chunk has been verified.
+    ///
+    pub fn current_round_chunks(&self) -> Result<RoundChunks, CoordinatorError> {
+        let round = self.current_round()?;
+
+        let chunks = round
+            .chunks()
+            .iter()
+            .map(|chunk| {
+                Ok(ChunkStatus {
+                    chunk_id: chunk.chunk_id(),
+                    current_contribution_id: chunk.current_contribution_id(),
+                    is_verified: chunk.current_contribution()?.is_verified(),
+                })
+            })
+            .collect::<Result<Vec<_>, CoordinatorError>>()?;
+
+        Ok(RoundChunks {
+            round_height: round.round_height(),
+            number_of_chunks: chunks.len() as u64,
+            chunks,
+        })
+    }
+
+    ///
+    /// Returns the height of the current round, the progress of its
+    /// contributors and verifications, and whether it is finished.
+    ///
+    /// The summary is taken from a single view of the coordinator, so
+    /// that clients do not stitch together calls made at different times.
+    ///
+    pub fn current_round_summary(&self) -> Result<RoundSummary, CoordinatorError> {
+        let round_height = self.current_round_height()?;
+        let number_of_finished_contributors = self
+            .state
+            .current_round_finished_contributors()
+            .map(|contributors| contributors.len())
+            .unwrap_or(0);
+
+        Ok(RoundSummary {
+            round_height,
+            number_of_contributors: self.state.current_contributors().len() + number_of_finished_contributors,
+            number_of_finished_contributors,
+            number_of_qualifying_contributors: self.state.current_round_number_of_qualifying_contributors(),
+            minimum_contributors: self.environment.minimum_contributors_per_round(),
+            number_of_pending_verifications: self.state.get_pending_verifications().len(),
+            is_finished: self.state.is_current_round_finished(),
+            is_aggregated: self.state.is_current_round_aggregated(),
+        })
+    }
+
     ///
     /// Returns `true` if the given participant is a contributor in the queue.
     ///
@@ -586,6 +1188,28 @@ impl Coordinator {
         self.state.queue_contributors()
     }
 
+    ///
+    /// Returns the contributors currently in the queue with their time of joining,
+    /// in queue order.
+    ///
+    #[inline]
+    pub fn queue_order(&self) -> Vec<(Participant, OffsetDateTime)> {
+        self.state.queue_order()
+    }
+
+    ///
+    /// Returns the position of the given participant in the queue, starting
+    /// from 1, and the number of contributors in the queue.
+    ///
+    /// If the participant is not in the queue, returns `None`.
+    ///
+    #[inline]
+    pub fn queue_position(&self, participant: &Participant) -> Option<(u64, u64)> {
+        let queue = self.state.queue_order();
+        let position = queue.iter().position(|(p, _)| p == participant)?;
+        Some((position as u64 + 1, queue.len() as u64))
+    }
+
     ///
     /// Returns a list of the contributors currently in the round.
     ///
@@ -613,16 +1237,98 @@ impl Coordinator {
     ///
     /// Adds the given participant to the queue if they are permitted to participate.
     ///
+    /// On success, this function returns the position of the participant in the
+    /// queue, so that they do not need to poll for it right after joining.
+    /// Joining again while already in the queue updates the IP and reliability
+    /// score of the participant, so that clients can safely retry. Joining while
+    /// contributing to the current round returns `AlreadyContributing`, unless the
+    /// environment allows current contributors in the queue.
+    ///
     #[inline]
     pub fn add_to_queue(
         &mut self,
         participant: Participant,
         participant_ip: Option<IpAddr>,
         reliability_score: u8,
-    ) -> Result<(), CoordinatorError> {
+    ) -> Result<QueuePosition, CoordinatorError> {
+        self.check_writable()?;
+
+        // Check that the ceremony is not paused.
+        if self.state.is_paused() {
+            return Err(CoordinatorError::Paused);
+        }
+
+        // Check that the participant is permitted to join the queue.
+        if !self.environment.participant_access().is_allowed(&participant) {
+            return Err(CoordinatorError::ParticipantNotAllowed);
+        }
+
         // Attempt to add the participant to the next round.
+        self.state.add_to_queue(
+            participant.clone(),
+            participant_ip,
+            reliability_score,
+            self.time.as_ref(),
+        )?;
+
+        // Save the coordinator state in storage.
+        self.save_state()?;
+
+        // Fetch the position of the participant in the queue.
+        let (position, queue_size) = self
+            .queue_position(&participant)
+            .ok_or_else(|| CoordinatorError::ParticipantNotFound(participant.clone()))?;
+        Ok(QueuePosition { position, queue_size })
+    }
+
+    ///
+    /// Replaces the verifiers managed by the coordinator, without a restart.
+    ///
+    /// The new verifiers are saved with the coordinator state, so they remain
+    /// in place across restarts. Verifications already claimed by a replaced
+    /// verifier may still be submitted by them, so no work in flight is lost.
+    ///
+    #[inline]
+    pub fn set_coordinator_verifiers(&mut self, verifiers: Vec<Participant>) -> Result<(), CoordinatorError> {
+        self.check_writable()?;
+
+        // Replace the verifiers in the coordinator state.
+        self.state.set_coordinator_verifiers(verifiers.clone())?;
+        self.environment.set_coordinator_verifiers(verifiers);
+
+        // Save the coordinator state in storage.
+        self.save_state()?;
+
+        Ok(())
+    }
+
+    ///
+    /// Replaces the participants permitted to join the queue, without a restart.
+    ///
+    /// Participants already in the queue or in the current round are unaffected,
+    /// and the replacement lasts until the coordinator is restarted with its
+    /// configured environment.
+    ///
+    #[inline]
+    pub fn set_participant_access(&mut self, participant_access: ParticipantAccess) {
+        self.environment.set_participant_access(participant_access);
+    }
+
+    ///
+    /// Records the report of the reliability checks the given contributor
+    /// has just gone through, for their admission to the queue.
+    ///
+    #[inline]
+    pub fn record_reliability_report(
+        &mut self,
+        participant: Participant,
+        report: ReliabilityReport,
+    ) -> Result<(), CoordinatorError> {
+        self.check_writable()?;
+
+        // Record the reliability report of the participant.
         self.state
-            .add_to_queue(participant, participant_ip, reliability_score, self.time.as_ref())?;
+            .record_reliability_report(participant, report, self.time.as_ref())?;
 
         // Save the coordinator state in storage.
         self.save_state()?;
@@ -630,11 +1336,43 @@ impl Coordinator {
         Ok(())
     }
 
+    ///
+    /// Returns the latest reliability report of the given contributor, if any,
+    /// with the measurements of each check and whether it currently admits
+    /// them to the queue.
+    ///
+    #[inline]
+    pub fn reliability_report(&self, participant: &Participant) -> Option<ReliabilityStatus> {
+        let (report, reported_at) = self.state.reliability_report(participant)?;
+        let passed = self
+            .state
+            .has_passed_reliability_checks(participant, self.time.as_ref());
+        Some(ReliabilityStatus {
+            score: report.score_percent(),
+            reported_at: *reported_at,
+            passed,
+            results: report.results.clone(),
+        })
+    }
+
+    ///
+    /// Returns the recorded events in the lifecycle of the given participant,
+    /// ordered by time.
+    ///
+    /// Chunk locks are only known while they are held.
+    ///
+    #[inline]
+    pub fn participant_timeline(&self, participant: &Participant) -> Vec<TimelineEntry> {
+        self.state.participant_timeline(participant)
+    }
+
     ///
     /// Removes the given participant from the queue if they are in the queue.
     ///
     #[inline]
     pub fn remove_from_queue(&mut self, participant: &Participant) -> Result<(), CoordinatorError> {
+        self.check_writable()?;
+
         // Attempt to remove the participant from the next round.
         self.state.remove_from_queue(participant)?;
 
@@ -652,6 +1390,8 @@ impl Coordinator {
         fields(participant = %participant)
     )]
     pub fn drop_participant(&mut self, participant: &Participant) -> Result<(), CoordinatorError> {
+        self.check_writable()?;
+
         // Drop the participant from the ceremony.
         let drop = self.state.drop_participant(participant, self.time.as_ref())?;
 
@@ -669,6 +1409,8 @@ impl Coordinator {
     ///
     #[inline]
     pub fn ban_participant(&mut self, participant: &Participant) -> Result<(), CoordinatorError> {
+        self.check_writable()?;
+
         // Ban the participant from the ceremony.
         let drop = self.state.ban_participant(participant, self.time.as_ref())?;
 
@@ -686,6 +1428,8 @@ impl Coordinator {
     ///
     #[inline]
     pub fn unban_participant(&mut self, participant: &Participant) -> Result<(), CoordinatorError> {
+        self.check_writable()?;
+
         // Unban the participant from the ceremony.
         self.state.unban_participant(participant);
 
@@ -709,6 +1453,8 @@ impl Coordinator {
     ///
     #[inline]
     pub fn enable_manual_lock(&mut self) -> Result<(), CoordinatorError> {
+        self.check_writable()?;
+
         // Sets the manual lock to `true`.
         self.state.enable_manual_lock();
 
@@ -723,6 +1469,8 @@ impl Coordinator {
     ///
     #[inline]
     pub fn disable_manual_lock(&mut self) -> Result<(), CoordinatorError> {
+        self.check_writable()?;
+
         // Sets the manual lock to `false`.
         self.state.disable_manual_lock();
 
@@ -733,14 +1481,55 @@ impl Coordinator {
     }
 
     ///
-    /// Returns `true` if the given participant is authorized as a
-    /// contributor and listed in the contributor IDs for this round.
-    ///
-    /// If the participant is not a contributor, or if there are
-    /// no prior rounds, returns `false`.
+    /// Returns `true` if the ceremony is paused.
     ///
     #[inline]
-    pub fn is_current_contributor(&self, participant: &Participant) -> bool {
+    pub fn is_paused(&self) -> bool {
+        self.state.is_paused()
+    }
+
+    ///
+    /// Pauses the ceremony, rejecting participants joining the queue,
+    /// locking chunks, and contributing, until the ceremony is resumed.
+    ///
+    #[inline]
+    pub fn pause(&mut self) -> Result<(), CoordinatorError> {
+        self.check_writable()?;
+
+        // Sets the pause to `true`.
+        self.state.pause();
+
+        // Save the coordinator state in storage.
+        self.save_state()?;
+
+        Ok(())
+    }
+
+    ///
+    /// Resumes the ceremony, if it is paused.
+    ///
+    #[inline]
+    pub fn resume(&mut self) -> Result<(), CoordinatorError> {
+        self.check_writable()?;
+
+        // Sets the pause to `false`.
+        self.state.resume();
+
+        // Save the coordinator state in storage.
+        self.save_state()?;
+
+        Ok(())
+    }
+
+    ///
+    /// Returns `true` if the given participant is authorized as a
+    /// contributor and listed in the contributor IDs for this round.
+    ///
+    /// If the participant is not a contributor, or if there are
+    /// no prior rounds, returns `false`.
+    ///
+    #[inline]
+    pub fn is_current_contributor(&self, participant: &Participant) -> bool {
         // Fetch the current round from storage.
         let round = match Self::load_current_round(&self.storage) {
             // Case 1 - This is a typical round of the ceremony.
@@ -787,6 +1576,48 @@ impl Coordinator {
         self.state.is_coordinator_verifier(&participant)
     }
 
+    ///
+    /// Returns the participant for the given address. If the address belongs
+    /// to a verifier managed by the coordinator, this function returns a
+    /// `Participant::Verifier`, otherwise it returns a `Participant::Contributor`.
+    ///
+    #[inline]
+    pub fn participant_from_address(&self, address: &str) -> Participant {
+        let verifier = Participant::new_verifier(address);
+        match self.environment.coordinator_verifiers().contains(&verifier) {
+            true => verifier,
+            false => Participant::new_contributor(address),
+        }
+    }
+
+    ///
+    /// Returns the contributor for the given address.
+    ///
+    /// If the address belongs to a verifier managed by the coordinator,
+    /// this function returns `CoordinatorError::ParticipantUnauthorized`.
+    ///
+    #[inline]
+    pub fn authorize_contributor(&self, address: &str) -> Result<Participant, CoordinatorError> {
+        match self.participant_from_address(address) {
+            participant @ Participant::Contributor(_) => Ok(participant),
+            Participant::Verifier(_) => Err(CoordinatorError::ParticipantUnauthorized),
+        }
+    }
+
+    ///
+    /// Returns the verifier for the given address.
+    ///
+    /// If the address does not belong to a verifier managed by the coordinator,
+    /// this function returns `CoordinatorError::ParticipantUnauthorized`.
+    ///
+    #[inline]
+    pub fn authorize_verifier(&self, address: &str) -> Result<Participant, CoordinatorError> {
+        match self.participant_from_address(address) {
+            participant @ Participant::Verifier(_) => Ok(participant),
+            Participant::Contributor(_) => Err(CoordinatorError::ParticipantUnauthorized),
+        }
+    }
+
     ///
     /// Returns the current round height of the ceremony from storage,
     /// irrespective of the stage of its completion.
@@ -852,12 +1683,367 @@ impl Coordinator {
         }
     }
 
+    ///
+    /// Returns the participants that contributed to or verified the round
+    /// corresponding to the given height, with their roles, from storage.
+    ///
+    /// Only verified contributions are counted. Each participant is listed
+    /// once per role, in the order of the chunks and contributions.
+    ///
+    /// If the round does not exist, returns a `CoordinatorError`.
+    ///
+    pub fn participants_in_round(
+        &self,
+        round_height: u64,
+    ) -> Result<Vec<(Participant, ParticipantRole)>, CoordinatorError> {
+        let round = self.get_round(round_height)?;
+
+        let mut participants = vec![];
+        let verified = round
+            .chunks()
+            .iter()
+            .flat_map(|chunk| chunk.get_contributions().values())
+            .filter(|contribution| contribution.is_verified());
+        for contribution in verified {
+            let contributor = contribution.get_contributor().clone();
+            let verifier = contribution.get_verifier().clone();
+            let entries = contributor
+                .map(|participant| (participant, ParticipantRole::Contributor))
+                .into_iter()
+                .chain(verifier.map(|participant| (participant, ParticipantRole::Verifier)));
+            for entry in entries {
+                if !participants.contains(&entry) {
+                    participants.push(entry);
+                }
+            }
+        }
+        Ok(participants)
+    }
+
+    ///
+    /// Returns the bytes of the contribution file at the given locator from storage.
+    ///
+    /// If `privileged` is `false`, this function only returns the file if the
+    /// corresponding contribution has been verified. Otherwise, it returns
+    /// `CoordinatorError::ContributionMissingVerification`.
+    ///
+    pub fn get_contribution_file(
+        &self,
+        locator: &ContributionLocator,
+        privileged: bool,
+    ) -> Result<Vec<u8>, CoordinatorError> {
+        // Check that the contribution exists in the round state.
+        let round = Self::load_round(&self.storage, locator.round_height())?;
+        let contribution = round
+            .chunk(locator.chunk_id())?
+            .get_contribution(locator.contribution_id())?;
+
+        // Check that the contribution is verified, unless the caller is privileged.
+        if !privileged && !contribution.is_verified() {
+            return Err(CoordinatorError::ContributionMissingVerification);
+        }
+
+        // Fetch the contribution file from storage.
+        let locator = Locator::ContributionFile(*locator);
+        if !self.storage.exists(&locator) {
+            return Err(CoordinatorError::ContributionMissing);
+        }
+        match self.storage.get(&locator)? {
+            Object::ContributionFile(bytes) => Ok(bytes),
+            _ => Err(CoordinatorError::StorageFailed),
+        }
+    }
+
+    ///
+    /// Returns the locator of the contribution with the given response hash,
+    /// if it was recorded in any round of the ceremony.
+    ///
+    /// The hash is compared against the hex-encoded response hash in the stored
+    /// contribution file signatures, so contributors can confirm that their
+    /// contribution was included without the coordinator reading any response file.
+    /// If no contribution matches, this function returns `None`.
+    ///
+    pub fn find_contribution(&self, response_hash: &str) -> Result<Option<ContributionLocator>, CoordinatorError> {
+        let response_hash = response_hash.to_lowercase();
+
+        for round_height in 0..=Self::load_current_round_height(&self.storage)? {
+            for (locator, contribution_file_signature) in self.contribution_file_signatures(round_height)? {
+                if contribution_file_signature.get_response_hash() == response_hash {
+                    return Ok(Some(locator));
+                }
+            }
+        }
+
+        Ok(None)
+    }
+
+    ///
+    /// Returns the hex-encoded root of the Merkle tree over the response
+    /// hashes of the contributions in the given round.
+    ///
+    /// The leaves are ordered by chunk ID, then by contribution ID, so the
+    /// root changes as each contribution in the round is added.
+    ///
+    pub fn merkle_root(&self, round_height: u64) -> Result<String, CoordinatorError> {
+        let (_, tree) = self.merkle_tree(round_height)?;
+        Ok(hex::encode(tree.root()))
+    }
+
+    ///
+    /// Returns the proof that the contribution at the given locator is included
+    /// in the Merkle tree of its round, as returned by [Coordinator::merkle_root].
+    ///
+    /// If the contribution does not exist, this function returns
+    /// `CoordinatorError::ContributionMissing`.
+    ///
+    pub fn merkle_proof(&self, locator: &ContributionLocator) -> Result<MerkleProof, CoordinatorError> {
+        let (locators, tree) = self.merkle_tree(locator.round_height())?;
+        locators
+            .iter()
+            .position(|leaf| {
+                leaf.chunk_id() == locator.chunk_id() && leaf.contribution_id() == locator.contribution_id()
+            })
+            .and_then(|leaf_index| tree.proof(leaf_index))
+            .ok_or(CoordinatorError::ContributionMissing)
+    }
+
+    ///
+    /// Returns the Merkle tree over the response hashes of the contributions
+    /// in the given round, and the locators of its leaves in order.
+    ///
+    fn merkle_tree(&self, round_height: u64) -> Result<(Vec<ContributionLocator>, MerkleTree), CoordinatorError> {
+        let mut locators = vec![];
+        let mut leaves = vec![];
+        for (locator, contribution_file_signature) in self.contribution_file_signatures(round_height)? {
+            locators.push(locator);
+            leaves.push(hex::decode(contribution_file_signature.get_response_hash())?);
+        }
+        Ok((locators, MerkleTree::new(&leaves)))
+    }
+
+    ///
+    /// Returns the stored contribution file signatures of the contributions in
+    /// the given round, ordered by chunk ID, then by contribution ID.
+    ///
+    fn contribution_file_signatures(
+        &self,
+        round_height: u64,
+    ) -> Result<Vec<(ContributionLocator, ContributionFileSignature)>, CoordinatorError> {
+        let round = Self::load_round(&self.storage, round_height)?;
+
+        let mut signatures = vec![];
+        for chunk in round.chunks() {
+            // Skip the initial challenge, which is not a contribution.
+            for contribution_id in chunk.get_contributions().keys().filter(|id| **id > 0) {
+                let locator =
+                    ContributionSignatureLocator::new(round_height, chunk.chunk_id(), *contribution_id, false);
+                if let Some(contribution_file_signature) = self.load_contribution_file_signature(&locator)? {
+                    signatures.push((
+                        ContributionLocator::new(round_height, chunk.chunk_id(), *contribution_id, false),
+                        contribution_file_signature,
+                    ));
+                }
+            }
+        }
+
+        Ok(signatures)
+    }
+
+    ///
+    /// Checks that the contributions of every chunk in the given round link
+    /// together, and returns the locator of the first contribution that does not.
+    ///
+    /// Each contribution must have been computed on the challenge produced by
+    /// the verification of its predecessor, starting from the initial challenge
+    /// of the chunk, as recorded in the stored contribution file signatures.
+    /// Unlike the verification of a single contribution, this catches a gap or
+    /// a reordering in the transcript. The chain of a chunk ends at its first
+    /// unverified contribution. If every link holds, this function returns `None`.
+    ///
+    pub fn verify_full_transcript(&self, round_height: u64) -> Result<Option<ContributionLocator>, CoordinatorError> {
+        let round = Self::load_round(&self.storage, round_height)?;
+
+        for chunk in round.chunks() {
+            let chunk_id = chunk.chunk_id();
+            let is_chunk_complete = chunk.only_contributions_complete(round.expected_number_of_contributions());
+
+            // The chain starts at the hash of the initial challenge of the chunk.
+            let challenge = Locator::ContributionFile(ContributionLocator::new(round_height, chunk_id, 0, true));
+            let mut expected_challenge_hash = hex::encode(calculate_hash(&*self.storage.reader(&challenge)?));
+
+            for (contribution_id, contribution) in chunk.get_contributions().range(1..) {
+                let locator = ContributionLocator::new(round_height, chunk_id, *contribution_id, false);
+
+                // Check that the contribution was computed on the expected challenge.
+                let contributed = match self.load_contribution_file_signature(&ContributionSignatureLocator::new(
+                    round_height,
+                    chunk_id,
+                    *contribution_id,
+                    false,
+                ))? {
+                    Some(contributed) => contributed,
+                    None => return Ok(Some(locator)),
+                };
+                if contributed.get_challenge_hash() != expected_challenge_hash {
+                    error!("Contribution {:?} does not follow its predecessor", locator);
+                    return Ok(Some(locator));
+                }
+
+                // The chain of this chunk ends at its first unverified contribution.
+                if !contribution.is_verified() {
+                    break;
+                }
+
+                // The verification of the final contribution is stored with the next round.
+                let verified_locator = match is_chunk_complete && *contribution_id == chunk.current_contribution_id() {
+                    true => ContributionSignatureLocator::new(round_height + 1, chunk_id, 0, true),
+                    false => ContributionSignatureLocator::new(round_height, chunk_id, *contribution_id, true),
+                };
+                let verified = match self.load_contribution_file_signature(&verified_locator)? {
+                    Some(verified) => verified,
+                    None => return Ok(Some(locator)),
+                };
+
+                // Check that the verifier checked this response, and continue from its next challenge.
+                match verified.get_next_challenge_hash() {
+                    Some(next_challenge_hash) if verified.get_response_hash() == contributed.get_response_hash() => {
+                        expected_challenge_hash = next_challenge_hash.clone();
+                    }
+                    _ => {
+                        error!("Verification of contribution {:?} does not match its response", locator);
+                        return Ok(Some(locator));
+                    }
+                }
+            }
+        }
+
+        Ok(None)
+    }
+
+    ///
+    /// Returns the contribution file signature at the given locator, if it exists.
+    ///
+    fn load_contribution_file_signature(
+        &self,
+        locator: &ContributionSignatureLocator,
+    ) -> Result<Option<ContributionFileSignature>, CoordinatorError> {
+        let locator = Locator::ContributionFileSignature(*locator);
+        match self.storage.exists(&locator) {
+            true => Ok(Some(serde_json::from_slice(&*self.storage.reader(&locator)?)?)),
+            false => Ok(None),
+        }
+    }
+
+    ///
+    /// Returns the bytes of the verified challenge for the given chunk in the given round.
+    ///
+    /// If the challenge in storage does not have the expected size, this function
+    /// returns `CoordinatorError::ChallengeCorrupt` instead of the partial file.
+    ///
+    /// A verified challenge never changes within its round, so if caching
+    /// is enabled in the environment, the challenges of the current round
+    /// are kept in memory and repeat requests do not read from storage.
+    /// The cache is cleared when the round advances or is reset.
+    ///
+    pub fn get_challenge(&self, round_height: u64, chunk_id: u64) -> Result<Arc<Vec<u8>>, CoordinatorError> {
+        let cache_challenges = self.environment.cache_challenges();
+
+        // Check if the challenge is cached already.
+        if cache_challenges {
+            let challenges = self
+                .challenges
+                .read()
+                .map_err(|_| CoordinatorError::StorageLockFailed)?;
+            if let Some(challenge) = challenges.get(&(round_height, chunk_id)) {
+                return Ok(challenge.clone());
+            }
+        }
+
+        // Fetch the challenge from storage.
+        let locator = ContributionLocator::new(round_height, chunk_id, 0, true);
+        let challenge = Arc::new(self.get_contribution_file(&locator, false)?);
+
+        // Check that the challenge is not empty or truncated in storage.
+        let size = challenge.len() as u64;
+        let expected = Object::contribution_file_size(&self.environment, chunk_id, true);
+        if size != expected {
+            error!(
+                "Challenge for round {} chunk {} is corrupt, its size is {} instead of {}",
+                round_height, chunk_id, size, expected
+            );
+            return Err(CoordinatorError::ChallengeCorrupt { size, expected });
+        }
+
+        // Cache the challenge, if it belongs to the current round.
+        if cache_challenges && round_height == self.state.current_round_height() {
+            let mut challenges = self
+                .challenges
+                .write()
+                .map_err(|_| CoordinatorError::StorageLockFailed)?;
+            challenges.retain(|(height, _), _| *height == round_height);
+            challenges.insert((round_height, chunk_id), challenge.clone());
+        }
+
+        Ok(challenge)
+    }
+
+    ///
+    /// Signs the given response body with the given coordinator signing key,
+    /// and returns the signature as a string.
+    ///
+    /// The signed message is the hex-encoded hash of the response body, which
+    /// allows clients to check that a downloaded file was served by the coordinator.
+    ///
+    pub fn sign_response(&self, signing_key: &str, body: &[u8]) -> Result<String, CoordinatorError> {
+        Ok(self.signature.sign(signing_key, &hex::encode(calculate_hash(body)))?)
+    }
+
+    ///
+    /// Returns `true` if the given signature over the response body is valid
+    /// for the given coordinator public key.
+    ///
+    pub fn verify_response(&self, public_key: &str, body: &[u8], signature: &str) -> bool {
+        self.signature
+            .verify(public_key, &hex::encode(calculate_hash(body)), signature)
+    }
+
+    ///
+    /// Returns the current Unix time of the coordinator and the given nonce of the
+    /// client, signed with the given coordinator signing key.
+    ///
+    /// Clients use this to compute the offset of their clock from the coordinator,
+    /// and check it with [SignedTime::verify] against the nonce they sent, so that
+    /// the time cannot be skewed in transit or by replaying an earlier response.
+    ///
+    pub fn signed_time(&self, signing_key: &str, nonce: &str) -> Result<SignedTime, CoordinatorError> {
+        let unix_time = self.time.now_utc().unix_timestamp();
+        let signature = self.sign_response(signing_key, SignedTime::message(nonce, unix_time).as_bytes())?;
+        Ok(SignedTime {
+            nonce: nonce.to_string(),
+            unix_time,
+            signature,
+        })
+    }
+
     /// Lets the coordinator know that the participant is still alive
     /// and participating (or waiting to participate) in the ceremony.
     pub fn heartbeat(&mut self, participant: &Participant) -> Result<(), CoordinatorError> {
+        self.check_writable()?;
         self.state.heartbeat(participant, self.time.as_ref())
     }
 
+    ///
+    /// Returns the interval at which participants are expected to send
+    /// a heartbeat, for servers to pass on to their clients.
+    ///
+    /// The interval is a third of the contributor seen timeout, so that
+    /// a participant may miss two heartbeats before it is dropped.
+    ///
+    #[inline]
+    pub fn heartbeat_interval(&self) -> Duration {
+        self.environment.contributor_seen_timeout() / 3
+    }
+
     ///
     /// Attempts to acquire the lock to a chunk for the given participant.
     ///
@@ -866,14 +2052,54 @@ impl Coordinator {
     #[tracing::instrument(
         level = "error",
         skip(self),
-        fields(participant = %participant),
+        fields(participant = %participant, round = field::Empty, chunk = field::Empty),
         err
     )]
     pub fn try_lock(&mut self, participant: &Participant) -> Result<(u64, LockedLocators), CoordinatorError> {
+        self.try_lock_task(participant, None)
+    }
+
+    ///
+    /// Attempts to acquire the lock to the given chunk for the given participant,
+    /// instead of the next chunk assigned to them.
+    ///
+    /// The chunk must be assigned to the participant and not yet contributed
+    /// to by them. On failure, this function returns a `CoordinatorError`.
+    ///
+    #[tracing::instrument(
+        level = "error",
+        skip(self),
+        fields(participant = %participant, round = field::Empty, chunk = field::Empty),
+        err
+    )]
+    pub fn try_lock_for_chunk(
+        &mut self,
+        participant: &Participant,
+        chunk_id: u64,
+    ) -> Result<(u64, LockedLocators), CoordinatorError> {
+        self.try_lock_task(participant, Some(chunk_id))
+    }
+
+    ///
+    /// Attempts to acquire the lock to the given chunk, or to the next
+    /// assigned chunk if none is given, for the given participant.
+    ///
+    fn try_lock_task(
+        &mut self,
+        participant: &Participant,
+        chunk_id: Option<u64>,
+    ) -> Result<(u64, LockedLocators), CoordinatorError> {
         if participant.is_verifier() {
             return Err(CoordinatorError::ExpectedContributor);
         }
 
+        self.check_writable()?;
+
+        // Check that the ceremony is not paused.
+        if self.state.is_paused() {
+            return Err(CoordinatorError::Paused);
+        }
+
         // Check that the participant is in the current round, and has not been dropped or finished.
         if !self.state.is_current_contributor(participant) {
             return Err(CoordinatorError::ParticipantUnauthorized);
@@ -894,10 +2120,19 @@ impl Coordinator {
             return Err(CoordinatorError::CurrentRoundAggregated);
         }
 
-        // Attempt to fetch the next chunk ID and contribution ID for the given participant.
-        let current_task = self.state.fetch_task(participant, self.time.as_ref())?;
+        // Attempt to fetch the next (or the given) chunk ID and contribution ID for the given participant.
+        let current_task = match chunk_id {
+            Some(chunk_id) => self
+                .state
+                .fetch_task_for_chunk(participant, chunk_id, self.time.as_ref())?,
+            None => self.state.fetch_task(participant, self.time.as_ref())?,
+        };
         trace!("Fetched task {} for {}", current_task, participant);
 
+        let span = Span::current();
+        span.record("round", &self.state.current_round_height());
+        span.record("chunk", &current_task.chunk_id());
+
         let round = Self::load_current_round(&self.storage)?;
         let chunk = round.chunk(current_task.chunk_id())?;
         if current_task.contribution_id() > (chunk.current_contribution_id() + 1) {
@@ -909,15 +2144,24 @@ impl Coordinator {
         debug!("Locking chunk {} for {}", current_task.chunk_id(), participant);
         match self.try_lock_chunk(current_task.chunk_id(), participant) {
             // Case 1 - Participant acquired lock, return the locator.
-            Ok(locked_locators) => {
+            Ok(mut locked_locators) => {
                 trace!("Incrementing the number of locks held by {}", participant);
                 self.state
                     .acquired_lock(participant, current_task.chunk_id(), self.time.as_ref())?;
+                locked_locators.set_deadline(self.state.lock_deadline(participant, current_task.chunk_id()));
+                locked_locators.set_previous_contribution_hash(
+                    self.previous_contribution_hash(&locked_locators.current_contribution()),
+                );
 
                 // Save the coordinator state in storage.
                 self.save_state()?;
 
                 info!("Acquired lock on chunk {} for {}", current_task.chunk_id(), participant);
+                (self.event_callback)(CeremonyEvent::ChunkLocked {
+                    round_height: self.state.current_round_height(),
+                    chunk_id: current_task.chunk_id(),
+                    participant: participant.clone(),
+                });
                 Ok((current_task.chunk_id(), locked_locators))
             }
             // Case 2 - Participant failed to acquire the lock, put the chunk ID back.
@@ -931,7 +2175,10 @@ impl Coordinator {
                 // Save the coordinator state in storage.
                 self.save_state()?;
 
-                error!("{}", error);
+                match error.is_temporary() {
+                    true => info!("{:?}", error),
+                    false => error!("{}", error),
+                }
                 return Err(error);
             }
         }
@@ -958,6 +2205,8 @@ impl Coordinator {
         chunk_id: u64,
         locators: &LockedLocators,
     ) -> Result<(), CoordinatorError> {
+        self.check_writable()?;
+
         if !participant.is_verifier() {
             return Err(CoordinatorError::ExpectedVerifier);
         }
@@ -966,62 +2215,287 @@ impl Coordinator {
     }
 
     ///
-    /// Attempts to add a contribution for the given chunk ID from the given participant.
-    ///
-    /// This function constructs the expected response locator for the given participant
-    /// and chunk ID, and checks that the participant has uploaded the response file and
-    /// contribution file signature prior to adding the unverified contribution to the
-    /// round self.
+    /// Writes an uploaded response file and its contribution file signature to storage.
     ///
-    /// On success, this function releases the lock from the contributor and returns
-    /// the response file locator.
+    /// Before anything is written, this function checks that the locators are those
+    /// of a chunk locked by the participant, that the contribution file signature is
+    /// valid for the participant, and that the signed hashes match the uploaded
+    /// response file.
     ///
-    /// On failure, it returns a `CoordinatorError`.
+    /// On failure, it returns a `CoordinatorError` and leaves storage unchanged.
     ///
-    #[tracing::instrument(
-        level = "error",
-        skip(self, participant, chunk_id),
-        fields(participant = %participant, chunk = chunk_id),
-        err
-    )]
-    pub fn try_contribute(
+    pub fn write_contribution(
         &mut self,
         participant: &Participant,
-        chunk_id: u64,
-    ) -> Result<ContributionLocator, CoordinatorError> {
-        // Check that the participant is a contributor.
-        if !participant.is_contributor() {
-            return Err(CoordinatorError::ExpectedContributor);
-        }
+        response_locator: ContributionLocator,
+        response: Vec<u8>,
+        signature_locator: ContributionSignatureLocator,
+        contribution_file_signature: ContributionFileSignature,
+    ) -> Result<(), CoordinatorError> {
+        self.check_writable()?;
 
-        // Check that the chunk ID is valid.
-        if chunk_id > self.environment.number_of_chunks() {
-            return Err(CoordinatorError::ChunkIdInvalid);
+        // Check that the response file and signature locators belong to the lock of the participant.
+        self.check_locked_locators(participant, &response_locator, &signature_locator)?;
+
+        // Check that the contribution deadline of the lock has not passed.
+        self.check_lock_deadline(participant, response_locator.chunk_id())?;
+
+        // Check that the response file has the expected size, before it is hashed or verified.
+        let size = response.len() as u64;
+        let expected = Object::contribution_file_size(&self.environment, response_locator.chunk_id(), false);
+        if size != expected {
+            error!("The uploaded response file has {} bytes instead of {}", size, expected);
+            return Err(CoordinatorError::ContributionFileSizeMismatch);
         }
 
-        // Check that the participant is in the current round, and has not been dropped or finished.
-        if !self.state.is_current_contributor(participant) {
-            return Err(CoordinatorError::ParticipantUnauthorized);
+        // Check that the contribution file signature is valid.
+        let address = participant.address();
+        if !self.signature.verify(
+            &address,
+            &serde_json::to_string(&contribution_file_signature.get_state())?,
+            contribution_file_signature.get_signature(),
+        ) {
+            error!("Contribution file signature failed to verify for {}", participant);
+            return Err(CoordinatorError::ContributorSignatureInvalid);
         }
 
-        // Check that the current round is not yet finished.
-        if self.state.is_current_round_finished() {
-            return Err(CoordinatorError::CurrentRoundFinished);
+        // Check that the signed response hash matches the uploaded response file.
+        if hex::decode(contribution_file_signature.get_response_hash())? != calculate_hash(&response).as_slice() {
+            error!("The signed response hash does not match the uploaded response file.");
+            return Err(CoordinatorError::ContributionHashMismatch);
         }
 
-        // Check that the current round is not yet aggregating.
-        if self.state.is_current_round_aggregating() {
-            return Err(CoordinatorError::CurrentRoundAggregating);
+        // Check that the signed challenge hash matches the challenge hash in the uploaded response file.
+        let challenge_hash_in_response = response.get(0..64).ok_or(CoordinatorError::ContributionHashMismatch)?;
+        if hex::decode(contribution_file_signature.get_challenge_hash())? != challenge_hash_in_response {
+            error!("The signed challenge hash does not match the uploaded response file.");
+            return Err(CoordinatorError::ContributionHashMismatch);
         }
 
-        // Check that the current round is not yet aggregated.
-        if self.state.is_current_round_aggregated() {
-            return Err(CoordinatorError::CurrentRoundAggregated);
+        // Write the response file and contribution file signature to storage.
+        let objects = [
+            (
+                Locator::ContributionFile(response_locator),
+                Object::ContributionFile(response),
+            ),
+            (
+                Locator::ContributionFileSignature(signature_locator),
+                Object::ContributionFileSignature(contribution_file_signature),
+            ),
+        ];
+        for (locator, object) in objects {
+            match self.storage.exists(&locator) {
+                true => self.storage.update(&locator, object)?,
+                false => self.storage.insert(locator, object)?,
+            }
         }
 
-        // Fetch the current round height from storage.
+        Ok(())
+    }
+
+    ///
+    /// Returns the number of bytes of the given response file received so far by
+    /// [Coordinator::upload_contribution_part], from which an interrupted upload
+    /// may be resumed.
+    ///
+    #[inline]
+    pub fn upload_status(&self, response_locator: &ContributionLocator) -> Result<u64, CoordinatorError> {
+        self.storage.upload_size(response_locator)
+    }
+
+    ///
+    /// Writes a part of a response file being uploaded by the given participant
+    /// to storage, and returns the number of bytes received so far.
+    ///
+    /// The response file must be the next contribution to a chunk locked by the
+    /// participant, so there is at most one upload per lock. A part must start at the
+    /// offset returned by [Coordinator::upload_status], so that a part received twice
+    /// after a retry is not appended twice, or at offset zero to start the upload over.
+    /// Once all parts are received, the upload is finished with [Coordinator::finish_upload].
+    ///
+    pub fn upload_contribution_part(
+        &mut self,
+        participant: &Participant,
+        response_locator: ContributionLocator,
+        offset: u64,
+        part: &[u8],
+    ) -> Result<u64, CoordinatorError> {
+        self.check_writable()?;
+
+        // Check that the response file belongs to the lock of the participant. The parts are
+        // kept in storage rather than tracked in memory, so this is checked on every part.
+        let signature_locator = ContributionSignatureLocator::new(
+            response_locator.round_height(),
+            response_locator.chunk_id(),
+            response_locator.contribution_id(),
+            false,
+        );
+        self.check_locked_locators(participant, &response_locator, &signature_locator)?;
+
+        // Check that the contribution deadline of the lock has not passed.
+        self.check_lock_deadline(participant, response_locator.chunk_id())?;
+
+        // Check that the part starts where the previous part ended.
+        let expected = self.storage.upload_size(&response_locator)?;
+        if offset != 0 && offset != expected {
+            return Err(CoordinatorError::UploadOffsetMismatch { offset, expected });
+        }
+
+        // Check that the part does not exceed the size of a response file.
+        let maximum = Object::contribution_file_size(&self.environment, response_locator.chunk_id(), false);
+        if offset + part.len() as u64 > maximum {
+            return Err(CoordinatorError::ContributionFileSizeMismatch);
+        }
+
+        self.storage.write_upload(&response_locator, offset, part)
+    }
+
+    ///
+    /// Writes a response file assembled from the parts uploaded by the given participant,
+    /// and its contribution file signature to storage.
+    ///
+    /// The assembled file goes through the same checks as [Coordinator::write_contribution],
+    /// so its hashes are verified over the full file. The uploaded parts are discarded
+    /// whether or not the checks pass.
+    ///
+    pub fn finish_upload(
+        &mut self,
+        participant: &Participant,
+        response_locator: ContributionLocator,
+        signature_locator: ContributionSignatureLocator,
+        contribution_file_signature: ContributionFileSignature,
+    ) -> Result<(), CoordinatorError> {
+        self.check_writable()?;
+
+        // Check that the upload belongs to the lock of the participant, before it is discarded.
+        self.check_locked_locators(participant, &response_locator, &signature_locator)?;
+
+        let response = self.storage.take_upload(&response_locator)?;
+        self.write_contribution(
+            participant,
+            response_locator,
+            response,
+            signature_locator,
+            contribution_file_signature,
+        )
+    }
+
+    ///
+    /// Checks that the given response file and contribution file signature locators
+    /// are the next contribution to a chunk currently locked by the given participant,
+    /// so that a participant cannot write to a locator they do not own.
+    ///
+    fn check_locked_locators(
+        &self,
+        participant: &Participant,
+        response_locator: &ContributionLocator,
+        signature_locator: &ContributionSignatureLocator,
+    ) -> Result<(), CoordinatorError> {
+        let round = Self::load_current_round(&self.storage)?;
+        let chunk_id = response_locator.chunk_id();
+        let chunk = round.chunk(chunk_id)?;
+        if !chunk.is_locked_by(participant) {
+            error!("{} has not locked chunk {}", participant, chunk_id);
+            return Err(CoordinatorError::ParticipantUnauthorized);
+        }
+
+        let contribution_id = chunk.current_contribution_id() + 1;
+        let expected_response = ContributionLocator::new(round.round_height(), chunk_id, contribution_id, false);
+        let expected_signature =
+            ContributionSignatureLocator::new(round.round_height(), chunk_id, contribution_id, false);
+        if *response_locator != expected_response || *signature_locator != expected_signature {
+            error!(
+                "{} uploaded to {:?} and {:?}, instead of the locators of their lock",
+                participant, response_locator, signature_locator
+            );
+            return Err(CoordinatorError::ParticipantUnauthorized);
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Returns `ContributionDeadlineExceeded` if the given participant holds the lock
+    /// on the given chunk, and the deadline to contribute to it has passed.
+    ///
+    fn check_lock_deadline(&self, participant: &Participant, chunk_id: u64) -> Result<(), CoordinatorError> {
+        match self.state.lock_deadline(participant, chunk_id) {
+            Some(deadline) if self.time.now_utc() > deadline => {
+                warn!("{} missed the contribution deadline of chunk {}", participant, chunk_id);
+                Err(CoordinatorError::ContributionDeadlineExceeded)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    ///
+    /// Attempts to add a contribution for the given chunk ID from the given participant.
+    ///
+    /// This function constructs the expected response locator for the given participant
+    /// and chunk ID, and checks that the participant has uploaded the response file and
+    /// contribution file signature prior to adding the unverified contribution to the
+    /// round self.
+    ///
+    /// On success, this function releases the lock from the contributor and returns
+    /// the response file locator.
+    ///
+    /// On failure, it returns a `CoordinatorError`.
+    ///
+    #[tracing::instrument(
+        level = "error",
+        skip(self, participant, chunk_id),
+        fields(participant = %participant, round = field::Empty, chunk = chunk_id),
+        err
+    )]
+    pub fn try_contribute(
+        &mut self,
+        participant: &Participant,
+        chunk_id: u64,
+    ) -> Result<ContributionLocator, CoordinatorError> {
+        // Check that the participant is a contributor.
+        if !participant.is_contributor() {
+            return Err(CoordinatorError::ExpectedContributor);
+        }
+
+        // Check that the chunk ID is valid.
+        if chunk_id > self.environment.number_of_chunks() {
+            return Err(CoordinatorError::ChunkIdInvalid);
+        }
+
+        self.check_writable()?;
+
+        // Check that the ceremony is not paused.
+        if self.state.is_paused() {
+            return Err(CoordinatorError::Paused);
+        }
+
+        // Check that the participant is in the current round, and has not been dropped or finished.
+        if !self.state.is_current_contributor(participant) {
+            return Err(CoordinatorError::ParticipantUnauthorized);
+        }
+
+        // Check that the contribution deadline of the lock has not passed.
+        self.check_lock_deadline(participant, chunk_id)?;
+
+        // Check that the current round is not yet finished.
+        if self.state.is_current_round_finished() {
+            return Err(CoordinatorError::CurrentRoundFinished);
+        }
+
+        // Check that the current round is not yet aggregating.
+        if self.state.is_current_round_aggregating() {
+            return Err(CoordinatorError::CurrentRoundAggregating);
+        }
+
+        // Check that the current round is not yet aggregated.
+        if self.state.is_current_round_aggregated() {
+            return Err(CoordinatorError::CurrentRoundAggregated);
+        }
+
+        // Fetch the current round height from storage.
         let round_height = Self::load_current_round_height(&self.storage)?;
         trace!("Current round height in storage is {}", round_height);
+        Span::current().record("round", &round_height);
 
         // Check if the participant should dispose the response being contributed.
         if let Some(task) = self.state.lookup_disposing_task(participant, chunk_id)?.cloned() {
@@ -1059,6 +2533,31 @@ impl Coordinator {
 
         // Check if the participant has this chunk ID in a pending task.
         if let Some(task) = self.state.lookup_pending_task(participant, chunk_id)?.cloned() {
+            // Check that both the response file and its signature were written, and that the
+            // signature is for this response, so that a partial upload is reported to the
+            // contributor rather than discarding the response.
+            let contribution_id = task.contribution_id();
+            let response = ContributionLocator::new(round_height, chunk_id, contribution_id, false);
+            let signature = ContributionSignatureLocator::new(round_height, chunk_id, contribution_id, false);
+            if !self.storage.exists(&Locator::ContributionFile(response)) {
+                return Err(CoordinatorError::ContributionMissing);
+            }
+            let contribution_file_signature = match self.load_contribution_file_signature(&signature)? {
+                Some(contribution_file_signature) => contribution_file_signature,
+                None => {
+                    error!("The response of {} to chunk {} has no signature", participant, chunk_id);
+                    return Err(CoordinatorError::ContributionSignatureMissing);
+                }
+            };
+            let response_hash = calculate_hash(&*self.storage.reader(&Locator::ContributionFile(response))?);
+            if hex::decode(contribution_file_signature.get_response_hash())? != response_hash.as_slice() {
+                error!(
+                    "The signature of {} to chunk {} is not for the stored response",
+                    participant, chunk_id
+                );
+                return Err(CoordinatorError::ContributionSignatureResponseMismatch);
+            }
+
             debug!("Adding contribution for chunk");
 
             match self.add_contribution(chunk_id, participant) {
@@ -1073,6 +2572,11 @@ impl Coordinator {
                     self.save_state()?;
 
                     info!("Added contribution");
+                    (self.event_callback)(CeremonyEvent::ContributionAccepted {
+                        round_height,
+                        chunk_id,
+                        participant: participant.clone(),
+                    });
                     return Ok(locator);
                 }
                 // Case 2 - Participant failed to add their contribution, remove the contribution file.
@@ -1110,7 +2614,7 @@ impl Coordinator {
     #[tracing::instrument(
         level = "error",
         skip(self, task),
-        fields(participant = %participant),
+        fields(participant = %participant, round = field::Empty, chunk = task.chunk_id()),
         err
     )]
     pub fn try_verify(&mut self, participant: &Participant, task: &Task) -> Result<(), CoordinatorError> {
@@ -1119,6 +2623,8 @@ impl Coordinator {
             return Err(CoordinatorError::ExpectedVerifier);
         }
 
+        self.check_writable()?;
+
         // Check that the chunk ID is valid.
         if task.chunk_id() > self.environment.number_of_chunks() {
             return Err(CoordinatorError::ChunkIdInvalid);
@@ -1139,6 +2645,8 @@ impl Coordinator {
             return Err(CoordinatorError::CurrentRoundAggregated);
         }
 
+        Span::current().record("round", &self.state.current_round_height());
+
         debug!(
             "Adding verification from {} for chunk {} contribution {}",
             participant,
@@ -1158,6 +2666,11 @@ impl Coordinator {
                 self.save_state()?;
 
                 info!("Added verification from {} for chunk {}", participant, task.chunk_id());
+                (self.event_callback)(CeremonyEvent::VerificationCompleted {
+                    round_height: self.state.current_round_height(),
+                    chunk_id: task.chunk_id(),
+                    participant: participant.clone(),
+                });
                 Ok(())
             }
             // Case 2 - Participant failed to add their contribution, remove the contribution file.
@@ -1195,11 +2708,52 @@ impl Coordinator {
         }
     }
 
+    ///
+    /// Claims the next task pending verification for the given verifier, so
+    /// that verification can be shared between several external verifiers.
+    ///
+    /// The verifier must be one of the coordinator verifiers in the environment.
+    /// On success, this function returns the claimed task.
+    ///
+    #[inline]
+    pub fn claim_verification(&mut self, verifier: &Participant) -> Result<Task, CoordinatorError> {
+        self.check_writable()?;
+
+        // Claim the next task pending verification.
+        let task = self.state.claim_verification(verifier, self.time.as_ref())?;
+
+        // Save the coordinator state in storage.
+        self.save_state()?;
+
+        Ok(task)
+    }
+
+    ///
+    /// Submits the verification of a claimed task, once the verifier has uploaded
+    /// the next challenge file and its contribution file signature.
+    ///
+    /// Returns `CoordinatorError::VerificationNotClaimed` if the task is not claimed
+    /// by the verifier, otherwise this checks the verification as [Coordinator::try_verify].
+    ///
+    #[inline]
+    pub fn submit_verification(&mut self, verifier: &Participant, task: &Task) -> Result<(), CoordinatorError> {
+        self.check_writable()?;
+
+        // Check that the task is claimed by the verifier.
+        if !self.state.is_verification_claimed_by(task, verifier) {
+            return Err(CoordinatorError::VerificationNotClaimed);
+        }
+
+        self.try_verify(verifier, task)
+    }
+
     ///
     /// Attempts to aggregate the contributions of the current round of the ceremony.
     ///
     #[inline]
     pub fn try_aggregate(&mut self) -> Result<(), CoordinatorError> {
+        self.check_writable()?;
+
         // Check that the current round height matches in storage and self.
         let current_round_height = {
             // Fetch the current round height from storage.
@@ -1276,6 +2830,8 @@ impl Coordinator {
     ///
     #[tracing::instrument(skip(self, started_at))]
     pub fn try_advance(&mut self, started_at: OffsetDateTime) -> Result<u64, CoordinatorError> {
+        self.check_writable()?;
+
         tracing::debug!("Trying to advance to the next round.");
 
         // Check that the current round height matches in storage and self.
@@ -1327,6 +2883,7 @@ impl Coordinator {
                         // If success, update coordinator state to next round.
                         info!("Coordinator has advanced to round {}", next_round_height);
                         self.state.commit_next_round();
+                        self.clear_challenges();
                         Ok(next_round_height)
                     }
                     // Case 1b - Coordinator failed to advance the round.
@@ -1391,6 +2948,10 @@ impl Coordinator {
     ///
     /// On failure, this function returns a `CoordinatorError`.
     ///
+    #[tracing::instrument(
+        skip(self, chunk_id, participant),
+        fields(participant = %participant, round = field::Empty, chunk = chunk_id)
+    )]
     pub(crate) fn try_lock_chunk(
         &mut self,
         chunk_id: u64,
@@ -1404,6 +2965,7 @@ impl Coordinator {
         // Fetch the current round height from storage.
         let current_round_height = Self::load_current_round_height(&self.storage)?;
         trace!("Current round height from storage is {}", current_round_height);
+        Span::current().record("round", &current_round_height);
 
         // Fetch the current round from storage.
         let mut round = Self::load_current_round(&self.storage)?;
@@ -2038,6 +3600,38 @@ impl Coordinator {
         Ok(new_height)
     }
 
+    ///
+    /// Runs initialization for the ceremony as a dry run, returning the plan for
+    /// each chunk without generating or writing any parameters to storage.
+    ///
+    /// This allows the parameters, curve, and storage space to be checked
+    /// before committing to a full initialization of the ceremony. If storage
+    /// does not have the space for every chunk and its copy, this function
+    /// returns `CoordinatorError::InsufficientDiskSpace`.
+    ///
+    pub fn dry_run_initialization(&mut self) -> Result<Vec<InitializationSummary>, CoordinatorError> {
+        // Establish the round height convention for initialization.
+        let round_height = 0;
+
+        let mut summaries = Vec::new();
+        for chunk_id in 0..self.environment.number_of_chunks() {
+            let summary = Initialization::run(&self.environment, &mut self.storage, round_height, chunk_id, true)?;
+            summaries.push(summary);
+        }
+
+        let required_space: u64 = summaries.iter().map(|summary| summary.required_space()).sum();
+        let available_space = self.storage.available_space()?;
+        info!(
+            "Initialization of {} chunks requires {} bytes of storage with {} bytes available",
+            summaries.len(),
+            required_space,
+            available_space
+        );
+        Initialization::check_available_space(&summaries, available_space)?;
+
+        Ok(summaries)
+    }
+
     ///
     /// Attempts to run initialization for the ceremony.
     ///
@@ -2109,7 +3703,7 @@ impl Coordinator {
             }
 
             info!("Coordinator is starting initialization on chunk {}", chunk_id);
-            let _contribution_hash = Initialization::run(&self.environment, &mut self.storage, round_height, chunk_id)?;
+            let _summary = Initialization::run(&self.environment, &mut self.storage, round_height, chunk_id, false)?;
             info!("Coordinator completed initialization on chunk {}", chunk_id);
 
             // 1 - Check that the contribution locator corresponding to this round's chunk now exists.
@@ -2280,15 +3874,30 @@ impl Coordinator {
     /// previous round to invite new participants into the round.
     ///
     pub fn reset_round(&mut self) -> Result<(), CoordinatorError> {
+        self.check_writable()?;
+
         let reset_action = self.state.reset_current_round(true, &*self.time)?;
 
         self.storage
             .update(&Locator::CoordinatorState, Object::CoordinatorState(self.state.clone()))?;
         self.reset_round_storage(&reset_action)?;
+        self.clear_challenges();
 
         Ok(())
     }
 
+    ///
+    /// Removes all challenges from the cache of [Coordinator::get_challenge].
+    ///
+    fn clear_challenges(&mut self) {
+        match self.challenges.get_mut() {
+            Ok(challenges) => challenges.clear(),
+            Err(poisoned) => poisoned.into_inner().clear(),
+        }
+    }
+
+        self.check_writable()?;
+
     /// Reset the current round in storage.
     ///
     /// + `remove_participants` is a list of participants that will
@@ -2349,6 +3958,8 @@ impl Coordinator {
         contributor_signing_key: &SigningKey,
         contributor_seed: &Seed,
     ) -> Result<(), CoordinatorError> {
+        self.check_writable()?;
+
         let (_chunk_id, locked_locators) = self.try_lock(contributor)?;
         let response_locator = locked_locators.next_contribution();
         let round_height = response_locator.round_height();
@@ -2407,6 +4018,8 @@ impl Coordinator {
         verifier_signing_key: &SigningKey,
         task: &Task,
     ) -> anyhow::Result<()> {
+        self.check_writable()?;
+
         let round_height = self.current_round_height()?;
         debug!(
             "Running verification for round {} chunk {}",
@@ -2439,6 +4052,8 @@ impl Coordinator {
         participant_signing_key: &SigningKey,
         participant_seed: &Seed,
     ) -> Result<(), CoordinatorError> {
+        self.check_writable()?;
+
         info!(
             "Running computation for round {} chunk {} contribution {} as {}",
             round_height, chunk_id, contribution_id, participant
@@ -2527,6 +4142,8 @@ impl Coordinator {
         participant: &Participant,
         participant_signing_key: &SigningKey,
     ) -> Result<LocatorPath, CoordinatorError> {
+        self.check_writable()?;
+
         let chunk_id = task.chunk_id();
         let contribution_id = task.contribution_id();
         info!(
@@ -2584,16 +4201,24 @@ impl Coordinator {
             "Starting verification on round {} chunk {} contribution {} as {}",
             round_height, chunk_id, contribution_id, participant
         );
-        Verification::run(
-            &self.environment,
-            &mut self.storage,
-            self.signature.clone(),
-            participant_signing_key,
-            round_height,
-            chunk_id,
-            contribution_id,
-            is_final_contribution,
-        )?;
+        let (environment, storage, signature) = (&self.environment, &mut self.storage, self.signature.clone());
+        let verify = || {
+            Verification::run(
+                environment,
+                storage,
+                signature,
+                participant_signing_key,
+                round_height,
+                chunk_id,
+                contribution_id,
+                is_final_contribution,
+            )
+        };
+        // Run the verification on the dedicated thread pool, if there is one.
+        match &self.verification_pool {
+            Some(pool) => pool.install(verify)?,
+            None => verify()?,
+        }
         info!(
             "Completed verification on round {} chunk {} contribution {} as {}",
             round_height, chunk_id, contribution_id, participant
@@ -2633,6 +4258,8 @@ impl Coordinator {
     /// chunks which become stuck during the ceremony.
     ///
     pub fn rollback_locked_task(&mut self, participant: &Participant, task: Task) -> Result<(), CoordinatorError> {
+        self.check_writable()?;
+
         self.state.rollback_locked_task(participant, task, &*self.time)?;
         self.save_state()?;
 
@@ -2646,6 +4273,29 @@ impl Coordinator {
             object: Object::RoundState(round),
         }))?)
     }
+
+    ///
+    /// Hands the given task, and the remaining tasks of the contributor holding it,
+    /// to the given coordinator contributor. Should be used to replace a contributor
+    /// who has stopped responding with a chosen replacement, without waiting for them
+    /// to be dropped.
+    ///
+    /// The contributor holding the task is dropped, and their locks are released.
+    ///
+    pub fn reassign_task(&mut self, task: Task, participant: &Participant) -> Result<(), CoordinatorError> {
+        self.check_writable()?;
+
+        // Drop the contributor holding the task, and replace them with the given participant.
+        let drop = self.state.reassign_task(&task, participant, self.time.as_ref())?;
+
+        // Update the round to reflect the coordinator state change.
+        self.drop_participant_from_storage(&drop)?;
+
+        // Save the coordinator state in storage.
+        self.save_state()?;
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -2654,9 +4304,13 @@ mod tests {
         authentication::Dummy,
         commands::{Seed, SigningKey, SEED_LENGTH},
         environment::*,
-        objects::{Participant, Task},
+        objects::{ContributionFileSignature, ContributionState, Participant, Task},
+        storage::{ContributionLocator, ContributionSignatureLocator, Locator, Object},
         testing::prelude::*,
         Coordinator,
+        CoordinatorError,
+        MockTimeSource,
+        SignedTime,
     };
 
     use once_cell::sync::Lazy;
@@ -2749,218 +4403,756 @@ mod tests {
         initialize_to_round_1(coordinator, &contributors)
     }
 
+    ///
+    /// Locks the given chunk for the given contributor, runs computation on it for
+    /// contribution 1 of round 1, and returns the response file and contribution
+    /// file signature written to storage.
+    ///
+    fn contribute_chunk(
+        coordinator: &mut Coordinator,
+        contributor: &Participant,
+        chunk_id: u64,
+    ) -> anyhow::Result<(Vec<u8>, ContributionFileSignature)> {
+        let contributor_signing_key: SigningKey = "secret_key".to_string();
+
+        coordinator.try_lock_chunk(chunk_id, contributor)?;
+        let mut seed: Seed = [0; SEED_LENGTH];
+        rand::thread_rng().fill_bytes(&mut seed[..]);
+        coordinator.run_computation(1, chunk_id, 1, contributor, &contributor_signing_key, &seed)?;
+
+        let response_locator = ContributionLocator::new(1, chunk_id, 1, false);
+        let signature_locator = ContributionSignatureLocator::new(1, chunk_id, 1, false);
+        let response = match coordinator.storage.get(&Locator::ContributionFile(response_locator))? {
+            Object::ContributionFile(response) => response,
+            _ => panic!("Expected a contribution file"),
+        };
+        let signature = match coordinator
+            .storage
+            .get(&Locator::ContributionFileSignature(signature_locator))?
+        {
+            Object::ContributionFileSignature(signature) => signature,
+            _ => panic!("Expected a contribution file signature"),
+        };
+
+        Ok((response, signature))
+    }
+
+    #[test]
+    #[serial]
+    fn coordinator_initialization_matches_json() -> anyhow::Result<()> {
+        initialize_test_environment(&TEST_ENVIRONMENT);
+
+        let mut coordinator = Coordinator::new(TEST_ENVIRONMENT.clone(), Arc::new(Dummy))?;
+        initialize_coordinator(&mut coordinator)?;
+
+        // Check that round 0 matches the round 0 JSON specification.
+        {
+            let now = OffsetDateTime::now_utc();
+
+            // Fetch round 0 from coordinator.
+            let mut expected = test_round_0_json()?;
+            let mut candidate = coordinator.get_round(0)?;
+
+            // Set the finish time to match.
+            println!("{} {}", expected.is_complete(), candidate.is_complete());
+            expected.try_finish_testing_only_unsafe(now.clone());
+            candidate.try_finish_testing_only_unsafe(now);
+
+            print_diff(&expected, &candidate);
+            assert_eq!(expected, candidate);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    #[serial]
+    fn coordinator_initialization() -> anyhow::Result<()> {
+        initialize_test_environment(&TEST_ENVIRONMENT);
+
+        let mut coordinator = Coordinator::new(TEST_ENVIRONMENT.clone(), Arc::new(Dummy))?;
+
+        {
+            // Run initialization.
+            info!("Initializing ceremony");
+            let round_height = coordinator.run_initialization(*TEST_STARTED_AT).unwrap();
+            info!("Initialized ceremony");
+
+            // Check current round height is now 0.
+            assert_eq!(0, round_height);
+
+            // Load the contributors and verifiers.
+            let contributors = vec![
+                Lazy::force(&TEST_CONTRIBUTOR_ID).clone(),
+                Lazy::force(&TEST_CONTRIBUTOR_ID_2).clone(),
+            ];
+
+            // Start round 1.
+            coordinator.next_round(*TEST_STARTED_AT, contributors).unwrap();
+        }
+
+        {
+            // Check round 0 is complete.
+            assert!(coordinator.get_round(0)?.is_complete());
+
+            // Check current round height is now 1.
+            assert_eq!(1, coordinator.current_round_height()?);
+
+            // Check that the coordinator participants are established correctly.
+            assert!(coordinator.is_coordinator_contributor(&TEST_CONTRIBUTOR_ID));
+            assert!(coordinator.is_coordinator_verifier(&TEST_VERIFIER_ID));
+
+            // Check the current round has a matching height.
+            let current_round = coordinator.current_round()?;
+            assert_eq!(coordinator.current_round_height()?, current_round.round_height());
+
+            // Check round 1 contributors.
+            assert_eq!(2, current_round.number_of_contributors());
+            assert!(current_round.is_contributor(&TEST_CONTRIBUTOR_ID));
+            assert!(current_round.is_contributor(&TEST_CONTRIBUTOR_ID_2));
+            assert!(!current_round.is_contributor(&TEST_CONTRIBUTOR_ID_3));
+            assert!(!current_round.is_contributor(&TEST_VERIFIER_ID));
+
+            // Check round 1 verifiers.
+            assert_eq!(0, current_round.number_of_verifiers());
+
+            // Check round 1 is NOT complete.
+            assert!(!current_round.is_complete());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    #[serial]
+    fn coordinator_contributor_try_lock_chunk() -> anyhow::Result<()> {
+        initialize_test_environment(&TEST_ENVIRONMENT);
+
+        let contributor = Lazy::force(&TEST_CONTRIBUTOR_ID);
+        let contributor_2 = Lazy::force(&TEST_CONTRIBUTOR_ID_2);
+
+        let mut coordinator = Coordinator::new(TEST_ENVIRONMENT.clone(), Arc::new(Dummy))?;
+        initialize_coordinator(&mut coordinator)?;
+
+        {
+            // Acquire the lock for chunk 0 as contributor 1.
+            assert!(coordinator.try_lock_chunk(0, &contributor).is_ok());
+
+            // Attempt to acquire the lock for chunk 0 as contributor 1 again.
+            assert!(coordinator.try_lock_chunk(0, &contributor).is_err());
+
+            // Acquire the lock for chunk 1 as contributor 1.
+            assert!(coordinator.try_lock_chunk(1, &contributor).is_ok());
+
+            // Attempt to acquire the lock for chunk 0 as contributor 2.
+            assert!(coordinator.try_lock_chunk(0, &contributor_2).is_err());
+
+            // Attempt to acquire the lock for chunk 1 as contributor 2.
+            assert!(coordinator.try_lock_chunk(1, &contributor_2).is_err());
+
+            // Acquire the lock for chunk 1 as contributor 2.
+            assert!(coordinator.try_lock_chunk(2, &contributor_2).is_ok());
+        }
+
+        {
+            // Check that chunk 0 is locked.
+            let round = coordinator.current_round()?;
+            let chunk = round.chunk(0)?;
+            assert!(chunk.is_locked());
+            assert!(!chunk.is_unlocked());
+
+            // Check that chunk 0 is locked by contributor 1.
+            assert!(chunk.is_locked_by(contributor));
+            assert!(!chunk.is_locked_by(contributor_2));
+
+            // Check that chunk 1 is locked.
+            let chunk = round.chunk(1)?;
+            assert!(chunk.is_locked());
+            assert!(!chunk.is_unlocked());
+
+            // Check that chunk 1 is locked by contributor 1.
+            assert!(chunk.is_locked_by(contributor));
+            assert!(!chunk.is_locked_by(contributor_2));
+
+            // Check that chunk 2 is locked.
+            let chunk = round.chunk(2)?;
+            assert!(chunk.is_locked());
+            assert!(!chunk.is_unlocked());
+
+            // Check that chunk 2 is locked by contributor 2.
+            assert!(chunk.is_locked_by(contributor_2));
+            assert!(!chunk.is_locked_by(contributor));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    #[serial]
+    fn coordinator_contributor_add_contribution() -> anyhow::Result<()> {
+        initialize_test_environment(&TEST_ENVIRONMENT_3);
+
+        let contributor = Lazy::force(&TEST_CONTRIBUTOR_ID).clone();
+        let contributor_signing_key: SigningKey = "secret_key".to_string();
+
+        let mut coordinator = Coordinator::new(TEST_ENVIRONMENT_3.clone(), Arc::new(Dummy))?;
+        initialize_coordinator(&mut coordinator)?;
+
+        {
+            // Acquire the lock for chunk 0 as contributor 1.
+            coordinator.try_lock_chunk(0, &contributor)?;
+        }
+
+        // Run computation on round 1 chunk 0 contribution 1.
+        {
+            // Check current round is 1.
+            let round = coordinator.current_round()?;
+            let round_height = round.round_height();
+            assert_eq!(1, round_height);
+
+            // Check chunk 0 is not verified.
+            let chunk_id = 0;
+            let chunk = round.chunk(chunk_id)?;
+            assert!(!chunk.is_complete(round.expected_number_of_contributions()));
+
+            // Check next contribution is 1.
+            let contribution_id = 1;
+            assert!(chunk.is_next_contribution_id(contribution_id, round.expected_number_of_contributions()));
+
+            // Run the computation
+            let mut seed: Seed = [0; SEED_LENGTH];
+            rand::thread_rng().fill_bytes(&mut seed[..]);
+            assert!(
+                coordinator
+                    .run_computation(
+                        round_height,
+                        chunk_id,
+                        contribution_id,
+                        &contributor,
+                        &contributor_signing_key,
+                        &seed
+                    )
+                    .is_ok()
+            );
+        }
+
+        // Add contribution for round 1 chunk 0 contribution 1.
+        let chunk_id = 0;
+        {
+            // Add round 1 chunk 0 contribution 1.
+            assert!(coordinator.add_contribution(chunk_id, &contributor).is_ok());
+        }
+        {
+            // Check chunk 0 lock is released.
+            let round = coordinator.current_round()?;
+            let chunk = round.chunk(chunk_id)?;
+            assert!(chunk.is_unlocked());
+            assert!(!chunk.is_locked());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    #[serial]
+    fn coordinator_get_contribution_file() -> anyhow::Result<()> {
+        initialize_test_environment(&TEST_ENVIRONMENT_3);
+
+        let contributor = Lazy::force(&TEST_CONTRIBUTOR_ID).clone();
+        let contributor_signing_key: SigningKey = "secret_key".to_string();
+
+        let mut coordinator = Coordinator::new(TEST_ENVIRONMENT_3.clone(), Arc::new(Dummy))?;
+        initialize_coordinator(&mut coordinator)?;
+
+        // The initial challenge of round 1 chunk 0 is verified.
+        let verified = ContributionLocator::new(1, 0, 0, true);
+        assert!(!coordinator.get_contribution_file(&verified, false)?.is_empty());
+
+        // Add round 1 chunk 0 contribution 1.
+        let chunk_id = 0;
+        coordinator.try_lock_chunk(chunk_id, &contributor)?;
+        let mut seed: Seed = [0; SEED_LENGTH];
+        rand::thread_rng().fill_bytes(&mut seed[..]);
+        coordinator.run_computation(1, chunk_id, 1, &contributor, &contributor_signing_key, &seed)?;
+        coordinator.add_contribution(chunk_id, &contributor)?;
+
+        // The unverified contribution is only returned to privileged callers.
+        let unverified = ContributionLocator::new(1, chunk_id, 1, false);
+        assert!(matches!(
+            coordinator.get_contribution_file(&unverified, false),
+            Err(CoordinatorError::ContributionMissingVerification)
+        ));
+        assert!(!coordinator.get_contribution_file(&unverified, true)?.is_empty());
+
+        // A contribution which does not exist is rejected.
+        let missing = ContributionLocator::new(1, chunk_id, 2, false);
+        assert!(matches!(
+            coordinator.get_contribution_file(&missing, true),
+            Err(CoordinatorError::ContributionMissing)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    #[serial]
+    fn coordinator_find_contribution() -> anyhow::Result<()> {
+        initialize_test_environment(&TEST_ENVIRONMENT_3);
+
+        let contributor = Lazy::force(&TEST_CONTRIBUTOR_ID).clone();
+        let contributor_signing_key: SigningKey = "secret_key".to_string();
+
+        let mut coordinator = Coordinator::new(TEST_ENVIRONMENT_3.clone(), Arc::new(Dummy))?;
+        initialize_coordinator(&mut coordinator)?;
+
+        // Add round 1 chunk 0 contribution 1.
+        let chunk_id = 0;
+        coordinator.try_lock_chunk(chunk_id, &contributor)?;
+        let mut seed: Seed = [0; SEED_LENGTH];
+        rand::thread_rng().fill_bytes(&mut seed[..]);
+        coordinator.run_computation(1, chunk_id, 1, &contributor, &contributor_signing_key, &seed)?;
+        coordinator.add_contribution(chunk_id, &contributor)?;
+
+        // The contribution is found by the hash of its response file.
+        let locator = ContributionLocator::new(1, chunk_id, 1, false);
+        let response_hash = hex::encode(calculate_hash(&coordinator.get_contribution_file(&locator, true)?));
+        assert_eq!(Some(locator), coordinator.find_contribution(&response_hash)?);
+        assert_eq!(
+            Some(locator),
+            coordinator.find_contribution(&response_hash.to_uppercase())?
+        );
+
+        // A hash which matches no contribution is not found.
+        assert_eq!(None, coordinator.find_contribution(&hex::encode([0u8; 64]))?);
+
+        Ok(())
+    }
+
+    #[test]
+    #[serial]
+    fn coordinator_merkle_proof() -> anyhow::Result<()> {
+        initialize_test_environment(&TEST_ENVIRONMENT_3);
+
+        let contributor = Lazy::force(&TEST_CONTRIBUTOR_ID).clone();
+        let contributor_signing_key: SigningKey = "secret_key".to_string();
+
+        let mut coordinator = Coordinator::new(TEST_ENVIRONMENT_3.clone(), Arc::new(Dummy))?;
+        initialize_coordinator(&mut coordinator)?;
+        let empty_root = coordinator.merkle_root(1)?;
+
+        // Add round 1 chunk 0 contribution 1.
+        let chunk_id = 0;
+        coordinator.try_lock_chunk(chunk_id, &contributor)?;
+        let mut seed: Seed = [0; SEED_LENGTH];
+        rand::thread_rng().fill_bytes(&mut seed[..]);
+        coordinator.run_computation(1, chunk_id, 1, &contributor, &contributor_signing_key, &seed)?;
+        coordinator.add_contribution(chunk_id, &contributor)?;
+
+        // The root changes once the contribution is added, and the contribution is included in it.
+        let root = coordinator.merkle_root(1)?;
+        assert_ne!(empty_root, root);
+
+        let locator = ContributionLocator::new(1, chunk_id, 1, false);
+        let response_hash = calculate_hash(&coordinator.get_contribution_file(&locator, true)?);
+        let proof = coordinator.merkle_proof(&locator)?;
+        assert!(proof.verify(&response_hash, &hex::decode(&root)?));
+
+        // A contribution which does not exist has no proof.
+        assert!(matches!(
+            coordinator.merkle_proof(&ContributionLocator::new(1, chunk_id, 2, false)),
+            Err(CoordinatorError::ContributionMissing)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    #[serial]
+    fn coordinator_current_round_chunks() -> anyhow::Result<()> {
+        initialize_test_environment(&TEST_ENVIRONMENT_3);
+
+        let contributor = Lazy::force(&TEST_CONTRIBUTOR_ID).clone();
+        let contributor_signing_key: SigningKey = "secret_key".to_string();
+
+        let mut coordinator = Coordinator::new(TEST_ENVIRONMENT_3.clone(), Arc::new(Dummy))?;
+        initialize_coordinator(&mut coordinator)?;
+
+        // Every chunk of round 1 starts with its verified challenge.
+        let round_chunks = coordinator.current_round_chunks()?;
+        assert_eq!(1, round_chunks.round_height);
+        assert_eq!(TEST_ENVIRONMENT_3.number_of_chunks(), round_chunks.number_of_chunks);
+        assert!(round_chunks.chunks.iter().all(|chunk| chunk.is_verified));
+
+        // Add round 1 chunk 0 contribution 1, which is not yet verified.
+        let chunk_id = 0;
+        coordinator.try_lock_chunk(chunk_id, &contributor)?;
+        let mut seed: Seed = [0; SEED_LENGTH];
+        rand::thread_rng().fill_bytes(&mut seed[..]);
+        coordinator.run_computation(1, chunk_id, 1, &contributor, &contributor_signing_key, &seed)?;
+        coordinator.add_contribution(chunk_id, &contributor)?;
+
+        let round_chunks = coordinator.current_round_chunks()?;
+        assert_eq!(1, round_chunks.chunks[0].current_contribution_id);
+        assert!(!round_chunks.chunks[0].is_verified);
+        assert!(round_chunks.chunks[1].is_verified);
+
+        Ok(())
+    }
+
+    #[test]
+    #[serial]
+    fn coordinator_initialize_number_of_chunks_mismatch() -> anyhow::Result<()> {
+        initialize_test_environment(&TEST_ENVIRONMENT_3);
+
+        let mut coordinator = Coordinator::new(TEST_ENVIRONMENT_3.clone(), Arc::new(Dummy))?;
+        coordinator.initialize()?;
+
+        // Restart the coordinator on the same storage with a different number of chunks.
+        let environment: Environment = Testing::from(Parameters::TestChunks { number_of_chunks: 4 }).into();
+        assert_ne!(TEST_ENVIRONMENT_3.number_of_chunks(), environment.number_of_chunks());
+
+        let mut coordinator = Coordinator::new(environment, Arc::new(Dummy))?;
+        assert!(matches!(
+            coordinator.initialize(),
+            Err(CoordinatorError::NumberOfChunksInvalid)
+        ));
+
+        Ok(())
+    }
+
     #[test]
     #[serial]
-    fn coordinator_initialization_matches_json() -> anyhow::Result<()> {
-        initialize_test_environment(&TEST_ENVIRONMENT);
+    fn coordinator_get_challenge_truncated() -> anyhow::Result<()> {
+        initialize_test_environment(&TEST_ENVIRONMENT_3);
 
-        let mut coordinator = Coordinator::new(TEST_ENVIRONMENT.clone(), Arc::new(Dummy))?;
+        let mut coordinator = Coordinator::new(TEST_ENVIRONMENT_3.clone(), Arc::new(Dummy))?;
         initialize_coordinator(&mut coordinator)?;
 
-        // Check that round 0 matches the round 0 JSON specification.
-        {
-            let now = OffsetDateTime::now_utc();
-
-            // Fetch round 0 from coordinator.
-            let mut expected = test_round_0_json()?;
-            let mut candidate = coordinator.get_round(0)?;
+        // Truncate the challenge of round 1 chunk 1 in storage.
+        let locator = ContributionLocator::new(1, 1, 0, true);
+        let challenge = coordinator.get_contribution_file(&locator, false)?;
+        coordinator.storage.update(
+            &Locator::ContributionFile(locator),
+            Object::ContributionFile(challenge[..challenge.len() / 2].to_vec()),
+        )?;
 
-            // Set the finish time to match.
-            println!("{} {}", expected.is_complete(), candidate.is_complete());
-            expected.try_finish_testing_only_unsafe(now.clone());
-            candidate.try_finish_testing_only_unsafe(now);
+        assert!(matches!(
+            coordinator.get_challenge(1, 1),
+            Err(CoordinatorError::ChallengeCorrupt { size, expected })
+                if size == challenge.len() as u64 / 2 && expected == challenge.len() as u64
+        ));
 
-            print_diff(&expected, &candidate);
-            assert_eq!(expected, candidate);
-        }
+        // The challenges of other chunks are unaffected.
+        let unaffected = ContributionLocator::new(1, 0, 0, true);
+        assert_eq!(
+            coordinator.get_contribution_file(&unaffected, false)?,
+            *coordinator.get_challenge(1, 0)?
+        );
 
         Ok(())
     }
 
     #[test]
     #[serial]
-    fn coordinator_initialization() -> anyhow::Result<()> {
+    fn coordinator_sign_response() -> anyhow::Result<()> {
         initialize_test_environment(&TEST_ENVIRONMENT);
 
-        let mut coordinator = Coordinator::new(TEST_ENVIRONMENT.clone(), Arc::new(Dummy))?;
-
-        {
-            // Run initialization.
-            info!("Initializing ceremony");
-            let round_height = coordinator.run_initialization(*TEST_STARTED_AT).unwrap();
-            info!("Initialized ceremony");
+        let coordinator = Coordinator::new(TEST_ENVIRONMENT.clone(), Arc::new(Dummy))?;
+        let signing_key: SigningKey = "secret_key".to_string();
 
-            // Check current round height is now 0.
-            assert_eq!(0, round_height);
+        let body = b"challenge bytes";
+        let signature = coordinator.sign_response(&signing_key, body)?;
+        assert!(coordinator.verify_response("public_key", body, &signature));
+        assert!(!coordinator.verify_response("public_key", b"tampered bytes", &signature));
 
-            // Load the contributors and verifiers.
-            let contributors = vec![
-                Lazy::force(&TEST_CONTRIBUTOR_ID).clone(),
-                Lazy::force(&TEST_CONTRIBUTOR_ID_2).clone(),
-            ];
+        Ok(())
+    }
 
-            // Start round 1.
-            coordinator.next_round(*TEST_STARTED_AT, contributors).unwrap();
-        }
+    #[test]
+    #[serial]
+    fn coordinator_signed_time() -> anyhow::Result<()> {
+        initialize_test_environment(&TEST_ENVIRONMENT);
 
-        {
-            // Check round 0 is complete.
-            assert!(coordinator.get_round(0)?.is_complete());
+        let time = Arc::new(MockTimeSource::new(OffsetDateTime::from_unix_timestamp(1_600_000_000)?));
+        let coordinator = Coordinator::new_with_time(TEST_ENVIRONMENT.clone(), Arc::new(Dummy), time)?;
+        let signing_key: SigningKey = "secret_key".to_string();
 
-            // Check current round height is now 1.
-            assert_eq!(1, coordinator.current_round_height()?);
+        let signed_time = coordinator.signed_time(&signing_key, "nonce")?;
+        assert_eq!(1_600_000_000, signed_time.unix_time);
+        assert_eq!(1_600_000_000, signed_time.verify(&Dummy, "public_key", "nonce")?);
 
-            // Check that the coordinator participants are established correctly.
-            assert!(coordinator.is_coordinator_contributor(&TEST_CONTRIBUTOR_ID));
-            assert!(coordinator.is_coordinator_verifier(&TEST_VERIFIER_ID));
+        // Check that a response to another request is rejected.
+        assert!(matches!(
+            signed_time.verify(&Dummy, "public_key", "other_nonce"),
+            Err(CoordinatorError::SignedTimeNonceMismatch)
+        ));
 
-            // Check the current round has a matching height.
-            let current_round = coordinator.current_round()?;
-            assert_eq!(coordinator.current_round_height()?, current_round.round_height());
+        // Check that a skewed time is rejected.
+        let skewed_time = SignedTime {
+            unix_time: 1_600_000_001,
+            ..signed_time
+        };
+        assert!(matches!(
+            skewed_time.verify(&Dummy, "public_key", "nonce"),
+            Err(CoordinatorError::SignedTimeInvalid)
+        ));
 
-            // Check round 1 contributors.
-            assert_eq!(2, current_round.number_of_contributors());
-            assert!(current_round.is_contributor(&TEST_CONTRIBUTOR_ID));
-            assert!(current_round.is_contributor(&TEST_CONTRIBUTOR_ID_2));
-            assert!(!current_round.is_contributor(&TEST_CONTRIBUTOR_ID_3));
-            assert!(!current_round.is_contributor(&TEST_VERIFIER_ID));
+        Ok(())
+    }
 
-            // Check round 1 verifiers.
-            assert_eq!(0, current_round.number_of_verifiers());
+    #[test]
+    #[serial]
+    fn coordinator_heartbeat_interval() -> anyhow::Result<()> {
+        let environment: Environment = Testing::from(Parameters::Test3Chunks)
+            .contributor_seen_timeout(time::Duration::minutes(6))
+            .into();
+        initialize_test_environment(&environment);
 
-            // Check round 1 is NOT complete.
-            assert!(!current_round.is_complete());
-        }
+        let coordinator = Coordinator::new(environment, Arc::new(Dummy))?;
+        assert_eq!(time::Duration::minutes(2), coordinator.heartbeat_interval());
 
         Ok(())
     }
 
+    #[test]
+    fn coordinator_error_retry_after() {
+        assert!(CoordinatorError::ChunkLockAlreadyAcquired.is_temporary());
+        assert!(CoordinatorError::Paused.retry_after().is_some());
+        assert!(!CoordinatorError::ParticipantUnauthorized.is_temporary());
+        assert!(CoordinatorError::ContributionMissing.retry_after().is_none());
+    }
+
     #[test]
     #[serial]
-    fn coordinator_contributor_try_lock_chunk() -> anyhow::Result<()> {
-        initialize_test_environment(&TEST_ENVIRONMENT);
+    fn coordinator_write_contribution_invalid_signature() -> anyhow::Result<()> {
+        initialize_test_environment(&TEST_ENVIRONMENT_3);
 
-        let contributor = Lazy::force(&TEST_CONTRIBUTOR_ID);
-        let contributor_2 = Lazy::force(&TEST_CONTRIBUTOR_ID_2);
+        let contributor = Lazy::force(&TEST_CONTRIBUTOR_ID).clone();
 
-        let mut coordinator = Coordinator::new(TEST_ENVIRONMENT.clone(), Arc::new(Dummy))?;
+        let mut coordinator = Coordinator::new(TEST_ENVIRONMENT_3.clone(), Arc::new(Dummy))?;
         initialize_coordinator(&mut coordinator)?;
 
-        {
-            // Acquire the lock for chunk 0 as contributor 1.
-            assert!(coordinator.try_lock_chunk(0, &contributor).is_ok());
+        // Run computation on round 1 chunk 0 contribution 1, and remove the
+        // response file and contribution file signature from storage.
+        let chunk_id = 0;
+        let (response, signature) = contribute_chunk(&mut coordinator, &contributor, chunk_id)?;
+        let response_locator = ContributionLocator::new(1, chunk_id, 1, false);
+        let signature_locator = ContributionSignatureLocator::new(1, chunk_id, 1, false);
+        coordinator
+            .storage
+            .remove(&Locator::ContributionFile(response_locator))?;
+        coordinator
+            .storage
+            .remove(&Locator::ContributionFileSignature(signature_locator))?;
+
+        // Upload the response file with a wrong signature.
+        let wrong_signature =
+            ContributionFileSignature::new(hex::encode(vec![0u8; 64]), signature.get_state().clone())?;
+        assert!(matches!(
+            coordinator.write_contribution(
+                &contributor,
+                response_locator,
+                response.clone(),
+                signature_locator,
+                wrong_signature
+            ),
+            Err(CoordinatorError::ContributorSignatureInvalid)
+        ));
+        assert!(!coordinator.storage.exists(&Locator::ContributionFile(response_locator)));
 
-            // Attempt to acquire the lock for chunk 0 as contributor 1 again.
-            assert!(coordinator.try_lock_chunk(0, &contributor).is_err());
+        // Upload the response file with the correct signature.
+        coordinator.write_contribution(&contributor, response_locator, response, signature_locator, signature)?;
+        assert!(coordinator.storage.exists(&Locator::ContributionFile(response_locator)));
+        assert!(coordinator.add_contribution(chunk_id, &contributor).is_ok());
 
-            // Acquire the lock for chunk 1 as contributor 1.
-            assert!(coordinator.try_lock_chunk(1, &contributor).is_ok());
+        Ok(())
+    }
 
-            // Attempt to acquire the lock for chunk 0 as contributor 2.
-            assert!(coordinator.try_lock_chunk(0, &contributor_2).is_err());
+    #[test]
+    #[serial]
+    fn coordinator_write_contribution_foreign_locator() -> anyhow::Result<()> {
+        initialize_test_environment(&TEST_ENVIRONMENT_3);
 
-            // Attempt to acquire the lock for chunk 1 as contributor 2.
-            assert!(coordinator.try_lock_chunk(1, &contributor_2).is_err());
+        let contributor = Lazy::force(&TEST_CONTRIBUTOR_ID).clone();
 
-            // Acquire the lock for chunk 1 as contributor 2.
-            assert!(coordinator.try_lock_chunk(2, &contributor_2).is_ok());
-        }
+        let mut coordinator = Coordinator::new(TEST_ENVIRONMENT_3.clone(), Arc::new(Dummy))?;
+        initialize_coordinator(&mut coordinator)?;
 
-        {
-            // Check that chunk 0 is locked.
-            let round = coordinator.current_round()?;
-            let chunk = round.chunk(0)?;
-            assert!(chunk.is_locked());
-            assert!(!chunk.is_unlocked());
+        // Run computation on round 1 chunk 0 contribution 1.
+        let chunk_id = 0;
+        let (response, signature) = contribute_chunk(&mut coordinator, &contributor, chunk_id)?;
+        let response_locator = ContributionLocator::new(1, chunk_id, 1, false);
+        let signature_locator = ContributionSignatureLocator::new(1, chunk_id, 1, false);
+
+        // Check that the response cannot be uploaded to a chunk the contributor has not locked.
+        let foreign_locator = ContributionLocator::new(1, 1, 1, false);
+        let foreign_signature_locator = ContributionSignatureLocator::new(1, 1, 1, false);
+        assert!(matches!(
+            coordinator.write_contribution(
+                &contributor,
+                foreign_locator,
+                response.clone(),
+                foreign_signature_locator,
+                signature.clone()
+            ),
+            Err(CoordinatorError::ParticipantUnauthorized)
+        ));
+        assert!(!coordinator.storage.exists(&Locator::ContributionFile(foreign_locator)));
+
+        // Check that the response cannot be uploaded to another contribution of the locked chunk.
+        let foreign_locator = ContributionLocator::new(1, chunk_id, 2, false);
+        assert!(matches!(
+            coordinator.write_contribution(
+                &contributor,
+                foreign_locator,
+                response.clone(),
+                signature_locator,
+                signature.clone()
+            ),
+            Err(CoordinatorError::ParticipantUnauthorized)
+        ));
 
-            // Check that chunk 0 is locked by contributor 1.
-            assert!(chunk.is_locked_by(contributor));
-            assert!(!chunk.is_locked_by(contributor_2));
+        // Check that the response can be uploaded to the locators of the lock.
+        coordinator.write_contribution(&contributor, response_locator, response, signature_locator, signature)?;
 
-            // Check that chunk 1 is locked.
-            let chunk = round.chunk(1)?;
-            assert!(chunk.is_locked());
-            assert!(!chunk.is_unlocked());
+        Ok(())
+    }
 
-            // Check that chunk 1 is locked by contributor 1.
-            assert!(chunk.is_locked_by(contributor));
-            assert!(!chunk.is_locked_by(contributor_2));
+    #[test]
+    #[serial]
+    fn coordinator_write_contribution_size_mismatch() -> anyhow::Result<()> {
+        initialize_test_environment(&TEST_ENVIRONMENT_3);
 
-            // Check that chunk 2 is locked.
-            let chunk = round.chunk(2)?;
-            assert!(chunk.is_locked());
-            assert!(!chunk.is_unlocked());
+        let contributor = Lazy::force(&TEST_CONTRIBUTOR_ID).clone();
 
-            // Check that chunk 2 is locked by contributor 2.
-            assert!(chunk.is_locked_by(contributor_2));
-            assert!(!chunk.is_locked_by(contributor));
-        }
+        let mut coordinator = Coordinator::new(TEST_ENVIRONMENT_3.clone(), Arc::new(Dummy))?;
+        initialize_coordinator(&mut coordinator)?;
+
+        // Run computation on round 1 chunk 0 contribution 1.
+        let chunk_id = 0;
+        let (response, signature) = contribute_chunk(&mut coordinator, &contributor, chunk_id)?;
+        let response_locator = ContributionLocator::new(1, chunk_id, 1, false);
+        let signature_locator = ContributionSignatureLocator::new(1, chunk_id, 1, false);
+
+        // Check that a truncated response is rejected, and the stored response is left unchanged.
+        let truncated = response[..response.len() / 2].to_vec();
+        assert!(matches!(
+            coordinator.write_contribution(&contributor, response_locator, truncated, signature_locator, signature),
+            Err(CoordinatorError::ContributionFileSizeMismatch)
+        ));
+        assert!(matches!(
+            coordinator.storage.get(&Locator::ContributionFile(response_locator))?,
+            Object::ContributionFile(stored) if stored == response
+        ));
 
         Ok(())
     }
 
     #[test]
     #[serial]
-    fn coordinator_contributor_add_contribution() -> anyhow::Result<()> {
+    fn coordinator_try_contribute_missing_signature() -> anyhow::Result<()> {
         initialize_test_environment(&TEST_ENVIRONMENT_3);
 
         let contributor = Lazy::force(&TEST_CONTRIBUTOR_ID).clone();
-        let contributor_signing_key: SigningKey = "secret_key".to_string();
 
         let mut coordinator = Coordinator::new(TEST_ENVIRONMENT_3.clone(), Arc::new(Dummy))?;
         initialize_coordinator(&mut coordinator)?;
 
-        {
-            // Acquire the lock for chunk 0 as contributor 1.
-            coordinator.try_lock_chunk(0, &contributor)?;
-        }
-
         // Run computation on round 1 chunk 0 contribution 1.
-        {
-            // Check current round is 1.
-            let round = coordinator.current_round()?;
-            let round_height = round.round_height();
-            assert_eq!(1, round_height);
+        let chunk_id = 0;
+        let (response, signature) = contribute_chunk(&mut coordinator, &contributor, chunk_id)?;
+        let response_locator = ContributionLocator::new(1, chunk_id, 1, false);
+        let signature_locator = ContributionSignatureLocator::new(1, chunk_id, 1, false);
+
+        // Remove the contribution file signature, as if writing it had failed.
+        coordinator
+            .storage
+            .remove(&Locator::ContributionFileSignature(signature_locator))?;
+
+        // Check that the contribution is not finalized, and that the response file is kept.
+        assert!(matches!(
+            coordinator.try_contribute(&contributor, chunk_id),
+            Err(CoordinatorError::ContributionSignatureMissing)
+        ));
+        assert!(coordinator.storage.exists(&Locator::ContributionFile(response_locator)));
+
+        // Replace the response, as if a later post had failed to write its signature.
+        let mut other_response = response.clone();
+        other_response[100] ^= 1;
+        coordinator.storage.update(
+            &Locator::ContributionFile(response_locator),
+            Object::ContributionFile(other_response),
+        )?;
+        coordinator.storage.insert(
+            Locator::ContributionFileSignature(signature_locator),
+            Object::ContributionFileSignature(signature.clone()),
+        )?;
 
-            // Check chunk 0 is not verified.
-            let chunk_id = 0;
-            let chunk = round.chunk(chunk_id)?;
-            assert!(!chunk.is_complete(round.expected_number_of_contributions()));
+        // Check that the contribution is not finalized with the signature of another response.
+        assert!(matches!(
+            coordinator.try_contribute(&contributor, chunk_id),
+            Err(CoordinatorError::ContributionSignatureResponseMismatch)
+        ));
+        assert!(coordinator.storage.exists(&Locator::ContributionFile(response_locator)));
 
-            // Check next contribution is 1.
-            let contribution_id = 1;
-            assert!(chunk.is_next_contribution_id(contribution_id, round.expected_number_of_contributions()));
+        // Post the contribution again with its signature, and finalize it.
+        coordinator.write_contribution(&contributor, response_locator, response, signature_locator, signature)?;
+        assert_eq!(response_locator, coordinator.try_contribute(&contributor, chunk_id)?);
 
-            // Run the computation
-            let mut seed: Seed = [0; SEED_LENGTH];
-            rand::thread_rng().fill_bytes(&mut seed[..]);
-            assert!(
-                coordinator
-                    .run_computation(
-                        round_height,
-                        chunk_id,
-                        contribution_id,
-                        &contributor,
-                        &contributor_signing_key,
-                        &seed
-                    )
-                    .is_ok()
-            );
-        }
+        Ok(())
+    }
 
-        // Add contribution for round 1 chunk 0 contribution 1.
+    #[test]
+    #[serial]
+    fn coordinator_upload_contribution_parts() -> anyhow::Result<()> {
+        initialize_test_environment(&TEST_ENVIRONMENT_3);
+
+        let contributor = Lazy::force(&TEST_CONTRIBUTOR_ID).clone();
+
+        let mut coordinator = Coordinator::new(TEST_ENVIRONMENT_3.clone(), Arc::new(Dummy))?;
+        initialize_coordinator(&mut coordinator)?;
+
+        // Run computation on round 1 chunk 0 contribution 1, and remove the
+        // response file and contribution file signature from storage.
         let chunk_id = 0;
-        {
-            // Add round 1 chunk 0 contribution 1.
-            assert!(coordinator.add_contribution(chunk_id, &contributor).is_ok());
-        }
-        {
-            // Check chunk 0 lock is released.
-            let round = coordinator.current_round()?;
-            let chunk = round.chunk(chunk_id)?;
-            assert!(chunk.is_unlocked());
-            assert!(!chunk.is_locked());
-        }
+        let (response, signature) = contribute_chunk(&mut coordinator, &contributor, chunk_id)?;
+        let response_locator = ContributionLocator::new(1, chunk_id, 1, false);
+        let signature_locator = ContributionSignatureLocator::new(1, chunk_id, 1, false);
+        coordinator
+            .storage
+            .remove(&Locator::ContributionFile(response_locator))?;
+        coordinator
+            .storage
+            .remove(&Locator::ContributionFileSignature(signature_locator))?;
+
+        // Upload the first half of the response file.
+        let (first, second) = response.split_at(response.len() / 2);
+        assert_eq!(0, coordinator.upload_status(&response_locator)?);
+        coordinator.upload_contribution_part(&contributor, response_locator, 0, first)?;
+        assert_eq!(first.len() as u64, coordinator.upload_status(&response_locator)?);
+
+        // Check that a part which does not resume from the received offset is rejected.
+        assert!(matches!(
+            coordinator.upload_contribution_part(&contributor, response_locator, 1, first),
+            Err(CoordinatorError::UploadOffsetMismatch { offset: 1, .. })
+        ));
+
+        // Check that a participant cannot upload to a locator of a chunk they have not locked.
+        let other_locator = ContributionLocator::new(1, chunk_id + 1, 1, false);
+        assert!(matches!(
+            coordinator.upload_contribution_part(&contributor, other_locator, 0, first),
+            Err(CoordinatorError::ParticipantUnauthorized)
+        ));
+        assert_eq!(0, coordinator.upload_status(&other_locator)?);
+
+        // Resume the upload, and check that the assembled response file is written.
+        let offset = coordinator.upload_status(&response_locator)?;
+        coordinator.upload_contribution_part(&contributor, response_locator, offset, second)?;
+        assert_eq!(response.len() as u64, coordinator.upload_status(&response_locator)?);
+        coordinator.finish_upload(&contributor, response_locator, signature_locator, signature)?;
+        assert_eq!(0, coordinator.upload_status(&response_locator)?);
+        assert!(coordinator.storage.exists(&Locator::ContributionFile(response_locator)));
+        assert!(coordinator.add_contribution(chunk_id, &contributor).is_ok());
 
         Ok(())
     }
@@ -3023,6 +5215,52 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    #[serial]
+    fn coordinator_verify_full_transcript() -> anyhow::Result<()> {
+        initialize_test_environment(&TEST_ENVIRONMENT_3);
+
+        let contributor = Lazy::force(&TEST_CONTRIBUTOR_ID).clone();
+        let contributor_signing_key: SigningKey = "secret_key".to_string();
+        let verifier = Lazy::force(&TEST_VERIFIER_ID).clone();
+        let verifier_signing_key: SigningKey = "secret_key".to_string();
+
+        let mut coordinator = Coordinator::new(TEST_ENVIRONMENT_3.clone(), Arc::new(Dummy))?;
+        initialize_coordinator(&mut coordinator)?;
+
+        // Add and verify round 1 chunk 0 contribution 1.
+        let chunk_id = 0;
+        coordinator.try_lock_chunk(chunk_id, &contributor)?;
+        let mut seed: Seed = [0; SEED_LENGTH];
+        rand::thread_rng().fill_bytes(&mut seed[..]);
+        coordinator.run_computation(1, chunk_id, 1, &contributor, &contributor_signing_key, &seed)?;
+        coordinator.add_contribution(chunk_id, &contributor)?;
+
+        let task = Task::new(chunk_id, 1);
+        coordinator.run_verification(1, &task, &verifier, &verifier_signing_key)?;
+        coordinator.verify_contribution(&task, &verifier)?;
+
+        assert_eq!(None, coordinator.verify_full_transcript(1)?);
+
+        // Record the contribution as computed on some other challenge.
+        let signature_locator =
+            Locator::ContributionFileSignature(ContributionSignatureLocator::new(1, chunk_id, 1, false));
+        let signature: ContributionFileSignature =
+            serde_json::from_slice(&*coordinator.storage.reader(&signature_locator)?)?;
+        let state = ContributionState::new(vec![0; 64], hex::decode(signature.get_response_hash())?, None)?;
+        let signature = ContributionFileSignature::new(signature.get_signature().to_string(), state)?;
+        coordinator
+            .storage
+            .update(&signature_locator, Object::ContributionFileSignature(signature))?;
+
+        assert_eq!(
+            Some(ContributionLocator::new(1, chunk_id, 1, false)),
+            coordinator.verify_full_transcript(1)?
+        );
+
+        Ok(())
+    }
+
     #[test]
     #[serial]
     // This test runs a round with a single coordinator and single verifier
@@ -3412,6 +5650,38 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    #[serial]
+    fn coordinator_participant_from_address() -> anyhow::Result<()> {
+        initialize_test_environment(&TEST_ENVIRONMENT);
+
+        let coordinator = Coordinator::new(TEST_ENVIRONMENT.clone(), Arc::new(Dummy))?;
+
+        let verifier = Lazy::force(&TEST_VERIFIER_ID).clone();
+        let contributor = Lazy::force(&TEST_CONTRIBUTOR_ID_2).clone();
+
+        // A registered verifier address resolves to a verifier.
+        assert_eq!(verifier, coordinator.participant_from_address(&verifier.address()));
+        assert_eq!(verifier, coordinator.authorize_verifier(&verifier.address())?);
+        assert!(matches!(
+            coordinator.authorize_contributor(&verifier.address()),
+            Err(CoordinatorError::ParticipantUnauthorized)
+        ));
+
+        // Any other address resolves to a contributor.
+        assert_eq!(
+            contributor,
+            coordinator.participant_from_address(&contributor.address())
+        );
+        assert_eq!(contributor, coordinator.authorize_contributor(&contributor.address())?);
+        assert!(matches!(
+            coordinator.authorize_verifier(&contributor.address()),
+            Err(CoordinatorError::ParticipantUnauthorized)
+        ));
+
+        Ok(())
+    }
+
     #[test]
     #[serial]
     #[ignore]
@@ -6,13 +6,17 @@ use crate::{
     },
     storage::{Disk, Locator, Object},
     CoordinatorError,
+    ParticipantEvent,
     TimeSource,
+    TimelineEntry,
 };
 use phase1::ProvingSystem;
+use setup1_shared::reliability::ReliabilityReport;
 
 use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::{
+    cmp::Ordering,
     collections::{HashMap, HashSet, LinkedList},
     iter::FromIterator,
     net::IpAddr,
@@ -75,6 +79,9 @@ pub struct ParticipantInfo {
     first_seen: OffsetDateTime,
     /// The timestamp of the last seen instance of this participant.
     last_seen: OffsetDateTime,
+    /// The timestamp when this participant joined the queue for the round.
+    #[serde(default)]
+    joined_queue_at: Option<OffsetDateTime>,
     /// The timestamp when this participant started the round.
     started_at: Option<OffsetDateTime>,
     /// The timestamp when this participant finished the round.
@@ -93,6 +100,12 @@ pub struct ParticipantInfo {
     disposing_tasks: LinkedList<Task>,
     /// The list of (chunk ID, contribution ID) tasks that are disposed of while computing.
     disposed_tasks: LinkedList<Task>,
+    /// A map of chunk IDs to the timestamps when the contributions of this participant were accepted.
+    #[serde(default)]
+    contributed_at: HashMap<u64, OffsetDateTime>,
+    /// A map of chunk IDs to the timestamps when the contributions of this participant were verified.
+    #[serde(default)]
+    verified_at: HashMap<u64, OffsetDateTime>,
 }
 
 impl PartialEq for ParticipantInfo {
@@ -119,6 +132,7 @@ impl ParticipantInfo {
             bucket_id,
             first_seen: now,
             last_seen: now,
+            joined_queue_at: None,
             started_at: None,
             finished_at: None,
             dropped_at: None,
@@ -128,6 +142,8 @@ impl ParticipantInfo {
             completed_tasks: LinkedList::new(),
             disposing_tasks: LinkedList::new(),
             disposed_tasks: LinkedList::new(),
+            contributed_at: HashMap::new(),
+            verified_at: HashMap::new(),
         }
     }
 
@@ -431,6 +447,50 @@ impl ParticipantInfo {
         }
     }
 
+    ///
+    /// Pops the assigned (chunk ID, contribution ID) task for the given chunk ID,
+    /// regardless of its place in the order of assigned tasks.
+    ///
+    #[inline]
+    fn pop_task_for_chunk(&mut self, chunk_id: u64, time: &dyn TimeSource) -> Result<Task, CoordinatorError> {
+        trace!("Popping task on chunk {} for {}", chunk_id, self.id);
+
+        // Check that the participant has started in the round.
+        if self.started_at.is_none() {
+            return Err(CoordinatorError::ParticipantHasNotStarted);
+        }
+
+        // Check that the participant was not dropped from the round.
+        if self.dropped_at.is_some() {
+            return Err(CoordinatorError::ParticipantWasDropped);
+        }
+
+        // Check that the participant has not finished the round.
+        if self.finished_at.is_some() {
+            return Err(CoordinatorError::ParticipantAlreadyFinished);
+        }
+
+        // Check that this chunk was not already completed by the participant.
+        if self.completed_tasks.iter().any(|t| t.contains(chunk_id)) {
+            return Err(CoordinatorError::ParticipantAlreadyFinishedChunk { chunk_id });
+        }
+
+        // Fetch the task on the given chunk, if it is assigned to the participant.
+        let task = match self.assigned_tasks.iter().find(|t| t.contains(chunk_id)) {
+            Some(task) => *task,
+            None => return Err(CoordinatorError::ParticipantUnauthorizedForChunkId { chunk_id }),
+        };
+
+        // Update the last seen time.
+        self.last_seen = time.now_utc();
+
+        // Move the task from the assigned tasks to the pending tasks.
+        self.assigned_tasks = self.assigned_tasks.iter().filter(|t| **t != task).copied().collect();
+        self.pending_tasks.push_back(task);
+
+        Ok(task)
+    }
+
     ///
     /// Adds the given chunk ID to the locked chunks held by this participant.
     ///
@@ -715,6 +775,11 @@ impl ParticipantInfo {
         // Add the task to the completed tasks.
         self.completed_tasks.push_back(task.clone());
 
+        // Record the time that the contribution of a contributor was accepted.
+        if self.id.is_contributor() {
+            self.contributed_at.insert(task.chunk_id(), self.last_seen);
+        }
+
         Ok(())
     }
 
@@ -966,6 +1031,17 @@ pub struct CoordinatorState {
     banned: HashSet<Participant>,
     /// The manual lock to hold the coordinator from transitioning to the next round.
     manual_lock: bool,
+    /// The pause to hold participants from joining the queue, locking chunks, and contributing.
+    #[serde(default)]
+    paused: bool,
+    /// The map of contributors to the report of their latest reliability checks,
+    /// and the time at which it was recorded.
+    #[serde(default)]
+    reliability_reports: HashMap<Participant, (ReliabilityReport, OffsetDateTime)>,
+    /// The map of tasks pending verification which have been claimed by a verifier,
+    /// and the time of the claim.
+    #[serde(default)]
+    claimed_verifications: HashMap<Task, (Participant, OffsetDateTime)>,
 }
 
 impl CoordinatorState {
@@ -990,6 +1066,9 @@ impl CoordinatorState {
             dropped: Vec::new(),
             banned: HashSet::new(),
             manual_lock: false,
+            paused: false,
+            reliability_reports: HashMap::default(),
+            claimed_verifications: HashMap::default(),
         }
     }
 
@@ -1079,8 +1158,12 @@ impl CoordinatorState {
 
             let mut queue = self.queue.clone();
 
-            // Add each participant back into the queue.
+            // Add each participant back into the queue, except the replacement
+            // contributors of the coordinator, which only take over dropped tasks.
             for (participant, participant_info) in current_contributors.iter().chain(self.next.iter()) {
+                if self.is_coordinator_contributor(participant) {
+                    continue;
+                }
                 queue.insert(
                     participant.clone(),
                     (
@@ -1262,6 +1345,22 @@ impl CoordinatorState {
         Ok(contributors)
     }
 
+    ///
+    /// Returns the number of contributors that finished the current round,
+    /// not counting the replacement contributors of the coordinator.
+    ///
+    pub fn current_round_number_of_qualifying_contributors(&self) -> usize {
+        self.current_round_height
+            .and_then(|round_height| self.finished_contributors.get(&round_height))
+            .map(|contributors| {
+                contributors
+                    .keys()
+                    .filter(|participant| !self.is_coordinator_contributor(participant))
+                    .count()
+            })
+            .unwrap_or(0)
+    }
+
     ///
     /// Returns `true` if the given participant is a contributor managed
     /// by the coordinator.
@@ -1280,6 +1379,36 @@ impl CoordinatorState {
         participant.is_verifier() && self.environment.coordinator_verifiers().contains(participant)
     }
 
+    ///
+    /// Returns the verifiers managed by the coordinator.
+    ///
+    #[inline]
+    pub(super) fn coordinator_verifiers(&self) -> &Vec<Participant> {
+        self.environment.coordinator_verifiers()
+    }
+
+    ///
+    /// Replaces the verifiers managed by the coordinator.
+    ///
+    /// Verifications already claimed by a replaced verifier may still be
+    /// submitted by them, but they are unable to claim any more.
+    ///
+    #[inline]
+    pub(super) fn set_coordinator_verifiers(&mut self, verifiers: Vec<Participant>) -> Result<(), CoordinatorError> {
+        // Check that all participants are verifiers.
+        if verifiers.iter().any(|participant| !participant.is_verifier()) {
+            return Err(CoordinatorError::ExpectedVerifier);
+        }
+
+        // Check that there is a verifier to assign pending verifications to.
+        if verifiers.is_empty() {
+            return Err(CoordinatorError::VerifierMissing);
+        }
+
+        self.environment.set_coordinator_verifiers(verifiers);
+        Ok(())
+    }
+
     ///
     /// Returns the total number of contributors currently in the queue.
     ///
@@ -1310,6 +1439,106 @@ impl CoordinatorState {
             .collect()
     }
 
+    ///
+    /// Returns the contributors currently in the queue with their time of joining,
+    /// ordered by priority tier, then by time of joining, and then by address for
+    /// contributors who joined at the same time.
+    ///
+    /// As the time of joining is saved with the queue, this order is preserved
+    /// when the coordinator state is reloaded from storage.
+    ///
+    pub fn queue_order(&self) -> Vec<(Participant, OffsetDateTime)> {
+        let mut queue: Vec<_> = self
+            .queue
+            .iter()
+            .filter(|(p, _)| p.is_contributor())
+            .map(|(p, (_, _, _, joined))| (p.clone(), *joined))
+            .collect();
+        queue.sort_by(|a, b| self.compare_queue_position((&a.0, &a.1), (&b.0, &b.1)));
+        queue
+    }
+
+    ///
+    /// Returns the recorded events in the lifecycle of the given participant,
+    /// from the queue, the next round, the current round, and past rounds,
+    /// ordered by time.
+    ///
+    pub(super) fn participant_timeline(&self, participant: &Participant) -> Vec<TimelineEntry> {
+        let mut timeline = vec![];
+        if let Some((_, _, _, joined)) = self.queue.get(participant) {
+            timeline.push(TimelineEntry {
+                time: *joined,
+                event: ParticipantEvent::JoinedQueue,
+            });
+        }
+
+        let rounds = self
+            .next
+            .get(participant)
+            .into_iter()
+            .chain(self.current_contributors.get(participant))
+            .chain(self.current_verifiers.get(participant))
+            .chain(self.finished_contributors.values().filter_map(|c| c.get(participant)))
+            .chain(self.finished_verifiers.values().filter_map(|v| v.get(participant)))
+            .chain(self.dropped.iter().filter(|info| &info.id == participant));
+        for info in rounds {
+            let round_height = info.round_height;
+            let mut push = |time: OffsetDateTime, event: ParticipantEvent| timeline.push(TimelineEntry { time, event });
+
+            if let Some(joined_queue_at) = info.joined_queue_at {
+                push(joined_queue_at, ParticipantEvent::JoinedQueue);
+            }
+            push(info.first_seen, ParticipantEvent::SelectedForRound { round_height });
+            if let Some(started_at) = info.started_at {
+                push(started_at, ParticipantEvent::StartedRound { round_height });
+            }
+            for lock in info.locked_chunks.values() {
+                let chunk_id = lock.chunk_id;
+                push(lock.lock_time, ParticipantEvent::LockedChunk { round_height, chunk_id });
+            }
+            for (&chunk_id, &contributed_at) in &info.contributed_at {
+                push(contributed_at, ParticipantEvent::ContributionAccepted {
+                    round_height,
+                    chunk_id,
+                });
+            }
+            for (&chunk_id, &verified_at) in &info.verified_at {
+                push(verified_at, ParticipantEvent::VerificationCompleted {
+                    round_height,
+                    chunk_id,
+                });
+            }
+            if let Some(finished_at) = info.finished_at {
+                push(finished_at, ParticipantEvent::FinishedRound { round_height });
+            }
+            if let Some(dropped_at) = info.dropped_at {
+                push(dropped_at, ParticipantEvent::Dropped { round_height });
+            }
+        }
+
+        timeline.sort_by_key(|entry| entry.time);
+        timeline
+    }
+
+    ///
+    /// Compares the queue positions of two contributors, given with their time of joining.
+    ///
+    /// Contributors in a higher priority tier come first, and contributors
+    /// within the same tier are ordered first-in, first-out.
+    ///
+    fn compare_queue_position(
+        &self,
+        (a, a_joined): (&Participant, &OffsetDateTime),
+        (b, b_joined): (&Participant, &OffsetDateTime),
+    ) -> Ordering {
+        let a_priority = self.environment.queue_priority(a);
+        let b_priority = self.environment.queue_priority(b);
+        b_priority
+            .cmp(&a_priority)
+            .then_with(|| a_joined.cmp(b_joined))
+            .then_with(|| a.address().cmp(&b.address()))
+    }
+
     ///
     /// Returns a list of the contributors currently in the round.
     ///
@@ -1327,6 +1556,23 @@ impl CoordinatorState {
         }
     }
 
+    ///
+    /// Returns the time by which the given participant must contribute to the
+    /// given chunk, if the participant holds the lock on it.
+    ///
+    /// The deadline is the time the lock was acquired, plus the
+    /// `maximum_contribution_time` of the environment. Past the deadline,
+    /// the participant is dropped on the next update and the chunk is
+    /// reassigned.
+    ///
+    pub(super) fn lock_deadline(&self, participant: &Participant, chunk_id: u64) -> Option<OffsetDateTime> {
+        let lock = self
+            .current_participant_info(participant)?
+            .locked_chunks()
+            .get(&chunk_id)?;
+        Some(*lock.lock_time() + self.environment.maximum_contribution_time())
+    }
+
     /// Gets mutable reference to the [ParticipantInfo] for a
     /// participant currently in the round.
     pub fn current_participant_info_mut(&mut self, participant: &Participant) -> Option<&mut ParticipantInfo> {
@@ -1484,6 +1730,9 @@ impl CoordinatorState {
     ///
     /// Adds the given participant to the queue if they are permitted to participate.
     ///
+    /// If the participant is already in the queue, their IP, reliability score and
+    /// last seen timestamp are updated, and they keep their time of joining.
+    ///
     #[inline]
     pub(super) fn add_to_queue(
         &mut self,
@@ -1497,16 +1746,13 @@ impl CoordinatorState {
             return Err(CoordinatorError::ParticipantBanned);
         }
 
-        // Check that the participant is not already added to the queue.
-        if self.queue.contains_key(&participant) {
-            return Err(CoordinatorError::ParticipantAlreadyAdded);
-        }
-
         // Check that the participant is not in precommit for the next round.
         if self.next.contains_key(&participant) {
             return Err(CoordinatorError::ParticipantAlreadyAdded);
         }
 
+        let queued = self.queue.get(&participant).copied();
+
         match &participant {
             Participant::Contributor(_) => {
                 // Check if the contributor is authorized.
@@ -1518,7 +1764,19 @@ impl CoordinatorState {
                 if !self.environment.allow_current_contributors_in_queue()
                     && self.current_contributors.contains_key(&participant)
                 {
-                    return Err(CoordinatorError::ParticipantInCurrentRoundCannotJoinQueue);
+                    return Err(CoordinatorError::AlreadyContributing);
+                }
+
+                // Check that the contributor has recently passed the reliability checks.
+                if !self.has_passed_reliability_checks(&participant, time) {
+                    return Err(CoordinatorError::ReliabilityCheckRequired);
+                }
+
+                // Check that the queue has room for another contributor.
+                if let Some(maximum_queue_size) = self.environment.maximum_queue_size() {
+                    if queued.is_none() && self.number_of_queue_contributors() >= maximum_queue_size {
+                        return Err(CoordinatorError::QueueFull);
+                    }
                 }
             }
             Participant::Verifier(_) => {
@@ -1526,6 +1784,11 @@ impl CoordinatorState {
             }
         }
 
+        // Forget the IP the participant joined with before, if they are joining again.
+        if queued.is_some() {
+            self.remove_contributor_ips(&participant);
+        }
+
         if !self.environment.disable_reliability_zeroing() {
             // Zero the reliability score if the participant is joining with a known IP.
             if let Some(ip) = participant_ip {
@@ -1542,9 +1805,14 @@ impl CoordinatorState {
             }
         }
 
-        // Add the participant to the queue.
+        // Add the participant to the queue, keeping the assigned round and the time of joining
+        // of a participant joining again.
+        let (round, joined) = match queued {
+            Some((_, round, _, joined)) => (round, joined),
+            None => (None, time.now_utc()),
+        };
         self.queue
-            .insert(participant, (reliability_score, None, time.now_utc(), time.now_utc()));
+            .insert(participant, (reliability_score, round, time.now_utc(), joined));
 
         Ok(())
     }
@@ -1593,7 +1861,7 @@ impl CoordinatorState {
                     }
                     false => Err(CoordinatorError::ParticipantHasLockedMaximumChunks),
                 },
-                None => Err(CoordinatorError::ParticipantNotFound(participant.clone())),
+                None => Err(self.not_current_participant_error(participant)),
             },
             Participant::Verifier(_) => {
                 return Err(CoordinatorError::ExpectedContributor);
@@ -1601,6 +1869,55 @@ impl CoordinatorState {
         }
     }
 
+    ///
+    /// Pops the (chunk ID, contribution ID) task on the given chunk ID,
+    /// if the chunk is assigned to the given contributor.
+    ///
+    pub(super) fn fetch_task_for_chunk(
+        &mut self,
+        participant: &Participant,
+        chunk_id: u64,
+        time: &dyn TimeSource,
+    ) -> Result<Task, CoordinatorError> {
+        // Fetch the contributor chunk lock limit.
+        let contributor_limit = self.environment.contributor_lock_chunk_limit();
+
+        // Remove the given chunk ID from the pending chunks of the given participant.
+        match participant {
+            Participant::Contributor(_) => match self.current_contributors.get_mut(participant) {
+                // Check that the participant is holding less than the chunk lock limit.
+                Some(participant_info) => match participant_info.locked_chunks.len() < contributor_limit {
+                    true => {
+                        let task = participant_info.pop_task_for_chunk(chunk_id, time)?;
+                        self.start_task_timer(participant, &task, time);
+                        Ok(task)
+                    }
+                    false => Err(CoordinatorError::ParticipantHasLockedMaximumChunks),
+                },
+                None => Err(self.not_current_participant_error(participant)),
+            },
+            Participant::Verifier(_) => Err(CoordinatorError::ExpectedContributor),
+        }
+    }
+
+    ///
+    /// Returns the error explaining why the given participant is not in the current
+    /// round, so that they know whether to wait, join the queue again, or stop.
+    ///
+    pub(super) fn not_current_participant_error(&self, participant: &Participant) -> CoordinatorError {
+        if self.is_banned_participant(participant) {
+            CoordinatorError::ParticipantBanned
+        } else if self.queue.contains_key(participant) || self.next.contains_key(participant) {
+            CoordinatorError::ParticipantInQueue
+        } else if self.is_finished_contributor(participant) {
+            CoordinatorError::ParticipantAlreadyFinished
+        } else if self.dropped.iter().any(|info| info.id == *participant) {
+            CoordinatorError::ParticipantWasDropped
+        } else {
+            CoordinatorError::ParticipantNotFound(participant.clone())
+        }
+    }
+
     ///
     /// Adds the given chunk ID to the locks held by the given participant.
     ///
@@ -1858,10 +2175,97 @@ impl CoordinatorState {
             .pending_verification
             .remove(task)
             .ok_or(CoordinatorError::VerifierMissing)?;
+        self.claimed_verifications.remove(task);
 
         Ok(())
     }
 
+    ///
+    /// Claims the next unclaimed task pending verification for the given verifier,
+    /// so that no other verifier is handed the same task.
+    ///
+    /// On success, this function returns the claimed task.
+    ///
+    #[inline]
+    pub(super) fn claim_verification(
+        &mut self,
+        verifier: &Participant,
+        time: &dyn TimeSource,
+    ) -> Result<Task, CoordinatorError> {
+        // Check that the participant is an authorized verifier.
+        if !self.is_coordinator_verifier(verifier) {
+            return Err(CoordinatorError::ParticipantUnauthorized);
+        }
+
+        // Fetch the unclaimed task with the lowest chunk ID and contribution ID.
+        let task = self
+            .pending_verification
+            .keys()
+            .filter(|task| !self.claimed_verifications.contains_key(task))
+            .min_by_key(|task| (task.chunk_id(), task.contribution_id()))
+            .copied()
+            .ok_or(CoordinatorError::NoPendingVerifications)?;
+
+        info!(
+            "Verifier {} claimed (chunk {}, contribution {})",
+            verifier,
+            task.chunk_id(),
+            task.contribution_id()
+        );
+
+        self.claimed_verifications
+            .insert(task, (verifier.clone(), time.now_utc()));
+
+        Ok(task)
+    }
+
+    ///
+    /// Returns `true` if the given task pending verification is claimed by the given verifier.
+    ///
+    #[inline]
+    pub(super) fn is_verification_claimed_by(&self, task: &Task, verifier: &Participant) -> bool {
+        matches!(self.claimed_verifications.get(task), Some((claimant, _)) if claimant == verifier)
+    }
+
+    ///
+    /// Releases the tasks claimed by the given verifier, so that other verifiers may claim them.
+    ///
+    /// Returns the number of released tasks.
+    ///
+    #[inline]
+    pub(super) fn release_verification_claims(&mut self, verifier: &Participant) -> usize {
+        let claims = self.claimed_verifications.len();
+        self.claimed_verifications
+            .retain(|_, (claimant, _)| claimant != verifier);
+        let released = claims - self.claimed_verifications.len();
+        if released > 0 {
+            warn!("Released {} verification claims of {}", released, verifier);
+        }
+        released
+    }
+
+    ///
+    /// Releases the claims which have been held for longer than the
+    /// participant lock timeout, so that other verifiers may claim them.
+    ///
+    fn update_verification_claim_timeouts(&mut self, time: &dyn TimeSource) {
+        let participant_lock_timeout = self.environment.participant_lock_timeout();
+        let now = time.now_utc();
+
+        self.claimed_verifications.retain(|task, (claimant, claimed_at)| {
+            let expired = now - *claimed_at > participant_lock_timeout;
+            if expired {
+                warn!(
+                    "Releasing the claim of {} on (chunk {}, contribution {}), which has timed out",
+                    claimant,
+                    task.chunk_id(),
+                    task.contribution_id()
+                );
+            }
+            !expired
+        });
+    }
+
     ///
     /// Adds the given (chunk ID, contribution ID) task to the completed tasks of the given participant,
     /// and removes the chunk ID from the locks held by the given participant.
@@ -1898,7 +2302,17 @@ impl CoordinatorState {
             },
             Participant::Verifier(_) => {
                 // Remove the task from the pending verification set.
-                self.remove_pending_verification(task)
+                self.remove_pending_verification(task)?;
+
+                // Record the time of the verification for the contributor of the task.
+                if let Some(participant_info) = self
+                    .current_contributors
+                    .values_mut()
+                    .find(|info| info.completed_tasks.contains(task))
+                {
+                    participant_info.verified_at.insert(task.chunk_id(), time.now_utc());
+                }
+                Ok(())
             }
         }
     }
@@ -2067,6 +2481,21 @@ impl CoordinatorState {
         Ok(())
     }
 
+    ///
+    /// Drops the given participant from the queue, precommit, and
+    /// current round, with any available replacement contributor.
+    ///
+    /// See [CoordinatorState::drop_participant_with_replacement].
+    ///
+    #[inline]
+    pub(super) fn drop_participant(
+        &mut self,
+        participant: &Participant,
+        time: &dyn TimeSource,
+    ) -> Result<DropParticipant, CoordinatorError> {
+        self.drop_participant_with_replacement(participant, None, time)
+    }
+
     ///
     /// Drops the given participant from the queue, precommit, and
     /// current round.
@@ -2079,7 +2508,9 @@ impl CoordinatorState {
     /// attempt to replace the contributor with an available
     /// replacement contributor. If there are no replacement
     /// contributors available the round will be reset via
-    /// [CoordinatorState::reset_current_round].
+    /// [CoordinatorState::reset_current_round]. The replacement
+    /// contributor is the given one, if any, or else the one with
+    /// the least load.
     ///
     /// If the participant being dropped is the only remaining regular
     /// (non-replacement) contributor or the only remaining verifier,
@@ -2090,9 +2521,10 @@ impl CoordinatorState {
         skip(self, participant, time),
         fields(participant = %participant)
     )]
-    pub(super) fn drop_participant(
+    fn drop_participant_with_replacement(
         &mut self,
         participant: &Participant,
+        replacement: Option<&Participant>,
         time: &dyn TimeSource,
     ) -> Result<DropParticipant, CoordinatorError> {
         // Check that the coordinator state is initialized.
@@ -2102,6 +2534,12 @@ impl CoordinatorState {
 
         warn!("Dropping {} from the ceremony", participant);
 
+        // Release the verifications claimed by the participant, if it is a verifier.
+        let released_claims = match participant {
+            Participant::Contributor(_) => 0,
+            Participant::Verifier(_) => self.release_verification_claims(participant),
+        };
+
         // Remove the participant from the queue and precommit, if present.
         if self.queue.contains_key(participant) || self.next.contains_key(participant) {
             // Remove the participant from the queue.
@@ -2118,26 +2556,8 @@ impl CoordinatorState {
                 self.rollback_next_round(time);
             }
 
-            // Update the IP map, there are two cases:
-            // 1. The IP is associated only with the dropped participant, remove it.
-            // 2. The IP associated with the dropped participant is also associated with other participants, in
-            //    which case only remove the first relevant mapping.
-            let ips: Vec<_> = self
-                .contributor_ips
-                .iter()
-                .filter(|(_ip, participants)| participants.contains(&participant))
-                .map(|(&ip, participants)| (ip, participants.clone()))
-                .collect();
-
-            for (ip, participants) in ips {
-                if participants.len() == 1 {
-                    // Remove the IP address entirely.
-                    self.contributor_ips.remove(&ip);
-                } else if let Some(participants) = self.contributor_ips.get_mut(&ip) {
-                    // Remove only the associated participant, leaving the others and the IP in place.
-                    participants.remove(&participant);
-                }
-            }
+            // Update the IP map.
+            self.remove_contributor_ips(participant);
 
             return Ok(DropParticipant::DropQueue(DropQueueParticipantData {
                 participant: participant.clone(),
@@ -2151,11 +2571,16 @@ impl CoordinatorState {
                 .get(participant)
                 .ok_or_else(|| CoordinatorError::ParticipantNotFound(participant.clone()))?
                 .clone(),
-            Participant::Verifier(_) => self
-                .current_verifiers
-                .get(participant)
-                .ok_or_else(|| CoordinatorError::ParticipantNotFound(participant.clone()))?
-                .clone(),
+            Participant::Verifier(_) => match self.current_verifiers.get(participant) {
+                Some(participant_info) => participant_info.clone(),
+                // An external verifier is not part of the round, and only had its claims to drop.
+                None if released_claims > 0 => {
+                    return Ok(DropParticipant::DropQueue(DropQueueParticipantData {
+                        participant: participant.clone(),
+                    }));
+                }
+                None => return Err(CoordinatorError::ParticipantNotFound(participant.clone())),
+            },
         };
         {
             // Check that the participant is not already dropped.
@@ -2307,7 +2732,8 @@ impl CoordinatorState {
                         Assigning replacement contributor to the dropped contributor's tasks."
                     );
                     // Assign the replacement contributor to the dropped tasks.
-                    let replacement_contributor = self.add_replacement_contributor_unsafe(bucket_id, time)?;
+                    let replacement_contributor =
+                        self.add_replacement_contributor_unsafe(bucket_id, replacement, time)?;
 
                     CeremonyStorageAction::ReplaceContributor(ReplaceContributorStorageAction {
                         dropped_contributor: participant.clone(),
@@ -2398,23 +2824,27 @@ impl CoordinatorState {
     /// Adds a replacement contributor from the coordinator as a current contributor
     /// and assigns them tasks from the given starting bucket ID.
     ///
+    /// If no replacement contributor is given, the one with the least load is used.
+    ///
     #[inline]
     pub(crate) fn add_replacement_contributor_unsafe(
         &mut self,
         bucket_id: u64,
+        replacement: Option<&Participant>,
         time: &dyn TimeSource,
     ) -> Result<Participant, CoordinatorError> {
-        // Fetch a coordinator contributor with the least load.
-        let coordinator_contributor =
-            self.environment
-                .coordinator_contributors()
-                .iter()
-                .min_by_key(|c| match self.current_contributors.get(c) {
+        // Fetch the given coordinator contributor, or the one with the least load.
+        let coordinator_contributor = match replacement {
+            Some(replacement) => Some(replacement),
+            None => self.environment.coordinator_contributors().iter().min_by_key(|c| {
+                match self.current_contributors.get(c) {
                     Some(participant_info) => {
                         participant_info.pending_tasks.len() + participant_info.assigned_tasks.len()
                     }
                     None => 0,
-                });
+                }
+            }),
+        };
 
         // Assign the replacement contributor to the dropped tasks.
         let contributor = coordinator_contributor.ok_or(CoordinatorError::CoordinatorContributorMissing)?;
@@ -2437,6 +2867,40 @@ impl CoordinatorState {
         Ok(contributor.clone())
     }
 
+    ///
+    /// Drops the current contributor holding the given task, and replaces them
+    /// with the given coordinator contributor, who must not already be working
+    /// in the current round.
+    ///
+    pub(super) fn reassign_task(
+        &mut self,
+        task: &Task,
+        participant: &Participant,
+        time: &dyn TimeSource,
+    ) -> Result<DropParticipant, CoordinatorError> {
+        // Check that the participant is an idle coordinator contributor.
+        if !participant.is_contributor() {
+            return Err(CoordinatorError::ExpectedContributor);
+        }
+        if !self.is_coordinator_contributor(participant) {
+            return Err(CoordinatorError::ParticipantUnauthorized);
+        }
+        if self.current_contributors.contains_key(participant) {
+            return Err(CoordinatorError::ParticipantAlreadyStarted);
+        }
+
+        // Fetch the current contributor holding the task.
+        let holder = self
+            .current_contributors
+            .iter()
+            .find(|(_, info)| info.assigned_tasks.contains(task) || info.pending_tasks.contains(task))
+            .map(|(holder, _)| holder.clone())
+            .ok_or(CoordinatorError::TaskNotAssigned(*task))?;
+
+        warn!("Reassigning task {} from {} to {}", task, holder, participant);
+        self.drop_participant_with_replacement(&holder, Some(participant), time)
+    }
+
     ///
     /// Returns `true` if the manual lock for transitioning to the next round is enabled.
     ///
@@ -2461,6 +2925,77 @@ impl CoordinatorState {
         self.manual_lock = false;
     }
 
+    ///
+    /// Records the report of the latest reliability checks of the given contributor.
+    ///
+    #[inline]
+    pub(super) fn record_reliability_report(
+        &mut self,
+        participant: Participant,
+        report: ReliabilityReport,
+        time: &dyn TimeSource,
+    ) -> Result<(), CoordinatorError> {
+        if !participant.is_contributor() {
+            return Err(CoordinatorError::ExpectedContributor);
+        }
+
+        self.reliability_reports.insert(participant, (report, time.now_utc()));
+        Ok(())
+    }
+
+    ///
+    /// Returns the report of the latest reliability checks of the given
+    /// contributor, and the time at which it was recorded, if any.
+    ///
+    #[inline]
+    pub(super) fn reliability_report(&self, participant: &Participant) -> Option<&(ReliabilityReport, OffsetDateTime)> {
+        self.reliability_reports.get(participant)
+    }
+
+    ///
+    /// Returns `true` if the given contributor has recently passed the reliability
+    /// checks, or if the environment does not require reliability checks.
+    ///
+    #[inline]
+    pub(super) fn has_passed_reliability_checks(&self, participant: &Participant, time: &dyn TimeSource) -> bool {
+        let minimum_score = match self.environment.required_reliability_score() {
+            Some(minimum_score) => minimum_score,
+            None => return true,
+        };
+
+        match self.reliability_reports.get(participant) {
+            Some((report, reported_at)) => {
+                report.score_percent() >= minimum_score
+                    && time.now_utc() - *reported_at <= self.environment.reliability_report_ttl()
+            }
+            None => false,
+        }
+    }
+
+    ///
+    /// Returns `true` if the ceremony is paused.
+    ///
+    #[inline]
+    pub(super) fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    ///
+    /// Sets the pause of the ceremony to `true`.
+    ///
+    #[inline]
+    pub(super) fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    ///
+    /// Sets the pause of the ceremony to `false`.
+    ///
+    #[inline]
+    pub(super) fn resume(&mut self) {
+        self.paused = false;
+    }
+
     ///
     /// Updates the state of the queue for all waiting participants.
     ///
@@ -2472,14 +3007,14 @@ impl CoordinatorState {
             _ => return Err(CoordinatorError::RoundHeightNotSet),
         };
 
-        // Sort the participants in the queue by time joined.
+        // Sort the participants in the queue by priority tier and time joined.
         let mut queue: Vec<_> = self
             .queue
             .clone()
             .into_par_iter()
             .map(|(p, (r, _, ls, j))| (p, r, ls, j))
             .collect();
-        queue.par_sort_by(|a, b| (a.3).cmp(&b.3));
+        queue.par_sort_by(|a, b| self.compare_queue_position((&a.0, &a.3), (&b.0, &b.3)));
 
         // Parse the queue participants into contributors and verifiers,
         // and check that they are not banned participants.
@@ -2583,6 +3118,8 @@ impl CoordinatorState {
         &mut self,
         time: &dyn TimeSource,
     ) -> Result<Vec<DropParticipant>, CoordinatorError> {
+        self.update_verification_claim_timeouts(time);
+
         Ok(self
             .update_contributor_seen_drops(time)?
             .into_iter()
@@ -2607,13 +3144,15 @@ impl CoordinatorState {
     /// This will drop a participant (verifier or contributor) if it
     /// has been holding a lock for longer than
     /// [crate::environment::Environment]'s
-    /// `participant_lock_timeout`.
+    /// `participant_lock_timeout`, or a contributor if it has been holding
+    /// a lock for longer than the `maximum_contribution_time`.
     fn update_participant_lock_drops(
         &mut self,
         time: &dyn TimeSource,
     ) -> Result<Vec<DropParticipant>, CoordinatorError> {
-        // Fetch the timeout threshold for contributors.
+        // Fetch the timeout thresholds for participants.
         let participant_lock_timeout = self.environment.participant_lock_timeout();
+        let maximum_contribution_time = self.environment.maximum_contribution_time();
 
         // Fetch the current time.
         let now = time.now_utc();
@@ -2623,12 +3162,16 @@ impl CoordinatorState {
             .iter()
             .chain(self.current_verifiers.clone().iter())
             .filter_map(|(participant, participant_info)| {
+                let lock_timeout = match participant.is_contributor() {
+                    true => participant_lock_timeout.min(maximum_contribution_time),
+                    false => participant_lock_timeout,
+                };
                 let exceeded_chunk_names: Vec<String> = participant_info
                     .locked_chunks
                     .values()
                     .filter(|lock| {
                         let elapsed = now - lock.lock_time;
-                        elapsed > participant_lock_timeout
+                        elapsed > lock_timeout
                     })
                     .map(|lock| lock.chunk_id.to_string())
                     .collect();
@@ -2640,7 +3183,7 @@ impl CoordinatorState {
                         "Dropping participant {} because it has exceeded the maximum ({:?}s) allowed time \
                         it is allowed to hold a lock (on chunks {}).",
                         participant,
-                        participant_lock_timeout.whole_seconds(),
+                        lock_timeout.whole_seconds(),
                         exceeded_chunks_string,
                     );
                     Some(self.drop_participant(participant, time))
@@ -2976,7 +3519,9 @@ impl CoordinatorState {
             let number_of_chunks = self.environment.number_of_chunks() as u64;
 
             // Set the chunk ID ordering for each contributor.
-            for (bucket_index, (participant, (reliability, next_round, _, _))) in contributors.into_iter().enumerate() {
+            for (bucket_index, (participant, (reliability, next_round, _, joined))) in
+                contributors.into_iter().enumerate()
+            {
                 let bucket_id = bucket_index as u64;
                 let tasks = initialize_tasks(bucket_id, number_of_chunks, number_of_contributors as u64)?;
 
@@ -2989,6 +3534,7 @@ impl CoordinatorState {
                 // Initialize the participant info for the contributor.
                 let mut participant_info =
                     ParticipantInfo::new(participant.clone(), next_round_height, reliability, bucket_id, time);
+                participant_info.joined_queue_at = Some(joined);
                 participant_info.start(tasks, time)?;
 
                 // Check that the chunk IDs are set in the participant information.
@@ -3247,6 +3793,31 @@ impl CoordinatorState {
         }
     }
 
+    ///
+    /// Removes the given participant from the IP map, there are two cases:
+    /// 1. The IP is associated only with the participant, remove it.
+    /// 2. The IP associated with the participant is also associated with other participants, in
+    ///    which case only remove the mapping of the participant.
+    ///
+    fn remove_contributor_ips(&mut self, participant: &Participant) {
+        let ips: Vec<_> = self
+            .contributor_ips
+            .iter()
+            .filter(|(_ip, participants)| participants.contains(participant))
+            .map(|(&ip, participants)| (ip, participants.clone()))
+            .collect();
+
+        for (ip, participants) in ips {
+            if participants.len() == 1 {
+                // Remove the IP address entirely.
+                self.contributor_ips.remove(&ip);
+            } else if let Some(participants) = self.contributor_ips.get_mut(&ip) {
+                // Remove only the associated participant, leaving the others and the IP in place.
+                participants.remove(participant);
+            }
+        }
+    }
+
     ///
     /// Updates the coordinator's state by zeroing the reliability score for participants using
     /// the same IP.
@@ -3349,6 +3920,7 @@ pub(crate) enum DropParticipant {
 
 #[cfg(test)]
 mod tests {
+    use setup1_shared::reliability::{ReliabilityCheck, ReliabilityReport};
     use std::net::Ipv4Addr;
 
     use crate::{
@@ -3654,6 +4226,337 @@ mod tests {
         assert_eq!(Some(current_round_height), state.current_round_height);
     }
 
+    #[test]
+    fn test_queue_order_after_reload() {
+        let time = MockTimeSource::new(OffsetDateTime::now_utc());
+        let environment = TEST_ENVIRONMENT.clone();
+
+        // Initialize a new coordinator state.
+        let mut state = CoordinatorState::new(environment.clone());
+        state.initialize(5);
+
+        // Add contributors to the queue, with two of them joining at the same time.
+        for id in [3, 1, 2] {
+            let contributor = Participant::Contributor(id.to_string());
+            let contributor_ip = IpAddr::V4(format!("0.0.0.{}", id).parse().unwrap());
+            state
+                .add_to_queue(contributor, Some(contributor_ip), 10, &time)
+                .unwrap();
+            if id == 3 {
+                time.update(|now| now + Duration::seconds(1));
+            }
+        }
+        state.update_queue().unwrap();
+
+        let expected: Vec<_> = ["3", "1", "2"]
+            .iter()
+            .map(|id| Participant::Contributor(id.to_string()))
+            .collect();
+        let order: Vec<_> = state.queue_order().into_iter().map(|(p, _)| p).collect();
+        assert_eq!(expected, order);
+
+        // Reload the coordinator state and check the order is unchanged.
+        let reloaded: CoordinatorState = serde_json::from_slice(&serde_json::to_vec(&state).unwrap()).unwrap();
+        assert_eq!(state.queue_order(), reloaded.queue_order());
+    }
+
+    #[test]
+    fn test_update_queue_priority() {
+        let time = MockTimeSource::new(OffsetDateTime::now_utc());
+        let priority_contributor = Participant::Contributor("2".to_string());
+        let environment: Environment = Testing::default()
+            .maximum_contributors_per_round(1)
+            .queue_priority_contributors(&[(priority_contributor.clone(), 1)])
+            .into();
+
+        // Initialize a new coordinator state.
+        let mut state = CoordinatorState::new(environment);
+        state.initialize(5);
+
+        // Add a default contributor, followed by a priority contributor.
+        for id in 1..=3 {
+            let contributor = Participant::Contributor(id.to_string());
+            let contributor_ip = IpAddr::V4(format!("0.0.0.{}", id).parse().unwrap());
+            state
+                .add_to_queue(contributor, Some(contributor_ip), 10, &time)
+                .unwrap();
+            time.update(|now| now + Duration::seconds(1));
+        }
+        state.update_queue().unwrap();
+
+        // The priority contributor is assigned to the next round, and the
+        // default contributors follow in the order they joined.
+        let expected: Vec<_> = ["2", "1", "3"]
+            .iter()
+            .map(|id| Participant::Contributor(id.to_string()))
+            .collect();
+        let order: Vec<_> = state.queue_order().into_iter().map(|(p, _)| p).collect();
+        assert_eq!(expected, order);
+        for (index, contributor) in expected.iter().enumerate() {
+            assert_eq!(Some(6 + index as u64), state.queue.get(contributor).unwrap().1);
+        }
+    }
+
+    #[test]
+    fn test_add_to_queue_full() {
+        let time = SystemTimeSource::new();
+        let maximum_queue_size = 3;
+        let environment: Environment = Testing::default().maximum_queue_size(maximum_queue_size).into();
+
+        // Initialize a new coordinator state.
+        let mut state = CoordinatorState::new(environment);
+        state.initialize(5);
+
+        // Fill the queue to its maximum size.
+        for id in 1..=maximum_queue_size {
+            let contributor = Participant::Contributor(id.to_string());
+            let contributor_ip = IpAddr::V4(format!("0.0.0.{}", id).parse().unwrap());
+            state
+                .add_to_queue(contributor, Some(contributor_ip), 10, &time)
+                .unwrap();
+        }
+        assert_eq!(maximum_queue_size, state.number_of_queue_contributors());
+
+        // Check that the next contributor is rejected.
+        let overflow = Participant::Contributor("overflow".to_string());
+        let overflow_ip = IpAddr::V4(Ipv4Addr::new(0, 0, 0, 100));
+        assert!(matches!(
+            state.add_to_queue(overflow.clone(), Some(overflow_ip), 10, &time),
+            Err(CoordinatorError::QueueFull)
+        ));
+        assert!(!state.is_queue_contributor(&overflow));
+
+        // Check that the contributor can join once a slot frees up.
+        state
+            .remove_from_queue(&Participant::Contributor("1".to_string()))
+            .unwrap();
+        state
+            .add_to_queue(overflow.clone(), Some(overflow_ip), 10, &time)
+            .unwrap();
+        assert!(state.is_queue_contributor(&overflow));
+    }
+
+    /// Returns a reliability report in which the given checks have passed or failed.
+    fn reliability_report(checks: &[(ReliabilityCheck, bool)]) -> ReliabilityReport {
+        let mut report = ReliabilityReport::default();
+        for (check, passed) in checks {
+            let error = match passed {
+                true => None,
+                false => Some("Timed out".to_string()),
+            };
+            report.record(*check, std::time::Duration::from_millis(10), error);
+        }
+        report
+    }
+
+    #[test]
+    fn test_add_to_queue_reliability_check_required() {
+        let time = MockTimeSource::new(OffsetDateTime::now_utc());
+        let environment: Environment = Testing::default()
+            .required_reliability_score(100, Duration::minutes(10))
+            .into();
+
+        // Initialize a new coordinator state.
+        let mut state = CoordinatorState::new(environment);
+        state.initialize(5);
+
+        // Check that a contributor without a reliability report is rejected.
+        let contributor = Participant::Contributor("contributor".to_string());
+        let contributor_ip = IpAddr::V4(Ipv4Addr::new(0, 0, 0, 1));
+        assert!(matches!(
+            state.add_to_queue(contributor.clone(), Some(contributor_ip), 10, &time),
+            Err(CoordinatorError::ReliabilityCheckRequired)
+        ));
+
+        // Check that a contributor with a failed check is rejected.
+        let failed = reliability_report(&[(ReliabilityCheck::Latency, true), (ReliabilityCheck::Cpu, false)]);
+        state
+            .record_reliability_report(contributor.clone(), failed, &time)
+            .unwrap();
+        assert!(matches!(
+            state.add_to_queue(contributor.clone(), Some(contributor_ip), 10, &time),
+            Err(CoordinatorError::ReliabilityCheckRequired)
+        ));
+
+        // Check that a contributor with an expired report is rejected.
+        let passed = reliability_report(&[(ReliabilityCheck::Latency, true), (ReliabilityCheck::Cpu, true)]);
+        state
+            .record_reliability_report(contributor.clone(), passed.clone(), &time)
+            .unwrap();
+        time.update(|now| now + Duration::minutes(11));
+        assert!(matches!(
+            state.add_to_queue(contributor.clone(), Some(contributor_ip), 10, &time),
+            Err(CoordinatorError::ReliabilityCheckRequired)
+        ));
+
+        // Check that a contributor with a recent, passing report is added.
+        state
+            .record_reliability_report(contributor.clone(), passed, &time)
+            .unwrap();
+        state
+            .add_to_queue(contributor.clone(), Some(contributor_ip), 10, &time)
+            .unwrap();
+        assert!(state.is_queue_contributor(&contributor));
+    }
+
+    #[test]
+    fn test_reliability_report() {
+        let time = MockTimeSource::new(OffsetDateTime::now_utc());
+        let environment: Environment = Testing::default()
+            .required_reliability_score(50, Duration::minutes(10))
+            .into();
+
+        // Initialize a new coordinator state.
+        let mut state = CoordinatorState::new(environment);
+        state.initialize(5);
+
+        // Check that a contributor without a reliability report has none.
+        let contributor = Participant::Contributor("contributor".to_string());
+        assert_eq!(None, state.reliability_report(&contributor));
+        assert!(!state.has_passed_reliability_checks(&contributor, &time));
+
+        // Check that the latest report is kept, and passes while it is recent.
+        let failed = reliability_report(&[(ReliabilityCheck::Latency, false), (ReliabilityCheck::Cpu, false)]);
+        let passed = reliability_report(&[(ReliabilityCheck::Latency, true), (ReliabilityCheck::Cpu, false)]);
+        state
+            .record_reliability_report(contributor.clone(), failed, &time)
+            .unwrap();
+        state
+            .record_reliability_report(contributor.clone(), passed.clone(), &time)
+            .unwrap();
+        assert_eq!(Some(&(passed, time.time())), state.reliability_report(&contributor));
+        assert!(state.has_passed_reliability_checks(&contributor, &time));
+
+        // Check that the measurements of the report are persisted with the state.
+        let persisted: CoordinatorState = serde_json::from_slice(&serde_json::to_vec(&state).unwrap()).unwrap();
+        assert_eq!(
+            state.reliability_report(&contributor),
+            persisted.reliability_report(&contributor)
+        );
+
+        // Check that the report no longer passes once it has expired.
+        time.update(|now| now + Duration::minutes(11));
+        assert!(!state.has_passed_reliability_checks(&contributor, &time));
+    }
+
+    #[test]
+    fn test_claim_verification() {
+        let time = SystemTimeSource::new();
+        let verifier_1 = Participant::new_verifier("verifier-1");
+        let verifier_2 = Participant::new_verifier("verifier-2");
+        let environment: Environment = Testing::default()
+            .coordinator_verifiers(&[verifier_1.clone(), verifier_2.clone()])
+            .into();
+
+        // Initialize a new coordinator state.
+        let mut state = CoordinatorState::new(environment);
+        state.initialize(5);
+
+        // Check that an unknown verifier is unauthorized.
+        let outsider = Participant::new_verifier("outsider");
+        assert!(matches!(
+            state.claim_verification(&outsider, &time),
+            Err(CoordinatorError::ParticipantUnauthorized)
+        ));
+
+        // Check that there is nothing to claim yet.
+        assert!(matches!(
+            state.claim_verification(&verifier_1, &time),
+            Err(CoordinatorError::NoPendingVerifications)
+        ));
+
+        // Add two tasks pending verification.
+        let task_1 = Task::new(0, 1);
+        let task_2 = Task::new(1, 1);
+        state.add_pending_verification(&task_2).unwrap();
+        state.add_pending_verification(&task_1).unwrap();
+
+        // Check that each verifier claims a different task, which stays pending verification.
+        assert_eq!(task_1, state.claim_verification(&verifier_1, &time).unwrap());
+        assert_eq!(task_2, state.claim_verification(&verifier_2, &time).unwrap());
+        assert!(state.is_verification_claimed_by(&task_1, &verifier_1));
+        assert!(!state.is_verification_claimed_by(&task_1, &verifier_2));
+        assert_eq!(2, state.get_pending_verifications().len());
+        assert!(matches!(
+            state.claim_verification(&verifier_1, &time),
+            Err(CoordinatorError::NoPendingVerifications)
+        ));
+
+        // Check that the claim is released with the pending verification.
+        state.remove_pending_verification(&task_1).unwrap();
+        assert!(!state.is_verification_claimed_by(&task_1, &verifier_1));
+    }
+
+    #[test]
+    fn test_release_verification_claims() {
+        let time = MockTimeSource::new(OffsetDateTime::now_utc());
+        let verifier_1 = Participant::new_verifier("verifier-1");
+        let verifier_2 = Participant::new_verifier("verifier-2");
+        let environment: Environment = Testing::default()
+            .coordinator_verifiers(&[verifier_1.clone(), verifier_2.clone()])
+            .participant_lock_timeout(Duration::minutes(20))
+            .into();
+
+        // Initialize a new coordinator state.
+        let mut state = CoordinatorState::new(environment);
+        state.initialize(5);
+
+        // Add two tasks pending verification, and claim both.
+        let task_1 = Task::new(0, 1);
+        let task_2 = Task::new(1, 1);
+        state.add_pending_verification(&task_1).unwrap();
+        state.add_pending_verification(&task_2).unwrap();
+        assert_eq!(task_1, state.claim_verification(&verifier_1, &time).unwrap());
+        assert_eq!(task_2, state.claim_verification(&verifier_2, &time).unwrap());
+
+        // Check that dropping a verifier releases its claims to the other verifiers.
+        assert!(matches!(
+            state.drop_participant(&verifier_1, &time),
+            Ok(DropParticipant::DropQueue(_))
+        ));
+        assert!(!state.is_verification_claimed_by(&task_1, &verifier_1));
+        assert_eq!(task_1, state.claim_verification(&verifier_2, &time).unwrap());
+
+        // Check that the claims are kept until they time out.
+        time.update(|now| now + Duration::minutes(20));
+        state.update_dropped_participants(&time).unwrap();
+        assert!(state.is_verification_claimed_by(&task_1, &verifier_2));
+        assert!(state.is_verification_claimed_by(&task_2, &verifier_2));
+
+        time.update(|now| now + Duration::minutes(1));
+        state.update_dropped_participants(&time).unwrap();
+        assert!(!state.is_verification_claimed_by(&task_1, &verifier_2));
+        assert!(!state.is_verification_claimed_by(&task_2, &verifier_2));
+        assert_eq!(task_1, state.claim_verification(&verifier_1, &time).unwrap());
+    }
+
+    #[test]
+    fn test_fetch_task_not_current_participant() {
+        let time = SystemTimeSource::new();
+        let environment = TEST_ENVIRONMENT.clone();
+
+        // Initialize a new coordinator state.
+        let mut state = CoordinatorState::new(environment.clone());
+        state.initialize(1);
+
+        // Check that a participant who never joined is not found.
+        let contributor = test_coordinator_contributor(&environment).unwrap();
+        assert!(matches!(
+            state.fetch_task(&contributor, &time),
+            Err(CoordinatorError::ParticipantNotFound(_))
+        ));
+
+        // Check that a participant waiting in the queue is told so.
+        let contributor_ip = IpAddr::V4(Ipv4Addr::UNSPECIFIED);
+        state
+            .add_to_queue(contributor.clone(), Some(contributor_ip), 10, &time)
+            .unwrap();
+        assert!(matches!(
+            state.fetch_task(&contributor, &time),
+            Err(CoordinatorError::ParticipantInQueue)
+        ));
+    }
+
     #[test]
     fn test_remove_from_queue_contributor() {
         let time = SystemTimeSource::new();
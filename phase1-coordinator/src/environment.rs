@@ -78,6 +78,63 @@ pub enum Deployment {
     Production,
 }
 
+/// The participants permitted to join the queue of the ceremony.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ParticipantAccess {
+    /// Any participant may join the queue.
+    Open,
+    /// Only the listed participants may join the queue.
+    Allow(Vec<Participant>),
+    /// All participants except the listed participants may join the queue.
+    Deny(Vec<Participant>),
+}
+
+impl ParticipantAccess {
+    /// Returns `true` if the given participant may join the queue.
+    pub fn is_allowed(&self, participant: &Participant) -> bool {
+        match self {
+            ParticipantAccess::Open => true,
+            ParticipantAccess::Allow(participants) => participants.contains(participant),
+            ParticipantAccess::Deny(participants) => !participants.contains(participant),
+        }
+    }
+}
+
+impl Default for ParticipantAccess {
+    fn default() -> Self {
+        ParticipantAccess::Open
+    }
+}
+
+/// The output format of the logs of the coordinator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum LogFormat {
+    /// Human-readable lines.
+    Pretty,
+    /// One JSON object per line, including the fields of the enclosing spans.
+    Json,
+}
+
+impl Default for LogFormat {
+    fn default() -> Self {
+        LogFormat::Pretty
+    }
+}
+
+impl std::str::FromStr for LogFormat {
+    type Err = String;
+
+    fn from_str(format: &str) -> Result<Self, Self::Err> {
+        match format.to_lowercase().as_str() {
+            "pretty" => Ok(LogFormat::Pretty),
+            "json" => Ok(LogFormat::Json),
+            _ => Err(format!("Unknown log format: {}", format)),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum Parameters {
     AleoInner,
@@ -197,6 +254,27 @@ impl Parameters {
     }
 }
 
+/// Challenges are cached unless an environment opts out.
+fn default_cache_challenges() -> bool {
+    true
+}
+
+/// The default maximum age of a reliability report, which matches the deployments.
+fn default_reliability_report_ttl() -> time::Duration {
+    time::Duration::minutes(10)
+}
+
+/// The default maximum time to contribute to a locked chunk, which matches the
+/// lock timeout of production.
+fn default_maximum_contribution_time() -> time::Duration {
+    time::Duration::days(7)
+}
+
+/// The self check is strict unless an environment opts out, as in production.
+fn default_strict_self_check() -> bool {
+    true
+}
+
 #[serde_with::serde_as]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Environment {
@@ -209,7 +287,8 @@ pub struct Environment {
     /// The input correctness check preference of the coordinator.
     check_input_for_correctness: CheckForCorrectness,
 
-    /// The minimum number of contributors permitted to participate in a round.
+    /// The minimum number of contributors permitted to participate in a round,
+    /// and required to finish a round before it is finalized.
     minimum_contributors_per_round: usize,
     /// The maximum number of contributors permitted to participate in a round.
     maximum_contributors_per_round: usize,
@@ -233,6 +312,10 @@ pub struct Environment {
     /// before it will be dropped from the ceremony by the
     /// coordinator.
     participant_lock_timeout: time::Duration,
+    /// The maximum duration a contributor has to contribute to a chunk
+    /// after locking it.
+    #[serde(default = "default_maximum_contribution_time")]
+    maximum_contribution_time: time::Duration,
     /// The maximum duration a queued contributor can go without a heartbeat.
     queue_seen_timeout: time::Duration,
     /// The number of drops tolerated by a participant before banning them from future rounds.
@@ -243,11 +326,42 @@ pub struct Environment {
     allow_current_verifiers_in_queue: bool,
     /// The minimum number of seconds to wait after aggregation before starting the next round.
     queue_wait_time: u64,
+    /// The maximum number of contributors permitted to wait in the queue, if any.
+    #[serde(default)]
+    maximum_queue_size: Option<usize>,
+    /// The number of threads of the pool dedicated to verifications, if any.
+    #[serde(default)]
+    verification_threads: Option<usize>,
+    /// The setting to cache the verified challenges of the current round in memory.
+    #[serde(default = "default_cache_challenges")]
+    cache_challenges: bool,
+    /// The minimum percentage of reliability checks a contributor must have passed
+    /// to join the queue, if reliability checks are mandatory.
+    #[serde(default)]
+    required_reliability_score: Option<u8>,
+    /// The maximum age of a reliability report for it to count towards joining the queue.
+    #[serde(default = "default_reliability_report_ttl")]
+    reliability_report_ttl: time::Duration,
+    /// The setting to refuse to start if the storage fails the self check on startup.
+    #[serde(default = "default_strict_self_check")]
+    strict_self_check: bool,
+    /// The setting to serve reads only, from the storage of another coordinator.
+    #[serde(default)]
+    read_only: bool,
 
     /// The contributors managed by the coordinator.
     coordinator_contributors: Vec<Participant>,
     /// The verifiers managed by the coordinator.
     coordinator_verifiers: Vec<Participant>,
+    /// The contributors permitted to join the queue with a priority tier above the default.
+    #[serde(default)]
+    queue_priority_contributors: Vec<(Participant, u8)>,
+    /// The participants permitted to join the queue.
+    #[serde(default)]
+    participant_access: ParticipantAccess,
+    /// The output format of the logs of the coordinator.
+    #[serde(default)]
+    log_format: LogFormat,
 
     /// The software version number of the coordinator.
     software_version: u64,
@@ -259,6 +373,30 @@ pub struct Environment {
     disable_reliability_zeroing: bool,
 }
 
+///
+/// The settings of an [Environment] that operators check a deployment against.
+///
+/// The summary leaves out the participant lists and the storage directory,
+/// so that it can be served to operators without exposing them.
+///
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EnvironmentSummary {
+    pub deployment: Deployment,
+    pub software_version: u64,
+    pub parameters: Settings,
+    pub number_of_chunks: u64,
+    pub compressed_inputs: UseCompression,
+    pub compressed_outputs: UseCompression,
+    pub contributor_seen_timeout_seconds: i64,
+    pub verifier_seen_timeout_seconds: i64,
+    pub participant_lock_timeout_seconds: i64,
+    pub maximum_contribution_time_seconds: i64,
+    pub queue_seen_timeout_seconds: i64,
+    pub number_of_coordinator_verifiers: usize,
+    pub read_only: bool,
+}
+
 impl Environment {
     ///
     /// Returns the parameter settings of the coordinator.
@@ -273,6 +411,10 @@ impl Environment {
     /// The default choice should be `UseCompression::No` to minimize time
     /// spent by contributors on decompressing inputs.
     ///
+    /// `UseCompression::Yes` writes the initialization output and the next
+    /// challenges with compressed points, which take about half the storage.
+    /// Contributors and verifiers must use the same setting to read them.
+    ///
     pub const fn compressed_inputs(&self) -> UseCompression {
         self.compressed_inputs
     }
@@ -301,6 +443,10 @@ impl Environment {
     /// Returns the minimum number of contributors permitted to
     /// participate in a round.
     ///
+    /// A round is not finalized until at least this many contributors,
+    /// not counting the replacement contributors of the coordinator,
+    /// have finished it.
+    ///
     pub const fn minimum_contributors_per_round(&self) -> usize {
         self.minimum_contributors_per_round
     }
@@ -356,6 +502,17 @@ impl Environment {
         self.participant_lock_timeout
     }
 
+    ///
+    /// Returns the maximum duration that a contributor has to contribute
+    /// to a chunk after locking it.
+    ///
+    /// Past this deadline, the contribution is rejected and the contributor
+    /// is dropped from the ceremony by the coordinator.
+    ///
+    pub const fn maximum_contribution_time(&self) -> time::Duration {
+        self.maximum_contribution_time
+    }
+
     ///
     /// Returns the maximum duration that a queued contributor can go
     /// without a heartbeat.
@@ -396,6 +553,70 @@ impl Environment {
         self.queue_wait_time
     }
 
+    ///
+    /// Returns the maximum number of contributors permitted to wait in the queue.
+    ///
+    /// If `None`, the size of the queue is unbounded.
+    ///
+    pub const fn maximum_queue_size(&self) -> Option<usize> {
+        self.maximum_queue_size
+    }
+
+    ///
+    /// Returns the number of threads of the pool dedicated to verifications.
+    ///
+    /// Verifications are CPU-bound, so running them on their own pool keeps
+    /// them from starving the other work of the coordinator. If `None`,
+    /// verifications run on the global thread pool.
+    ///
+    pub const fn verification_threads(&self) -> Option<usize> {
+        self.verification_threads
+    }
+
+    ///
+    /// Returns the setting to cache the verified challenges of the current round in memory.
+    ///
+    /// Challenges are immutable once verified, so repeat requests for them
+    /// can be served without reading from storage again.
+    ///
+    pub const fn cache_challenges(&self) -> bool {
+        self.cache_challenges
+    }
+
+    ///
+    /// Returns the minimum percentage of reliability checks a contributor
+    /// must have passed to join the queue.
+    ///
+    /// If `None`, contributors may join the queue without a reliability report.
+    ///
+    pub const fn required_reliability_score(&self) -> Option<u8> {
+        self.required_reliability_score
+    }
+
+    ///
+    /// Returns the maximum age of a reliability report for it to
+    /// count towards a contributor joining the queue.
+    ///
+    pub const fn reliability_report_ttl(&self) -> time::Duration {
+        self.reliability_report_ttl
+    }
+
+    ///
+    /// Returns `true` if the coordinator must refuse to start
+    /// when the storage fails the self check on startup.
+    ///
+    pub const fn strict_self_check(&self) -> bool {
+        self.strict_self_check
+    }
+
+    ///
+    /// Returns `true` if the coordinator is a read-only replica, which
+    /// refuses every write and reloads its state from storage on update.
+    ///
+    pub const fn read_only(&self) -> bool {
+        self.read_only
+    }
+
     ///
     /// Returns the contributors managed by the coordinator.
     ///
@@ -414,6 +635,54 @@ impl Environment {
         &self.coordinator_verifiers
     }
 
+    ///
+    /// Returns the priority tier of the given contributor in the queue.
+    ///
+    /// Contributors in a higher tier are assigned to rounds before contributors
+    /// in a lower tier. Contributors which are not in the priority allowlist
+    /// are in the default tier `0`.
+    ///
+    pub fn queue_priority(&self, participant: &Participant) -> u8 {
+        self.queue_priority_contributors
+            .iter()
+            .find(|(contributor, _)| contributor == participant)
+            .map(|(_, priority)| *priority)
+            .unwrap_or_default()
+    }
+
+    ///
+    /// Returns the participants permitted to join the queue.
+    ///
+    /// If [ParticipantAccess::Open], any participant may join the queue.
+    ///
+    pub const fn participant_access(&self) -> &ParticipantAccess {
+        &self.participant_access
+    }
+
+    ///
+    /// Returns the output format of the logs of the coordinator.
+    ///
+    /// This may be overridden at runtime with the `COORDINATOR_LOG_FORMAT`
+    /// environment variable, see [crate::logger::init_logger].
+    ///
+    pub const fn log_format(&self) -> LogFormat {
+        self.log_format
+    }
+
+    ///
+    /// Replaces the verifiers managed by the coordinator.
+    ///
+    pub(crate) fn set_coordinator_verifiers(&mut self, verifiers: Vec<Participant>) {
+        self.coordinator_verifiers = verifiers;
+    }
+
+    ///
+    /// Replaces the participants permitted to join the queue.
+    ///
+    pub(crate) fn set_participant_access(&mut self, participant_access: ParticipantAccess) {
+        self.participant_access = participant_access;
+    }
+
     ///
     /// Returns the software version number of the coordinator.
     ///
@@ -446,6 +715,28 @@ impl Environment {
         (total_size_in_g1!(proving_system, power) + chunk_size as u64 - 1) / chunk_size as u64
     }
 
+    ///
+    /// Returns the settings of the coordinator that operators check
+    /// a deployment against, without any participant list.
+    ///
+    pub fn summary(&self) -> EnvironmentSummary {
+        EnvironmentSummary {
+            deployment: self.deployment.clone(),
+            software_version: self.software_version,
+            parameters: self.parameters(),
+            number_of_chunks: self.number_of_chunks(),
+            compressed_inputs: self.compressed_inputs,
+            compressed_outputs: self.compressed_outputs,
+            contributor_seen_timeout_seconds: self.contributor_seen_timeout.whole_seconds(),
+            verifier_seen_timeout_seconds: self.verifier_seen_timeout.whole_seconds(),
+            participant_lock_timeout_seconds: self.participant_lock_timeout.whole_seconds(),
+            maximum_contribution_time_seconds: self.maximum_contribution_time.whole_seconds(),
+            queue_seen_timeout_seconds: self.queue_seen_timeout.whole_seconds(),
+            number_of_coordinator_verifiers: self.coordinator_verifiers.len(),
+            read_only: self.read_only,
+        }
+    }
+
     /// Returns the storage system of the coordinator.
     pub(crate) fn storage(&self) -> anyhow::Result<Disk> {
         Ok(Disk::load(self)?)
@@ -491,6 +782,57 @@ impl Testing {
         self
     }
 
+    pub fn maximum_queue_size(mut self, maximum: usize) -> Self {
+        self.environment.maximum_queue_size = Some(maximum);
+        self
+    }
+
+    pub fn participant_access(mut self, participant_access: ParticipantAccess) -> Self {
+        self.environment.participant_access = participant_access;
+        self
+    }
+
+    pub fn log_format(mut self, log_format: LogFormat) -> Self {
+        self.environment.log_format = log_format;
+        self
+    }
+
+    pub fn verification_threads(mut self, threads: usize) -> Self {
+        self.environment.verification_threads = Some(threads);
+        self
+    }
+
+    pub fn cache_challenges(mut self, cache_challenges: bool) -> Self {
+        self.environment.cache_challenges = cache_challenges;
+        self
+    }
+
+    pub fn required_reliability_score(mut self, minimum: u8, ttl: time::Duration) -> Self {
+        self.environment.required_reliability_score = Some(minimum);
+        self.environment.reliability_report_ttl = ttl;
+        self
+    }
+
+    pub fn strict_self_check(mut self, strict_self_check: bool) -> Self {
+        self.environment.strict_self_check = strict_self_check;
+        self
+    }
+
+    pub fn read_only(mut self, read_only: bool) -> Self {
+        self.environment.read_only = read_only;
+        self
+    }
+
+    pub fn compressed_inputs(mut self, compressed_inputs: UseCompression) -> Self {
+        self.environment.compressed_inputs = compressed_inputs;
+        self
+    }
+
+    pub fn allow_current_contributors_in_queue(mut self, allow: bool) -> Self {
+        self.environment.allow_current_contributors_in_queue = allow;
+        self
+    }
+
     pub fn disable_reliability_zeroing(mut self, disable_zeroing: bool) -> Self {
         self.environment.disable_reliability_zeroing = disable_zeroing;
         self
@@ -520,6 +862,18 @@ impl Testing {
         deployment
     }
 
+    #[inline]
+    pub fn queue_priority_contributors(&self, contributors: &[(Participant, u8)]) -> Self {
+        // Check that all participants are contributors.
+        if contributors.iter().any(|(p, _)| !p.is_contributor()) {
+            panic!("Specifying to environment a list of queue priority contributors with non-contributors.")
+        }
+
+        let mut deployment = self.clone();
+        deployment.environment.queue_priority_contributors = contributors.to_vec();
+        deployment
+    }
+
     pub fn contributor_seen_timeout(&self, contributor_timeout: time::Duration) -> Self {
         let mut deployment = self.clone();
         deployment.environment.contributor_seen_timeout = contributor_timeout;
@@ -532,11 +886,23 @@ impl Testing {
         deployment
     }
 
+    pub fn maximum_contribution_time(&self, maximum_contribution_time: time::Duration) -> Self {
+        let mut deployment = self.clone();
+        deployment.environment.maximum_contribution_time = maximum_contribution_time;
+        deployment
+    }
+
     pub fn queue_seen_timeout(&self, queue_seen_timeout: time::Duration) -> Self {
         let mut deployment = self.clone();
         deployment.environment.queue_seen_timeout = queue_seen_timeout;
         deployment
     }
+
+    pub fn local_base_directory(&self, local_base_directory: &str) -> Self {
+        let mut deployment = self.clone();
+        deployment.environment.local_base_directory = local_base_directory.to_string();
+        deployment
+    }
 }
 
 impl From<Parameters> for Testing {
@@ -573,14 +939,25 @@ impl std::default::Default for Testing {
                 contributor_seen_timeout: time::Duration::minutes(5),
                 verifier_seen_timeout: time::Duration::minutes(15),
                 participant_lock_timeout: time::Duration::minutes(20),
+                maximum_contribution_time: time::Duration::minutes(20),
                 queue_seen_timeout: time::Duration::days(10),
                 participant_ban_threshold: 5,
                 allow_current_contributors_in_queue: true,
                 allow_current_verifiers_in_queue: true,
                 queue_wait_time: 0,
+                maximum_queue_size: None,
+                verification_threads: None,
+                cache_challenges: true,
+                required_reliability_score: None,
+                reliability_report_ttl: time::Duration::minutes(10),
+                strict_self_check: false,
+                read_only: false,
 
                 coordinator_contributors: vec![Participant::new_contributor("testing-coordinator-contributor")],
                 coordinator_verifiers: vec![Participant::new_verifier("testing-coordinator-verifier")],
+                queue_priority_contributors: vec![],
+                participant_access: ParticipantAccess::Open,
+                log_format: LogFormat::Pretty,
 
                 software_version: 1,
                 deployment: Deployment::Testing,
@@ -609,6 +986,57 @@ impl Development {
         self
     }
 
+    pub fn maximum_queue_size(mut self, maximum: usize) -> Self {
+        self.environment.maximum_queue_size = Some(maximum);
+        self
+    }
+
+    pub fn participant_access(mut self, participant_access: ParticipantAccess) -> Self {
+        self.environment.participant_access = participant_access;
+        self
+    }
+
+    pub fn log_format(mut self, log_format: LogFormat) -> Self {
+        self.environment.log_format = log_format;
+        self
+    }
+
+    pub fn verification_threads(mut self, threads: usize) -> Self {
+        self.environment.verification_threads = Some(threads);
+        self
+    }
+
+    pub fn cache_challenges(mut self, cache_challenges: bool) -> Self {
+        self.environment.cache_challenges = cache_challenges;
+        self
+    }
+
+    pub fn required_reliability_score(mut self, minimum: u8, ttl: time::Duration) -> Self {
+        self.environment.required_reliability_score = Some(minimum);
+        self.environment.reliability_report_ttl = ttl;
+        self
+    }
+
+    pub fn strict_self_check(mut self, strict_self_check: bool) -> Self {
+        self.environment.strict_self_check = strict_self_check;
+        self
+    }
+
+    pub fn read_only(mut self, read_only: bool) -> Self {
+        self.environment.read_only = read_only;
+        self
+    }
+
+    pub fn compressed_inputs(mut self, compressed_inputs: UseCompression) -> Self {
+        self.environment.compressed_inputs = compressed_inputs;
+        self
+    }
+
+    pub fn allow_current_contributors_in_queue(mut self, allow: bool) -> Self {
+        self.environment.allow_current_contributors_in_queue = allow;
+        self
+    }
+
     pub fn contributor_seen_timeout(mut self, timeout: time::Duration) -> Self {
         self.environment.contributor_seen_timeout = timeout;
         self
@@ -619,6 +1047,11 @@ impl Development {
         self
     }
 
+    pub fn maximum_contribution_time(mut self, maximum_contribution_time: time::Duration) -> Self {
+        self.environment.maximum_contribution_time = maximum_contribution_time;
+        self
+    }
+
     pub fn disable_reliability_zeroing(mut self, disable_zeroing: bool) -> Self {
         self.environment.disable_reliability_zeroing = disable_zeroing;
         self
@@ -647,6 +1080,18 @@ impl Development {
         deployment.environment.coordinator_verifiers = verifiers.to_vec();
         deployment
     }
+
+    #[inline]
+    pub fn queue_priority_contributors(&self, contributors: &[(Participant, u8)]) -> Self {
+        // Check that all participants are contributors.
+        if contributors.iter().any(|(p, _)| !p.is_contributor()) {
+            panic!("Specifying to environment a list of queue priority contributors with non-contributors.")
+        }
+
+        let mut deployment = self.clone();
+        deployment.environment.queue_priority_contributors = contributors.to_vec();
+        deployment
+    }
 }
 
 impl From<Parameters> for Development {
@@ -689,14 +1134,25 @@ impl std::default::Default for Development {
                 contributor_seen_timeout: time::Duration::minutes(1),
                 verifier_seen_timeout: time::Duration::minutes(15),
                 participant_lock_timeout: time::Duration::minutes(20),
+                maximum_contribution_time: time::Duration::minutes(20),
                 queue_seen_timeout: time::Duration::minutes(10),
                 participant_ban_threshold: 5,
                 allow_current_contributors_in_queue: true,
                 allow_current_verifiers_in_queue: true,
                 queue_wait_time: 60,
+                maximum_queue_size: None,
+                verification_threads: None,
+                cache_challenges: true,
+                required_reliability_score: None,
+                reliability_report_ttl: time::Duration::minutes(10),
+                strict_self_check: false,
+                read_only: false,
 
                 coordinator_contributors: vec![Participant::new_contributor("development-coordinator-contributor")],
                 coordinator_verifiers: vec![Participant::new_verifier("development-coordinator-verifier")],
+                queue_priority_contributors: vec![],
+                participant_access: ParticipantAccess::Open,
+                log_format: LogFormat::Pretty,
 
                 software_version: 1,
                 deployment: Deployment::Development,
@@ -725,6 +1181,57 @@ impl Production {
         self
     }
 
+    pub fn maximum_queue_size(mut self, maximum: usize) -> Self {
+        self.environment.maximum_queue_size = Some(maximum);
+        self
+    }
+
+    pub fn participant_access(mut self, participant_access: ParticipantAccess) -> Self {
+        self.environment.participant_access = participant_access;
+        self
+    }
+
+    pub fn log_format(mut self, log_format: LogFormat) -> Self {
+        self.environment.log_format = log_format;
+        self
+    }
+
+    pub fn verification_threads(mut self, threads: usize) -> Self {
+        self.environment.verification_threads = Some(threads);
+        self
+    }
+
+    pub fn cache_challenges(mut self, cache_challenges: bool) -> Self {
+        self.environment.cache_challenges = cache_challenges;
+        self
+    }
+
+    pub fn required_reliability_score(mut self, minimum: u8, ttl: time::Duration) -> Self {
+        self.environment.required_reliability_score = Some(minimum);
+        self.environment.reliability_report_ttl = ttl;
+        self
+    }
+
+    pub fn strict_self_check(mut self, strict_self_check: bool) -> Self {
+        self.environment.strict_self_check = strict_self_check;
+        self
+    }
+
+    pub fn read_only(mut self, read_only: bool) -> Self {
+        self.environment.read_only = read_only;
+        self
+    }
+
+    pub fn compressed_inputs(mut self, compressed_inputs: UseCompression) -> Self {
+        self.environment.compressed_inputs = compressed_inputs;
+        self
+    }
+
+    pub fn allow_current_contributors_in_queue(mut self, allow: bool) -> Self {
+        self.environment.allow_current_contributors_in_queue = allow;
+        self
+    }
+
     pub fn contributor_seen_timeout(mut self, timeout: time::Duration) -> Self {
         self.environment.contributor_seen_timeout = timeout;
         self
@@ -735,6 +1242,11 @@ impl Production {
         self
     }
 
+    pub fn maximum_contribution_time(mut self, maximum_contribution_time: time::Duration) -> Self {
+        self.environment.maximum_contribution_time = maximum_contribution_time;
+        self
+    }
+
     pub fn queue_seen_timeout(mut self, timeout: time::Duration) -> Self {
         self.environment.queue_seen_timeout = timeout;
         self
@@ -768,6 +1280,18 @@ impl Production {
         deployment.environment.coordinator_verifiers = verifiers.to_vec();
         deployment
     }
+
+    #[inline]
+    pub fn queue_priority_contributors(&self, contributors: &[(Participant, u8)]) -> Self {
+        // Check that all participants are contributors.
+        if contributors.iter().any(|(p, _)| !p.is_contributor()) {
+            panic!("Specifying to environment a list of queue priority contributors with non-contributors.")
+        }
+
+        let mut deployment = self.clone();
+        deployment.environment.queue_priority_contributors = contributors.to_vec();
+        deployment
+    }
 }
 
 impl From<Parameters> for Production {
@@ -804,14 +1328,25 @@ impl std::default::Default for Production {
                 contributor_seen_timeout: time::Duration::days(7),
                 verifier_seen_timeout: time::Duration::days(7),
                 participant_lock_timeout: time::Duration::days(7),
+                maximum_contribution_time: time::Duration::days(7),
                 queue_seen_timeout: time::Duration::days(7),
                 participant_ban_threshold: 5,
                 allow_current_contributors_in_queue: false,
                 allow_current_verifiers_in_queue: true,
                 queue_wait_time: 120,
+                maximum_queue_size: None,
+                verification_threads: None,
+                cache_challenges: true,
+                required_reliability_score: None,
+                reliability_report_ttl: time::Duration::minutes(10),
+                strict_self_check: true,
+                read_only: false,
 
                 coordinator_contributors: vec![Participant::new_contributor("coordinator-contributor")],
                 coordinator_verifiers: vec![Participant::new_verifier("coordinator-verifier")],
+                queue_priority_contributors: vec![],
+                participant_access: ParticipantAccess::Open,
+                log_format: LogFormat::Pretty,
 
                 software_version: 1,
                 deployment: Deployment::Production,
@@ -884,4 +1419,33 @@ mod tests {
         assert_eq!(ChunkSize::from(1639_usize), chunk_size);
         assert_eq!(number_of_chunks as u64, Testing::from(parameters).number_of_chunks());
     }
+
+    #[test]
+    fn test_log_format_from_str() {
+        assert_eq!(LogFormat::Pretty, "pretty".parse().unwrap());
+        assert_eq!(LogFormat::Json, "JSON".parse().unwrap());
+        assert!("yaml".parse::<LogFormat>().is_err());
+
+        let environment: Environment = Testing::from(Parameters::Test3Chunks)
+            .log_format(LogFormat::Json)
+            .into();
+        assert_eq!(LogFormat::Json, environment.log_format());
+    }
+
+    #[test]
+    fn test_summary() {
+        let environment: Environment = Testing::from(Parameters::Test3Chunks)
+            .contributor_seen_timeout(time::Duration::minutes(5))
+            .into();
+        let summary = environment.summary();
+        assert_eq!(Deployment::Testing, summary.deployment);
+        assert_eq!(3, summary.number_of_chunks);
+        assert_eq!(300, summary.contributor_seen_timeout_seconds);
+        assert_eq!(1, summary.number_of_coordinator_verifiers);
+
+        // Check that no participant or storage path is exposed.
+        let json = serde_json::to_string(&summary).unwrap();
+        assert!(!json.contains("testing-coordinator"));
+        assert!(!json.contains(environment.local_base_directory()));
+    }
 }
@@ -31,6 +31,7 @@ mod macros;
 pub mod authentication;
 
 pub(crate) mod commands;
+pub use commands::initialization::InitializationSummary;
 
 pub mod coordinator;
 pub use coordinator::*;
@@ -47,9 +48,13 @@ pub use coordinator_state::CoordinatorState;
 
 pub mod environment;
 
+pub mod logger;
+
 pub mod objects;
 pub use objects::{ContributionFileSignature, ContributionState, Participant, Round};
 
+pub mod runner;
+
 pub mod storage;
 
 #[cfg(any(test, feature = "testing"))]
@@ -0,0 +1,40 @@
+use crate::environment::{Environment, LogFormat};
+
+use std::env;
+use tracing::warn;
+
+/// The environment variable which overrides the log format of the environment.
+pub const LOG_FORMAT_VARIABLE: &str = "COORDINATOR_LOG_FORMAT";
+
+///
+/// Initializes the global logger of the coordinator.
+///
+/// The log format is read from the `COORDINATOR_LOG_FORMAT` environment
+/// variable if it is set, and from the given environment otherwise.
+/// In [LogFormat::Json], each event is written as one JSON object per line,
+/// together with the fields of its enclosing spans (such as the round,
+/// chunk and participant), so that the logs may be shipped to a log
+/// aggregator as is.
+///
+pub fn init_logger(environment: &Environment) {
+    let (log_format, error) = match env::var(LOG_FORMAT_VARIABLE) {
+        Ok(variable) => match variable.parse::<LogFormat>() {
+            Ok(log_format) => (log_format, None),
+            Err(error) => (environment.log_format(), Some(error)),
+        },
+        Err(_) => (environment.log_format(), None),
+    };
+
+    match log_format {
+        LogFormat::Pretty => tracing_subscriber::fmt::init(),
+        LogFormat::Json => tracing_subscriber::fmt()
+            .json()
+            .with_current_span(true)
+            .with_span_list(true)
+            .init(),
+    }
+
+    if let Some(error) = error {
+        warn!("{}, using the {:?} log format instead", error, log_format);
+    }
+}
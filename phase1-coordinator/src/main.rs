@@ -1,13 +1,16 @@
 use phase1_coordinator::{
     authentication::{Dummy, Signature},
     environment::{Development, Environment, Parameters},
+    logger::init_logger,
+    runner::run_until_shutdown,
     Coordinator,
 };
-use tracing_subscriber;
 
 use std::{sync::Arc, time::Duration};
-use tokio::{sync::RwLock, task, time::sleep};
-use tracing::*;
+use tokio::{
+    sync::{watch, RwLock},
+    task,
+};
 
 fn coordinator(environment: &Environment, signature: Arc<dyn Signature>) -> anyhow::Result<Coordinator> {
     Ok(Coordinator::new(environment.clone(), signature)?)
@@ -15,8 +18,6 @@ fn coordinator(environment: &Environment, signature: Arc<dyn Signature>) -> anyh
 
 #[tokio::main]
 pub async fn main() -> anyhow::Result<()> {
-    tracing_subscriber::fmt::init();
-
     // Set the environment.
     let environment: Environment = Development::from(Parameters::TestCustom {
         number_of_chunks: 8,
@@ -27,51 +28,48 @@ pub async fn main() -> anyhow::Result<()> {
     // use phase1_coordinator::environment::Production;
     // let environment: Environment = Production::from(Parameters::AleoInner).into();
 
+    init_logger(&environment);
+
     // Instantiate the coordinator.
     let coordinator: Arc<RwLock<Coordinator>> = Arc::new(RwLock::new(coordinator(&environment, Arc::new(Dummy))?));
 
+    // The shutdown signal, which stops the coordinator loop after its current iteration.
+    let (shutdown_sender, shutdown_receiver) = watch::channel(false);
+
     let ceremony_coordinator = coordinator.clone();
     // Initialize the coordinator.
-    let ceremony = task::spawn(async move {
+    let mut ceremony = task::spawn(async move {
         // Initialize the coordinator.
-        ceremony_coordinator.write().await.initialize().unwrap();
+        ceremony_coordinator.write().await.initialize()?;
 
-        // Initialize the coordinator loop.
-        loop {
-            // Run the update operation.
-            if let Err(error) = ceremony_coordinator.write().await.update() {
-                error!("{}", error);
-            }
+        // Run the update operation every 10 seconds, until the coordinator is shutting down.
+        run_until_shutdown(
+            ceremony_coordinator,
+            Duration::from_secs(10),
+            shutdown_receiver,
+            |coordinator| Ok(coordinator.update()?),
+        )
+        .await;
 
-            // Sleep for 10 seconds in between iterations.
-            sleep(Duration::from_secs(10)).await;
-            break;
-        }
+        Ok::<_, anyhow::Error>(())
     });
 
-    // Initialize the shutdown procedure.
-    let shutdown_handler = {
-        let shutdown_coordinator = coordinator.clone();
-        task::spawn(async move {
-            tokio::signal::ctrl_c()
-                .await
-                .expect("Error while waiting for shutdown signal");
-            shutdown_coordinator
-                .write()
-                .await
-                .shutdown()
-                .expect("Error while shutting down");
-        })
-    };
-
     tokio::select! {
-        _ = shutdown_handler => {
-            println!("Shutdown completed first")
-        }
-        _ = ceremony => {
-            println!("Ceremony completed first")
+        _ = tokio::signal::ctrl_c() => {}
+        result = &mut ceremony => {
+            // The loop only stops on its own if the coordinator failed to initialize.
+            result??;
+            println!("Ceremony completed first");
+            return Ok(());
         }
     };
 
+    // Stop the coordinator loop, and wait for its current update to finish,
+    // so that nothing is written to storage once the state is saved.
+    shutdown_sender.send(true)?;
+    ceremony.await??;
+    coordinator.write().await.shutdown()?;
+    println!("Shutdown completed");
+
     Ok(())
 }
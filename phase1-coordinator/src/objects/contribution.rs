@@ -50,7 +50,6 @@ impl Contribution {
 
     /// Returns a reference to the verifier, if it exists.
     /// Otherwise returns `None`.
-    #[allow(dead_code)]
     #[inline]
     pub fn get_verifier(&self) -> &Option<Participant> {
         &self.verifier_id
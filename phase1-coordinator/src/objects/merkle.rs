@@ -0,0 +1,183 @@
+use serde::{Deserialize, Serialize};
+use setup_utils::calculate_hash;
+
+/// The domain separator of a leaf hash.
+const LEAF_PREFIX: u8 = 0;
+/// The domain separator of an inner node hash.
+const NODE_PREFIX: u8 = 1;
+
+///
+/// A Merkle tree over the ordered contribution hashes of a round.
+///
+/// Leaves and inner nodes are hashed with distinct prefixes, so that a
+/// leaf can never be passed off as an inner node. When a layer has an
+/// odd number of nodes, the last node is carried up to the next layer
+/// unchanged.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MerkleTree {
+    /// The layers of the tree, from the leaves up to the root.
+    layers: Vec<Vec<Vec<u8>>>,
+}
+
+impl MerkleTree {
+    /// Creates a new instance of `MerkleTree` over the given leaves, in order.
+    pub fn new(leaves: &[Vec<u8>]) -> Self {
+        let mut layers = vec![leaves.iter().map(|leaf| hash_leaf(leaf)).collect::<Vec<_>>()];
+
+        while layers.last().map(|layer| layer.len() > 1).unwrap_or(false) {
+            let next = layers
+                .last()
+                .unwrap()
+                .chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => hash_node(left, right),
+                    [node] => node.clone(),
+                    _ => unreachable!(),
+                })
+                .collect();
+            layers.push(next);
+        }
+
+        Self { layers }
+    }
+
+    /// Returns the number of leaves in the tree.
+    pub fn len(&self) -> usize {
+        self.layers[0].len()
+    }
+
+    /// Returns `true` if the tree has no leaves.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    ///
+    /// Returns the root of the tree.
+    ///
+    /// The root of an empty tree is the hash of an empty leaf layer.
+    ///
+    pub fn root(&self) -> Vec<u8> {
+        match self.layers.last().and_then(|layer| layer.first()) {
+            Some(root) => root.clone(),
+            None => calculate_hash(&[NODE_PREFIX]).to_vec(),
+        }
+    }
+
+    /// Returns the inclusion proof of the leaf at the given index, if it exists.
+    pub fn proof(&self, leaf_index: usize) -> Option<MerkleProof> {
+        if leaf_index >= self.len() {
+            return None;
+        }
+
+        let mut path = vec![];
+        let mut index = leaf_index;
+        for layer in &self.layers[..self.layers.len() - 1] {
+            let sibling = index ^ 1;
+            if sibling < layer.len() {
+                path.push(MerkleSibling {
+                    hash: hex::encode(&layer[sibling]),
+                    is_left: sibling < index,
+                });
+            }
+            index /= 2;
+        }
+
+        Some(MerkleProof { leaf_index, path })
+    }
+}
+
+/// A sibling node on the path from a leaf to the root of a [MerkleTree].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MerkleSibling {
+    /// The hex-encoded hash of the sibling node.
+    hash: String,
+    /// The setting for whether the sibling is the left node of the pair.
+    is_left: bool,
+}
+
+///
+/// The proof that a leaf is included in a [MerkleTree] with a given root.
+///
+/// The proof is self-contained, so a contributor can check it against
+/// a published root without access to any other contribution.
+///
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MerkleProof {
+    /// The index of the leaf in the tree.
+    leaf_index: usize,
+    /// The sibling nodes from the leaf up to the root.
+    path: Vec<MerkleSibling>,
+}
+
+impl MerkleProof {
+    /// Returns the index of the leaf in the tree.
+    pub fn leaf_index(&self) -> usize {
+        self.leaf_index
+    }
+
+    /// Returns `true` if the given leaf is included in the tree with the given root.
+    pub fn verify(&self, leaf: &[u8], root: &[u8]) -> bool {
+        let mut node = hash_leaf(leaf);
+        for sibling in &self.path {
+            let hash = match hex::decode(&sibling.hash) {
+                Ok(hash) => hash,
+                Err(_) => return false,
+            };
+            node = match sibling.is_left {
+                true => hash_node(&hash, &node),
+                false => hash_node(&node, &hash),
+            };
+        }
+        node == root
+    }
+}
+
+fn hash_leaf(leaf: &[u8]) -> Vec<u8> {
+    calculate_hash(&[&[LEAF_PREFIX][..], leaf].concat()).to_vec()
+}
+
+fn hash_node(left: &[u8], right: &[u8]) -> Vec<u8> {
+    calculate_hash(&[&[NODE_PREFIX][..], left, right].concat()).to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_merkle_proof() {
+        let leaves: Vec<Vec<u8>> = (0..5u8).map(|i| vec![i; 64]).collect();
+        let tree = MerkleTree::new(&leaves);
+        let root = tree.root();
+
+        // Check that every leaf has a valid proof against the root.
+        for (index, leaf) in leaves.iter().enumerate() {
+            let proof = tree.proof(index).unwrap();
+            assert_eq!(index, proof.leaf_index());
+            assert!(proof.verify(leaf, &root));
+        }
+
+        // Check that a proof does not hold for another leaf or another root.
+        let proof = tree.proof(1).unwrap();
+        assert!(!proof.verify(&leaves[2], &root));
+        assert!(!proof.verify(&leaves[1], &MerkleTree::new(&leaves[..4]).root()));
+
+        // Check that there is no proof for a leaf outside the tree.
+        assert!(tree.proof(5).is_none());
+    }
+
+    #[test]
+    pub fn test_merkle_root_single_and_empty() {
+        let leaf = vec![7u8; 64];
+        let tree = MerkleTree::new(&[leaf.clone()]);
+        assert!(tree.proof(0).unwrap().verify(&leaf, &tree.root()));
+
+        let empty = MerkleTree::new(&[]);
+        assert!(empty.is_empty());
+        assert_ne!(tree.root(), empty.root());
+        assert!(empty.proof(0).is_none());
+    }
+}
@@ -7,6 +7,9 @@ pub use contribution::*;
 pub mod contribution_file_signature;
 pub use contribution_file_signature::*;
 
+pub mod merkle;
+pub use merkle::*;
+
 pub mod participant;
 pub use participant::*;
 
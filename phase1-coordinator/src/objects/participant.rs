@@ -23,6 +23,16 @@ pub enum Participant {
     Verifier(VerifierId),
 }
 
+/// The role in which a [Participant] took part in a round.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ParticipantRole {
+    /// The participant made a verified contribution in the round.
+    Contributor,
+    /// The participant verified a contribution in the round.
+    Verifier,
+}
+
 impl Participant {
     /// Creates a new contributor instance of `Participant`.
     pub fn new_contributor(participant: &str) -> Self {
@@ -42,6 +42,8 @@ pub struct LockedLocators {
     current_contribution: ContributionLocator,
     next_contribution: ContributionLocator,
     next_contribution_file_signature: ContributionSignatureLocator,
+    deadline: Option<OffsetDateTime>,
+    previous_contribution_hash: Option<String>,
 }
 
 impl LockedLocators {
@@ -64,6 +66,26 @@ impl LockedLocators {
     pub fn next_contribution_file_signature(&self) -> ContributionSignatureLocator {
         self.next_contribution_file_signature
     }
+
+    /// Get the time by which the contribution must be made, if the lock has a deadline.
+    pub fn deadline(&self) -> Option<OffsetDateTime> {
+        self.deadline
+    }
+
+    /// Set the time by which the contribution must be made.
+    pub(crate) fn set_deadline(&mut self, deadline: Option<OffsetDateTime>) {
+        self.deadline = deadline;
+    }
+
+    /// Get the hex-encoded hash of the previous contribution, which the challenge file begins with, if known.
+    pub fn previous_contribution_hash(&self) -> Option<&str> {
+        self.previous_contribution_hash.as_deref()
+    }
+
+    /// Set the hex-encoded hash of the previous contribution.
+    pub(crate) fn set_previous_contribution_hash(&mut self, previous_contribution_hash: Option<String>) {
+        self.previous_contribution_hash = previous_contribution_hash;
+    }
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize, SerdeDiff)]
@@ -588,6 +610,8 @@ impl Round {
                     current_contribution,
                     next_contribution,
                     next_contribution_file_signature,
+                    deadline: None,
+                    previous_contribution_hash: None,
                 }
             }
             Participant::Verifier(_) => {
@@ -731,6 +755,8 @@ impl Round {
             current_contribution,
             next_contribution,
             next_contribution_file_signature,
+            deadline: None,
+            previous_contribution_hash: None,
         })
     }
 
@@ -263,6 +263,20 @@ mod test {
         assert_eq!(task, serde_json::from_str("\"0/1\"").unwrap());
     }
 
+    #[test]
+    fn test_task_wire_format() {
+        // Tasks cross the network as "chunk/contribution" strings, also inside lists.
+        let tasks = vec![Task::new(3, 12), Task::new(u64::MAX, 0)];
+        let json = format!(r#"["3/12","{}/0"]"#, u64::MAX);
+        assert_eq!(json, serde_json::to_string(&tasks).unwrap());
+        assert_eq!(tasks, serde_json::from_str::<Vec<Task>>(&json).unwrap());
+
+        // Check that malformed tasks are rejected.
+        for json in [r#""3""#, r#""3/""#, r#""a/12""#, r#""3/-1""#, "[3,12]"] {
+            assert!(serde_json::from_str::<Task>(json).is_err(), "{} was accepted", json);
+        }
+    }
+
     #[test]
     fn test_initialize_tasks_2_chunks_1_contributor() {
         let number_of_chunks = 2;
@@ -0,0 +1,42 @@
+use crate::Coordinator;
+
+use std::{sync::Arc, time::Duration};
+use tokio::{
+    sync::{watch, RwLock},
+    time::sleep,
+};
+use tracing::*;
+
+///
+/// Runs the given step on the coordinator every interval, until the shutdown signal is sent.
+///
+/// Once the shutdown signal is sent, the loop finishes the step in progress and
+/// returns, without starting another one. So once this function returns, the loop
+/// no longer touches storage and the coordinator state can be saved. A step which
+/// fails is logged, and the loop continues.
+///
+pub async fn run_until_shutdown<F>(
+    coordinator: Arc<RwLock<Coordinator>>,
+    interval: Duration,
+    mut shutdown: watch::Receiver<bool>,
+    mut step: F,
+) where
+    F: FnMut(&mut Coordinator) -> anyhow::Result<()>,
+{
+    while !*shutdown.borrow() {
+        if let Err(error) = step(&mut *coordinator.write().await) {
+            error!("{}", error);
+        }
+
+        // Sleep in between iterations, unless the coordinator is shutting down.
+        // A dropped sender can no longer signal, so it also stops the loop.
+        tokio::select! {
+            _ = sleep(interval) => {}
+            changed = shutdown.changed() => {
+                if changed.is_err() {
+                    break;
+                }
+            }
+        }
+    }
+}
@@ -14,6 +14,7 @@ use crate::{
     CoordinatorError,
     CoordinatorState,
 };
+use setup_utils::HashWriter;
 
 use anyhow::Result;
 use fs_err::{self as fs, File, OpenOptions};
@@ -22,7 +23,7 @@ use memmap::MmapOptions;
 
 use std::{
     convert::TryFrom,
-    io::{Read, Write},
+    io::{ErrorKind, Read, Seek, SeekFrom, Write},
     ops::{Deref, DerefMut},
     path::{Path, PathBuf},
     str::FromStr,
@@ -31,6 +32,9 @@ use tracing::{debug, error, trace};
 
 use super::{LocatorPath, StorageAction};
 
+/// The extension of a contribution file being uploaded in parts, replacing its `unverified` extension.
+const UPLOAD_EXTENSION: &str = "unverified.upload";
+
 #[derive(Debug)]
 pub struct Disk {
     environment: Environment,
@@ -254,8 +258,18 @@ impl Disk {
         Ok(())
     }
 
+    ///
     /// Copies an object from the given source locator to the given destination locator.
-    pub fn copy(&mut self, source_locator: &Locator, destination_locator: &Locator) -> Result<(), CoordinatorError> {
+    ///
+    /// The bytes are hashed as they are written, so on success this function returns
+    /// the hash of the bytes in the destination locator. On failure, the destination
+    /// locator is removed.
+    ///
+    pub fn copy(
+        &mut self,
+        source_locator: &Locator,
+        destination_locator: &Locator,
+    ) -> Result<Vec<u8>, CoordinatorError> {
         trace!(
             "Copying from A to B\n\n\tA: {}\n\tB: {}\n",
             self.to_path(source_locator)?,
@@ -277,13 +291,178 @@ impl Disk {
         // Fetch the source object.
         let source_object = self.get(source_locator)?;
 
-        // Initialize the destination file with the source object size.initializeinitialize
+        // Initialize the destination file with the source object size.
         self.initialize(destination_locator.clone(), source_object.size())?;
 
-        // Update the destination locator with the copied source object.
-        self.update(destination_locator, source_object)?;
+        // Write the source object to the destination locator, hashing the bytes as they are written.
+        let destination_path = self.to_path(destination_locator)?;
+        let result = OpenOptions::new()
+            .write(true)
+            .open(destination_path)
+            .map_err(CoordinatorError::from)
+            .and_then(|file| write_hashed(file, &source_object.to_bytes()));
+        match result {
+            Ok(hash) => {
+                trace!("Copied to {}", self.to_path(destination_locator)?);
+                Ok(hash)
+            }
+            Err(error) => {
+                self.remove(destination_locator)?;
+                Err(error)
+            }
+        }
+    }
+
+    /// Returns the number of bytes received so far by the partial upload of the
+    /// contribution file at the given locator.
+    pub fn upload_size(&self, locator: &ContributionLocator) -> Result<u64, CoordinatorError> {
+        let path = self.upload_path(locator)?;
+        match fs::metadata(&path) {
+            Ok(metadata) => Ok(metadata.len()),
+            Err(_) => Ok(0),
+        }
+    }
+
+    /// Writes the given bytes at the given offset of the partial upload of the contribution
+    /// file at the given locator, discarding any bytes after the offset, and returns the
+    /// number of bytes received so far.
+    pub fn write_upload(
+        &mut self,
+        locator: &ContributionLocator,
+        offset: u64,
+        bytes: &[u8],
+    ) -> Result<u64, CoordinatorError> {
+        let path = self.upload_path(locator)?;
+        trace!(
+            "Writing {} bytes to {} at offset {}",
+            bytes.len(),
+            path.display(),
+            offset
+        );
+
+        self.resolver
+            .chunk_directory_init(locator.round_height(), locator.chunk_id());
+
+        let mut file = OpenOptions::new().create(true).write(true).open(&path)?;
+        file.set_len(offset)?;
+        file.seek(SeekFrom::Start(offset))?;
+        file.write_all(bytes)?;
+        // Sync all in-memory data to disk.
+        file.flush()?;
+
+        Ok(offset + bytes.len() as u64)
+    }
+
+    /// Removes the partial upload of the contribution file at the given locator,
+    /// and returns its bytes.
+    pub fn take_upload(&mut self, locator: &ContributionLocator) -> Result<Vec<u8>, CoordinatorError> {
+        let path = self.upload_path(locator)?;
+        trace!("Taking {}", path.display());
+
+        // Check that the partial upload exists in storage.
+        if !path.is_file() {
+            error!(
+                "Partial upload {} missing in call to take_upload() in storage.",
+                path.display()
+            );
+            return Err(CoordinatorError::StorageLocatorMissing);
+        }
+
+        let bytes = fs::read(&path)?;
+        fs::remove_file(&path)?;
+        Ok(bytes)
+    }
+
+    /// Returns the path of the partial upload of the contribution file at the given locator,
+    /// next to the contribution file itself.
+    fn upload_path(&self, locator: &ContributionLocator) -> Result<PathBuf, CoordinatorError> {
+        let path: PathBuf = self.to_path(&Locator::ContributionFile(*locator))?.into();
+        Ok(path.with_extension(UPLOAD_EXTENSION))
+    }
+
+    ///
+    /// Copies the storage into a new directory with the given name in the given
+    /// directory, with the given coordinator state in place of the stored one.
+    ///
+    /// The snapshot is staged in a hidden directory next to it and only renamed
+    /// to its final path once complete, so the returned path never holds a
+    /// partial copy. The storage must not be written to while this runs.
+    ///
+    pub fn snapshot(
+        &self,
+        state: &CoordinatorState,
+        directory: &Path,
+        name: &str,
+    ) -> Result<PathBuf, CoordinatorError> {
+        let base = Path::new(self.environment.local_base_directory());
+
+        // Check that the snapshot is not taken into the storage itself.
+        fs::create_dir_all(directory)?;
+        if fs::canonicalize(directory)?.starts_with(fs::canonicalize(base)?) {
+            error!("Snapshot directory {} is inside the storage", directory.display());
+            return Err(CoordinatorError::SnapshotInvalid);
+        }
+
+        // Check that the snapshot does not exist yet.
+        let snapshot = directory.join(name);
+        if snapshot.exists() {
+            error!("Snapshot {} already exists", snapshot.display());
+            return Err(CoordinatorError::StorageLocatorAlreadyExists);
+        }
+
+        // Copy the storage into the staging directory, removing any leftover of a failed snapshot.
+        let staging = directory.join(format!(".{}.partial", name));
+        if staging.exists() {
+            fs::remove_dir_all(&staging)?;
+        }
+        copy_directory(base, &staging)?;
+
+        // Replace the stored coordinator state with the given state.
+        let state_path = DiskResolver::new(&staging.to_string_lossy()).to_path(&Locator::CoordinatorState)?;
+        fs::write(state_path, Object::CoordinatorState(state.clone()).to_bytes())?;
 
-        trace!("Copied to {}", self.to_path(destination_locator)?);
+        fs::rename(&staging, &snapshot)?;
+        debug!("Took a snapshot of the storage at {}", snapshot.display());
+        Ok(snapshot)
+    }
+
+    ///
+    /// Restores the storage of the given environment from a snapshot taken
+    /// with [Disk::snapshot].
+    ///
+    /// The base directory of the environment must not exist, or be empty, so
+    /// that a restore never overwrites a ceremony. Like the snapshot, the
+    /// restore is staged and only renamed into place once complete.
+    ///
+    pub fn restore(environment: &Environment, snapshot: &Path) -> Result<(), CoordinatorError> {
+        let base = Path::new(environment.local_base_directory());
+
+        // Check that the snapshot holds a coordinator state.
+        let state_path = DiskResolver::new(&snapshot.to_string_lossy()).to_path(&Locator::CoordinatorState)?;
+        if !state_path.as_path().is_file() {
+            error!("Snapshot {} has no coordinator state", snapshot.display());
+            return Err(CoordinatorError::SnapshotInvalid);
+        }
+
+        // Check that the base directory is empty.
+        if base.exists() && fs::read_dir(base)?.next().is_some() {
+            error!("Base directory {} is not empty", base.display());
+            return Err(CoordinatorError::StorageLocatorAlreadyExists);
+        }
+
+        // Copy the snapshot into the staging directory, and move it into place.
+        let name = base.file_name().map(|name| name.to_string_lossy()).unwrap_or_default();
+        let staging = base.with_file_name(format!(".{}.partial", name));
+        if staging.exists() {
+            fs::remove_dir_all(&staging)?;
+        }
+        copy_directory(snapshot, &staging)?;
+        if base.exists() {
+            fs::remove_dir(base)?;
+        }
+        fs::rename(&staging, base)?;
+
+        debug!("Restored the storage at {} from {}", base.display(), snapshot.display());
         Ok(())
     }
 
@@ -325,6 +504,11 @@ impl Disk {
         Ok(file.metadata()?.len())
     }
 
+    /// Returns the number of bytes available to this storage on its volume.
+    pub fn available_space(&self) -> Result<u64, CoordinatorError> {
+        Ok(fs2::available_space(self.environment.local_base_directory())?)
+    }
+
     /// Process a [StorageAction] which mutates the storage.
     pub fn process(&mut self, action: StorageAction) -> Result<()> {
         match action {
@@ -383,6 +567,14 @@ impl Disk {
                         continue;
                     }
 
+                    // Partial uploads have no locator, so they are removed directly.
+                    if file_path.ends_with(UPLOAD_EXTENSION) {
+                        if let Err(e) = fs::remove_file(&file_path) {
+                            tracing::error!("Could not remove partial upload - {:?}", e);
+                        }
+                        continue;
+                    }
+
                     let locator = match self.resolver.to_locator(&LocatorPath::new(file_path)) {
                         Ok(locator) => locator,
                         Err(e) => {
@@ -582,6 +774,55 @@ impl StorageObject for Disk {
     }
 }
 
+/// Recursively copies the contents of the source directory into the destination directory.
+///
+/// Writes the given bytes to the given writer, hashing them as they are written,
+/// and returns the hash of the written bytes.
+///
+/// If the writer stops accepting bytes before all of them are written,
+/// this function returns `CoordinatorError::StorageCopyTruncated`.
+///
+fn write_hashed<W: Write>(writer: W, bytes: &[u8]) -> Result<Vec<u8>, CoordinatorError> {
+    let mut writer = HashWriter::new(writer);
+    let mut written = 0;
+    while written < bytes.len() {
+        match writer.write(&bytes[written..]) {
+            Ok(0) => {
+                error!(
+                    "Copy is truncated, expected {} bytes but wrote {}",
+                    bytes.len(),
+                    written
+                );
+                return Err(CoordinatorError::StorageCopyTruncated {
+                    expected: bytes.len() as u64,
+                    found: written as u64,
+                });
+            }
+            Ok(n) => written += n,
+            Err(error) if error.kind() == ErrorKind::Interrupted => continue,
+            Err(error) => return Err(error.into()),
+        }
+    }
+    // Sync all in-memory data to disk.
+    writer.flush()?;
+    Ok(writer.into_hash().to_vec())
+}
+
+fn copy_directory(source: &Path, destination: &Path) -> Result<(), CoordinatorError> {
+    fs::create_dir_all(destination)?;
+    for entry in fs::read_dir(source)? {
+        let entry = entry?;
+        let path = entry.path();
+        match entry.file_type()?.is_dir() {
+            true => copy_directory(&path, &destination.join(entry.file_name()))?,
+            false => {
+                fs::copy(&path, destination.join(entry.file_name()))?;
+            }
+        }
+    }
+    Ok(())
+}
+
 #[derive(Debug)]
 struct DiskResolver {
     base: String,
@@ -831,7 +1072,97 @@ impl DiskResolver {
 #[cfg(test)]
 mod tests {
     use super::*;
-    // use crate::testing::prelude::*;
+    use crate::{
+        commands::Initialization,
+        storage::{FaultyDisk, StorageOperation},
+        testing::prelude::*,
+    };
+    use setup_utils::calculate_hash;
+
+    #[test]
+    #[serial]
+    fn test_copy_hash() {
+        initialize_test_environment(&TEST_ENVIRONMENT);
+
+        // Define test storage.
+        let mut storage = test_storage(&TEST_ENVIRONMENT);
+
+        // Generate a challenge to copy from.
+        Initialization::run(&TEST_ENVIRONMENT, &mut storage, 0, 0, false).unwrap();
+        let source = Locator::ContributionFile(ContributionLocator::new(0, 0, 0, true));
+        let destination = Locator::ContributionFile(ContributionLocator::new(0, 0, 1, true));
+        let expected_size = storage.size(&source).unwrap();
+
+        // Check that the copy hash matches the hash of the bytes on disk.
+        let hash = storage.copy(&source, &destination).unwrap();
+        let written = fs::read(storage.to_path(&destination).unwrap()).unwrap();
+        assert_eq!(expected_size, written.len() as u64);
+        assert_eq!(calculate_hash(&written).to_vec(), hash);
+    }
+
+    #[test]
+    fn test_write_hashed_truncated() {
+        let bytes = vec![7u8; 64];
+
+        // Check that the hash is of the bytes written.
+        let mut buffer = vec![0u8; 64];
+        let hash = write_hashed(buffer.as_mut_slice(), &bytes).unwrap();
+        assert_eq!(bytes, buffer);
+        assert_eq!(calculate_hash(&bytes).to_vec(), hash);
+
+        // Check that a writer which stops halfway through is detected.
+        let mut buffer = vec![0u8; 32];
+        assert!(matches!(
+            write_hashed(buffer.as_mut_slice(), &bytes),
+            Err(CoordinatorError::StorageCopyTruncated {
+                expected: 64,
+                found: 32
+            })
+        ));
+    }
+
+    #[test]
+    #[serial]
+    fn test_injected_faults() {
+        initialize_test_environment(&TEST_ENVIRONMENT);
+
+        // Define test storage.
+        let mut storage = FaultyDisk::new(test_storage(&TEST_ENVIRONMENT));
+        Initialization::run(&TEST_ENVIRONMENT, &mut storage, 0, 0, false).unwrap();
+        let source = Locator::ContributionFile(ContributionLocator::new(0, 0, 0, true));
+        let destination = Locator::ContributionFile(ContributionLocator::new(0, 0, 1, true));
+
+        // Check that only the second read fails.
+        storage.faults().fail_nth(StorageOperation::Read, 2);
+        assert!(storage.get(&source).is_ok());
+        assert!(matches!(storage.get(&source), Err(CoordinatorError::IOError(_))));
+        assert!(storage.get(&source).is_ok());
+        storage.faults().fail_nth(StorageOperation::Read, 1);
+        assert!(matches!(storage.reader(&source), Err(CoordinatorError::IOError(_))));
+        assert!(storage.reader(&source).is_ok());
+
+        // Check that only the first write fails.
+        let object = storage.get(&source).unwrap();
+        storage.faults().fail_nth(StorageOperation::Write, 1);
+        assert!(matches!(storage.writer(&source), Err(CoordinatorError::IOError(_))));
+        assert!(storage.update(&source, object).is_ok());
+
+        // Check that a failed copy leaves no destination behind.
+        storage.faults().fail_nth(StorageOperation::Copy, 1);
+        assert!(matches!(
+            storage.copy(&source, &destination),
+            Err(CoordinatorError::IOError(_))
+        ));
+        assert!(!storage.exists(&destination));
+        assert!(storage.copy(&source, &destination).is_ok());
+
+        // Check that a failed initialize creates no file.
+        let next = Locator::ContributionFile(ContributionLocator::new(0, 0, 2, true));
+        storage.faults().fail_nth(StorageOperation::Initialize, 1);
+        assert!(storage.initialize(next.clone(), 64).is_err());
+        assert!(!storage.exists(&next));
+        assert!(storage.initialize(next.clone(), 64).is_ok());
+    }
 
     #[test]
     fn test_to_path_coordinator_state() {
@@ -0,0 +1,135 @@
+use crate::{
+    storage::{Disk, DiskObjectReader, DiskObjectWriter, Locator, Object, StorageObject},
+    CoordinatorError,
+};
+
+use std::{
+    collections::HashMap,
+    io::{Error, ErrorKind},
+    ops::{Deref, DerefMut},
+    sync::Mutex,
+};
+
+/// The storage operations in which a fault may be injected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum StorageOperation {
+    Read,
+    Write,
+    Copy,
+    Initialize,
+}
+
+///
+/// Injects failures into the operations of a [FaultyDisk] storage,
+/// to deterministically test the error handling of the coordinator.
+///
+/// Calls are counted per operation from one.
+///
+#[derive(Debug, Default)]
+pub(crate) struct FaultInjector {
+    /// The number of calls of each operation so far.
+    calls: Mutex<HashMap<StorageOperation, usize>>,
+    /// The number of the call to fail for each operation.
+    faults: Mutex<HashMap<StorageOperation, usize>>,
+}
+
+impl FaultInjector {
+    /// Sets the `n`th call of the given operation, counted from now, to fail.
+    pub(crate) fn fail_nth(&self, operation: StorageOperation, n: usize) {
+        let calls = self.calls.lock().unwrap().get(&operation).copied().unwrap_or(0);
+        self.faults.lock().unwrap().insert(operation, calls + n);
+    }
+
+    /// Counts a call of the given operation, and returns an error if it is set to fail.
+    pub(crate) fn check(&self, operation: StorageOperation) -> Result<(), CoordinatorError> {
+        let mut calls = self.calls.lock().unwrap();
+        let call = calls.entry(operation).or_insert(0);
+        *call += 1;
+
+        let mut faults = self.faults.lock().unwrap();
+        if faults.get(&operation) == Some(call) {
+            faults.remove(&operation);
+            return Err(CoordinatorError::IOError(Error::new(
+                ErrorKind::Other,
+                format!("Injected fault in {:?} call {}", operation, call),
+            )));
+        }
+        Ok(())
+    }
+}
+
+///
+/// Wraps a [Disk] storage, to fail its operations as set in its [FaultInjector].
+///
+/// Only the calls made through the wrapper are counted, so the calls that
+/// an operation of the disk makes internally, such as the read of a copy,
+/// never fail.
+///
+#[derive(Debug)]
+pub(crate) struct FaultyDisk {
+    disk: Disk,
+    faults: FaultInjector,
+}
+
+impl FaultyDisk {
+    /// Wraps the given disk storage, with no faults set.
+    pub(crate) fn new(disk: Disk) -> Self {
+        Self {
+            disk,
+            faults: FaultInjector::default(),
+        }
+    }
+
+    /// Returns the fault injector of the storage, to make its operations fail.
+    pub(crate) fn faults(&self) -> &FaultInjector {
+        &self.faults
+    }
+
+    pub(crate) fn initialize(&mut self, locator: Locator, size: u64) -> Result<(), CoordinatorError> {
+        self.faults.check(StorageOperation::Initialize)?;
+        self.disk.initialize(locator, size)
+    }
+
+    pub(crate) fn get(&self, locator: &Locator) -> Result<Object, CoordinatorError> {
+        self.faults.check(StorageOperation::Read)?;
+        self.disk.get(locator)
+    }
+
+    pub(crate) fn update(&mut self, locator: &Locator, object: Object) -> Result<(), CoordinatorError> {
+        self.faults.check(StorageOperation::Write)?;
+        self.disk.update(locator, object)
+    }
+
+    pub(crate) fn copy(
+        &mut self,
+        source_locator: &Locator,
+        destination_locator: &Locator,
+    ) -> Result<Vec<u8>, CoordinatorError> {
+        self.faults.check(StorageOperation::Copy)?;
+        self.disk.copy(source_locator, destination_locator)
+    }
+
+    pub(crate) fn reader(&self, locator: &Locator) -> Result<DiskObjectReader, CoordinatorError> {
+        self.faults.check(StorageOperation::Read)?;
+        self.disk.reader(locator)
+    }
+
+    pub(crate) fn writer(&self, locator: &Locator) -> Result<DiskObjectWriter, CoordinatorError> {
+        self.faults.check(StorageOperation::Write)?;
+        self.disk.writer(locator)
+    }
+}
+
+impl Deref for FaultyDisk {
+    type Target = Disk;
+
+    fn deref(&self) -> &Self::Target {
+        &self.disk
+    }
+}
+
+impl DerefMut for FaultyDisk {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.disk
+    }
+}
@@ -1,5 +1,10 @@
 pub mod disk;
 pub use disk::*;
 
+#[cfg(test)]
+mod fault;
+#[cfg(test)]
+pub(crate) use fault::{FaultyDisk, StorageOperation};
+
 pub mod storage;
 pub use storage::*;
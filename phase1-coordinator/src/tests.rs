@@ -1,14 +1,18 @@
 use crate::{
     authentication::Dummy,
     commands::{Seed, SigningKey, SEED_LENGTH},
-    environment::{Environment, Parameters, Settings, Testing},
-    objects::Task,
-    storage::{Disk, StorageLocator},
+    environment::{Environment, Parameters, ParticipantAccess, Settings, Testing},
+    objects::{ParticipantRole, Task},
+    runner::run_until_shutdown,
+    storage::{ContributionLocator, Disk, Locator, StorageLocator},
     testing::prelude::*,
+    CeremonyEvent,
     Coordinator,
     CoordinatorError,
+    HealthStatus,
     MockTimeSource,
     Participant,
+    ParticipantEvent,
     Round,
 };
 use phase1::{helpers::CurveKind, ContributionMode, ProvingSystem};
@@ -21,8 +25,11 @@ use std::{
     collections::{HashSet, LinkedList},
     iter::FromIterator,
     net::{IpAddr, Ipv4Addr},
-    sync::Arc,
+    path::Path,
+    sync::{Arc, Mutex},
+    time::Duration,
 };
+use tokio::sync::{watch, RwLock};
 
 fn create_contributor(id: &str) -> (Participant, SigningKey, Seed) {
     let contributor = Participant::Contributor(format!("test-contributor-{}", id));
@@ -1238,6 +1245,170 @@ fn coordinator_drop_contributor_and_release_locks() {
     assert_eq!(0, coordinator.number_of_queue_contributors());
 }
 
+/// Test that a task locked by a contributor can be handed to a chosen
+/// replacement contributor, who then finishes the round in their place.
+#[test]
+#[serial]
+fn coordinator_reassign_task_test() -> anyhow::Result<()> {
+    let parameters = Parameters::Custom(Settings::new(
+        ContributionMode::Chunked,
+        ProvingSystem::Groth16,
+        CurveKind::Bls12_377,
+        6,  /* power */
+        16, /* batch_size */
+        16, /* chunk_size */
+    ));
+    let replacement_contributor_1 = create_contributor_test_details("replacement-1");
+    let replacement_contributor_2 = create_contributor_test_details("replacement-2");
+    let testing = Testing::from(parameters).coordinator_contributors(&[
+        replacement_contributor_1.participant.clone(),
+        replacement_contributor_2.participant.clone(),
+    ]);
+    let environment = initialize_test_environment(&testing.into());
+    let number_of_chunks = environment.number_of_chunks() as usize;
+
+    // Instantiate a coordinator and initialize the ceremony to round 0.
+    let mut coordinator = Coordinator::new(environment, Arc::new(Dummy))?;
+    coordinator.initialize()?;
+
+    let contributor_1 = create_contributor_test_details("1");
+    let contributor_2 = create_contributor_test_details("2");
+    let verifier_1 = create_verifier_test_details("1");
+    let contributor_1_ip = IpAddr::V4("0.0.0.1".parse()?);
+    let contributor_2_ip = IpAddr::V4("0.0.0.2".parse()?);
+    coordinator.add_to_queue(contributor_1.participant.clone(), Some(contributor_1_ip), 10)?;
+    coordinator.add_to_queue(contributor_2.participant.clone(), Some(contributor_2_ip), 9)?;
+
+    // Update the ceremony to round 1, and lock a chunk by the first contributor.
+    coordinator.update()?;
+    let (chunk_id, locked_locators) = coordinator.try_lock(&contributor_1.participant)?;
+    let task = Task::new(chunk_id, locked_locators.next_contribution().contribution_id());
+
+    // Check that the task can only be handed to a coordinator contributor.
+    assert!(matches!(
+        coordinator.reassign_task(task, &contributor_2.participant),
+        Err(CoordinatorError::ParticipantUnauthorized)
+    ));
+
+    // Hand the task to the second replacement contributor, rather than the least loaded one.
+    coordinator.reassign_task(task, &replacement_contributor_2.participant)?;
+    assert!(!coordinator.is_current_contributor(&contributor_1.participant));
+    assert!(!coordinator.is_current_contributor(&replacement_contributor_1.participant));
+    assert!(coordinator.is_current_contributor(&replacement_contributor_2.participant));
+    assert!(
+        !coordinator
+            .current_round()?
+            .is_chunk_locked_by(chunk_id, &contributor_1.participant)
+    );
+
+    // Check that the task cannot be handed over again.
+    assert!(matches!(
+        coordinator.reassign_task(task, &replacement_contributor_2.participant),
+        Err(CoordinatorError::ParticipantAlreadyStarted)
+    ));
+
+    // Finish round 1 with the replacement contributor and the second contributor.
+    for _ in 0..number_of_chunks {
+        replacement_contributor_2.contribute_to(&mut coordinator)?;
+        contributor_2.contribute_to(&mut coordinator)?;
+        verifier_1.verify_if_available(&mut coordinator)?;
+        verifier_1.verify_if_available(&mut coordinator)?;
+    }
+    coordinator.update()?;
+    assert!(coordinator.is_finished_contributor(&replacement_contributor_2.participant));
+    assert!(coordinator.is_finished_contributor(&contributor_2.participant));
+
+    Ok(())
+}
+
+/// Test that a round finished by fewer than the minimum number of contributors,
+/// not counting replacement contributors, is kept open instead of being finalized,
+/// and is restarted once the queue makes up for the missing contributors.
+#[test]
+#[serial]
+fn coordinator_minimum_contributors_to_finalize_test() -> anyhow::Result<()> {
+    let parameters = Parameters::Custom(Settings {
+        contribution_mode: ContributionMode::Chunked,
+        proving_system: ProvingSystem::Groth16,
+        curve: CurveKind::Bls12_377,
+        power: 1,
+        batch_size: 2,
+        chunk_size: 2,
+    });
+    let replacement_contributor = create_contributor_test_details("replacement-1");
+    let testing = Testing::from(parameters)
+        .coordinator_contributors(&[replacement_contributor.participant.clone()])
+        .minimum_contributors_per_round(2);
+    let environment = initialize_test_environment(&testing.into());
+    let number_of_chunks = environment.number_of_chunks() as usize;
+
+    // Instantiate a coordinator and initialize the ceremony to round 0.
+    let mut coordinator = Coordinator::new(environment, Arc::new(Dummy))?;
+    coordinator.initialize()?;
+
+    let contributor_1 = create_contributor_test_details("1");
+    let contributor_2 = create_contributor_test_details("2");
+    let verifier_1 = create_verifier_test_details("1");
+    let contributor_1_ip = IpAddr::V4("0.0.0.1".parse()?);
+    coordinator.add_to_queue(contributor_1.participant.clone(), Some(contributor_1_ip), 10)?;
+    let contributor_2_ip = IpAddr::V4("0.0.0.2".parse()?);
+    coordinator.add_to_queue(contributor_2.participant.clone(), Some(contributor_2_ip), 9)?;
+
+    // Update the ceremony to round 1, and replace the first contributor.
+    coordinator.update()?;
+    coordinator.try_lock(&contributor_1.participant)?;
+    coordinator.drop_participant(&contributor_1.participant)?;
+
+    // Finish round 1 with the replacement contributor and the second contributor.
+    for _ in 0..number_of_chunks {
+        replacement_contributor.contribute_to(&mut coordinator)?;
+        contributor_2.contribute_to(&mut coordinator)?;
+        verifier_1.verify_if_available(&mut coordinator)?;
+        verifier_1.verify_if_available(&mut coordinator)?;
+    }
+
+    // Check that the round is finished, but kept open, as only one contributor counts.
+    coordinator.update()?;
+    let summary = coordinator.current_round_summary()?;
+    assert_eq!(1, summary.round_height);
+    assert_eq!(2, summary.number_of_finished_contributors);
+    assert_eq!(1, summary.number_of_qualifying_contributors);
+    assert_eq!(2, summary.minimum_contributors);
+    assert!(summary.is_finished);
+    assert!(!summary.is_aggregated);
+
+    // Check that the round is reset once the queue makes up for the missing contributor,
+    // and that its contributors, except the replacement contributor, are back in the queue.
+    let contributor_3 = create_contributor_test_details("3");
+    let contributor_3_ip = IpAddr::V4("0.0.0.3".parse()?);
+    coordinator.add_to_queue(contributor_3.participant.clone(), Some(contributor_3_ip), 10)?;
+    coordinator.update()?;
+    assert_eq!(0, coordinator.current_round_height()?);
+    assert!(coordinator.is_queue_contributor(&contributor_2.participant));
+    assert!(coordinator.is_queue_contributor(&contributor_3.participant));
+    assert!(!coordinator.is_queue_contributor(&replacement_contributor.participant));
+
+    // Restart round 1, and finish it with the second and third contributors.
+    coordinator.update()?;
+    assert_eq!(1, coordinator.current_round_height()?);
+    for _ in 0..number_of_chunks {
+        contributor_2.contribute_to(&mut coordinator)?;
+        contributor_3.contribute_to(&mut coordinator)?;
+        verifier_1.verify_if_available(&mut coordinator)?;
+        verifier_1.verify_if_available(&mut coordinator)?;
+    }
+
+    // Check that the round is finalized.
+    coordinator.update()?;
+    let summary = coordinator.current_round_summary()?;
+    assert_eq!(1, summary.round_height);
+    assert_eq!(2, summary.number_of_qualifying_contributors);
+    assert!(summary.is_finished);
+    assert!(summary.is_aggregated);
+
+    Ok(())
+}
+
 /// Drops a few contributors and see what happens
 ///
 /// The goal of this test is to reproduce a specific error
@@ -2171,6 +2342,67 @@ fn participant_lock_timeout_drop_test() -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Test that a lock is returned with its contribution deadline, which is
+/// independent of the lock timeout, and that a contribution past the
+/// deadline is rejected.
+#[test]
+#[serial]
+fn contribution_deadline_test() -> anyhow::Result<()> {
+    let time = Arc::new(MockTimeSource::new(OffsetDateTime::now_utc()));
+
+    let parameters = Parameters::Custom(Settings::new(
+        ContributionMode::Chunked,
+        ProvingSystem::Groth16,
+        CurveKind::Bls12_377,
+        6,  /* power */
+        16, /* batch_size */
+        16, /* chunk_size */
+    ));
+
+    let testing_deployment: Testing = Testing::from(parameters)
+        .contributor_seen_timeout(time::Duration::minutes(20))
+        .participant_lock_timeout(time::Duration::minutes(30))
+        .maximum_contribution_time(time::Duration::minutes(10));
+
+    let environment = initialize_test_environment(&Environment::from(testing_deployment));
+
+    // Instantiate a coordinator.
+    let mut coordinator = Coordinator::new_with_time(environment, Arc::new(Dummy), time.clone())?;
+
+    // Initialize the ceremony to round 0.
+    coordinator.initialize()?;
+
+    let (contributor1, _, _) = create_contributor("1");
+    let contributor_1_ip = IpAddr::V4(Ipv4Addr::UNSPECIFIED);
+
+    coordinator.add_to_queue(contributor1.clone(), Some(contributor_1_ip), 10)?;
+
+    // Update the ceremony to round 1.
+    coordinator.update()?;
+
+    // Check that the lock is returned with its deadline.
+    let (chunk_id, locked_locators) = coordinator.try_lock(&contributor1)?;
+    assert_eq!(
+        Some(time.time() + time::Duration::minutes(10)),
+        locked_locators.deadline()
+    );
+
+    // Push the time past the deadline.
+    time.update(|prev| prev + time::Duration::minutes(11));
+
+    // Check that the contribution is rejected.
+    match coordinator.try_contribute(&contributor1, chunk_id) {
+        Err(CoordinatorError::ContributionDeadlineExceeded) => (),
+        result => panic!("Expected ContributionDeadlineExceeded, found {:?}", result),
+    }
+
+    // Check that the contributor is dropped on the next update.
+    coordinator.update()?;
+    assert_eq!(&contributor1, coordinator.dropped_participants().get(0).unwrap().id());
+
+    Ok(())
+}
+
 /// Test that a participant who stays in the queue for more
 /// than [Environment::queue_seen_timeout] is dropped from the
 /// queue by the coordinator.
@@ -2239,6 +2471,1130 @@ fn queue_seen_timeout_drop_test() -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Test that a paused ceremony rejects writes but still serves reads,
+/// and that the pause survives a restart of the coordinator.
+#[test]
+#[serial]
+fn pause_and_resume_test() -> anyhow::Result<()> {
+    let parameters = Parameters::Custom(Settings::new(
+        ContributionMode::Chunked,
+        ProvingSystem::Groth16,
+        CurveKind::Bls12_377,
+        6,  /* power */
+        16, /* batch_size */
+        16, /* chunk_size */
+    ));
+    let environment = initialize_test_environment(&Testing::from(parameters).into());
+
+    // Instantiate a coordinator.
+    let mut coordinator = Coordinator::new(environment.clone(), Arc::new(Dummy))?;
+
+    // Initialize the ceremony to round 0.
+    coordinator.initialize()?;
+
+    let (contributor1, _, _) = create_contributor("1");
+    let contributor_1_ip = IpAddr::V4("0.0.0.1".parse().unwrap());
+    coordinator.add_to_queue(contributor1.clone(), Some(contributor_1_ip), 10)?;
+
+    // Update the ceremony to round 1.
+    coordinator.update()?;
+    assert_eq!(1, coordinator.current_round_height()?);
+
+    // Pause the ceremony, and restart the coordinator.
+    coordinator.pause()?;
+    let mut coordinator = Coordinator::new(environment, Arc::new(Dummy))?;
+    assert!(coordinator.is_paused());
+
+    // Check that writes are rejected.
+    let (contributor2, _, _) = create_contributor("2");
+    let contributor_2_ip = IpAddr::V4("0.0.0.2".parse().unwrap());
+    assert!(matches!(
+        coordinator.add_to_queue(contributor2.clone(), Some(contributor_2_ip), 10),
+        Err(CoordinatorError::Paused)
+    ));
+    assert!(matches!(
+        coordinator.try_lock(&contributor1),
+        Err(CoordinatorError::Paused)
+    ));
+    assert!(matches!(
+        coordinator.try_contribute(&contributor1, 0),
+        Err(CoordinatorError::Paused)
+    ));
+
+    // Check that reads still succeed.
+    assert_eq!(1, coordinator.current_round_height()?);
+    assert!(coordinator.current_round().is_ok());
+
+    // Resume the ceremony, and check that writes are accepted again.
+    coordinator.resume()?;
+    assert!(!coordinator.is_paused());
+    coordinator.add_to_queue(contributor2.clone(), Some(contributor_2_ip), 10)?;
+    assert!(coordinator.is_queue_contributor(&contributor2));
+    coordinator.try_lock(&contributor1)?;
+
+    Ok(())
+}
+
+/// Test that the health of the coordinator reflects its state, and
+/// that it is unavailable once its storage can no longer be read.
+#[test]
+#[serial]
+fn coordinator_health_test() -> anyhow::Result<()> {
+    let time = Arc::new(MockTimeSource::new(OffsetDateTime::now_utc()));
+
+    let parameters = Parameters::Custom(Settings::new(
+        ContributionMode::Chunked,
+        ProvingSystem::Groth16,
+        CurveKind::Bls12_377,
+        6,  /* power */
+        16, /* batch_size */
+        16, /* chunk_size */
+    ));
+    let environment = initialize_test_environment(&Testing::from(parameters).into());
+
+    // Instantiate a coordinator.
+    let mut coordinator = Coordinator::new_with_time(environment.clone(), Arc::new(Dummy), time.clone())?;
+
+    // Initialize the ceremony to round 0.
+    coordinator.initialize()?;
+
+    let (contributor1, _, _) = create_contributor("1");
+    let contributor_1_ip = IpAddr::V4("0.0.0.1".parse().unwrap());
+    coordinator.add_to_queue(contributor1, Some(contributor_1_ip), 10)?;
+
+    time.update(|now| now + time::Duration::seconds(30));
+    let health = coordinator.health();
+    assert_eq!(HealthStatus::Ok, health.status);
+    assert_eq!(Some(0), health.round_height);
+    assert_eq!(1, health.queue_len);
+    assert_eq!(30, health.uptime_seconds);
+
+    // Remove the storage from under the coordinator.
+    fs::remove_dir_all(environment.local_base_directory())?;
+    let health = coordinator.health();
+    assert_eq!(HealthStatus::Unavailable, health.status);
+    assert_eq!(None, health.round_height);
+
+    Ok(())
+}
+
+/// Test that verifications run on the thread pool sized by the environment,
+/// and that its size is reported in the health of the coordinator.
+#[test]
+#[serial]
+fn verification_threads_test() -> anyhow::Result<()> {
+    let parameters = Parameters::Custom(Settings::new(
+        ContributionMode::Chunked,
+        ProvingSystem::Groth16,
+        CurveKind::Bls12_377,
+        6,  /* power */
+        16, /* batch_size */
+        16, /* chunk_size */
+    ));
+    let testing_deployment: Testing = Testing::from(parameters).verification_threads(2);
+    let environment = initialize_test_environment(&Environment::from(testing_deployment));
+
+    // Instantiate a coordinator.
+    let mut coordinator = Coordinator::new(environment, Arc::new(Dummy))?;
+    assert_eq!(2, coordinator.health().verification_threads);
+
+    // Initialize the ceremony to round 0.
+    coordinator.initialize()?;
+
+    let (contributor1, contributor_signing_key1, seed1) = create_contributor("1");
+    let (verifier, verifier_signing_key) = create_verifier("1");
+    let contributor_1_ip = IpAddr::V4("0.0.0.1".parse().unwrap());
+    coordinator.add_to_queue(contributor1.clone(), Some(contributor_1_ip), 10)?;
+
+    // Update the ceremony to round 1.
+    coordinator.update()?;
+
+    // Check that a contribution is verified on the dedicated thread pool.
+    coordinator.contribute(&contributor1, &contributor_signing_key1, &seed1)?;
+    verify_task_if_available(&mut coordinator, &verifier, &verifier_signing_key)?;
+    assert!(coordinator.get_pending_verifications().is_empty());
+
+    Ok(())
+}
+
+/// Test that a snapshot of a running ceremony can be restored into a
+/// new storage, from which a coordinator resumes the ceremony.
+#[test]
+#[serial]
+fn snapshot_and_restore_test() -> anyhow::Result<()> {
+    let parameters = Parameters::Custom(Settings::new(
+        ContributionMode::Chunked,
+        ProvingSystem::Groth16,
+        CurveKind::Bls12_377,
+        6,  /* power */
+        16, /* batch_size */
+        16, /* chunk_size */
+    ));
+    let testing_deployment = Testing::from(parameters);
+    let environment = initialize_test_environment(&testing_deployment.clone().into());
+    let restored_environment =
+        initialize_test_environment(&testing_deployment.local_base_directory("./transcript/restored").into());
+    let snapshots = "./transcript/snapshots";
+    if Path::new(snapshots).exists() {
+        fs::remove_dir_all(snapshots)?;
+    }
+
+    // Instantiate a coordinator.
+    let mut coordinator = Coordinator::new(environment, Arc::new(Dummy))?;
+
+    // Initialize the ceremony to round 0.
+    coordinator.initialize()?;
+
+    let (contributor1, _, _) = create_contributor("1");
+    let (contributor2, _, _) = create_contributor("2");
+    let contributor_ip = IpAddr::V4("0.0.0.1".parse().unwrap());
+    coordinator.add_to_queue(contributor1.clone(), Some(contributor_ip), 10)?;
+
+    // Update the ceremony to round 1, and take a snapshot.
+    coordinator.update()?;
+    let snapshot = coordinator.snapshot(snapshots)?;
+
+    // Check that changes after the snapshot are not in the snapshot.
+    coordinator.add_to_queue(contributor2.clone(), Some(contributor_ip), 10)?;
+    Coordinator::restore(&restored_environment, &snapshot)?;
+    let restored = Coordinator::new(restored_environment.clone(), Arc::new(Dummy))?;
+    assert_eq!(1, restored.current_round_height()?);
+    assert!(restored.is_current_contributor(&contributor1));
+    assert!(!restored.is_queue_contributor(&contributor2));
+
+    // Check that a restore never overwrites an existing storage.
+    assert!(matches!(
+        Coordinator::restore(&restored_environment, &snapshot),
+        Err(CoordinatorError::StorageLocatorAlreadyExists)
+    ));
+
+    fs::remove_dir_all(snapshots)?;
+    fs::remove_dir_all(restored_environment.local_base_directory())?;
+    Ok(())
+}
+
+/// Test that the self check reports a truncated contribution file, and that
+/// a strict environment refuses to start on an inconsistent storage.
+#[test]
+#[serial]
+fn self_check_test() -> anyhow::Result<()> {
+    let parameters = Parameters::Custom(Settings::new(
+        ContributionMode::Chunked,
+        ProvingSystem::Groth16,
+        CurveKind::Bls12_377,
+        6,  /* power */
+        16, /* batch_size */
+        16, /* chunk_size */
+    ));
+    let testing_deployment = Testing::from(parameters);
+    let environment = initialize_test_environment(&testing_deployment.clone().into());
+
+    // Instantiate a coordinator.
+    let mut coordinator = Coordinator::new(environment, Arc::new(Dummy))?;
+
+    // Initialize the ceremony to round 0.
+    coordinator.initialize()?;
+
+    let (contributor, _, _) = create_contributor("1");
+    let contributor_ip = IpAddr::V4("0.0.0.1".parse().unwrap());
+    coordinator.add_to_queue(contributor, Some(contributor_ip), 10)?;
+
+    // Update the ceremony to round 1, and check that the storage is consistent.
+    coordinator.update()?;
+    assert_eq!(1, coordinator.current_round_height()?);
+    assert!(coordinator.self_check()?.is_empty());
+
+    // Truncate the challenge of chunk 0 in round 1.
+    let locator = Locator::ContributionFile(ContributionLocator::new(1, 0, 0, true));
+    let path = coordinator.locator_to_path(locator)?;
+    let challenge = fs::read(&path)?;
+    fs::write(&path, &challenge[..challenge.len() / 2])?;
+
+    // Check that the self check reports the truncated challenge.
+    let problems = coordinator.self_check()?;
+    assert_eq!(1, problems.len());
+    assert!(problems[0].contains(&path.to_string()));
+
+    // Check that a lenient environment starts, and that a strict environment does not.
+    let mut lenient = Coordinator::new(testing_deployment.clone().into(), Arc::new(Dummy))?;
+    lenient.initialize()?;
+    let mut strict = Coordinator::new(testing_deployment.strict_self_check(true).into(), Arc::new(Dummy))?;
+    assert!(matches!(
+        strict.initialize(),
+        Err(CoordinatorError::StorageInconsistent)
+    ));
+
+    Ok(())
+}
+
+/// Test that only the participants permitted by the environment can join
+/// the queue, and that the permitted participants can be replaced.
+#[test]
+#[serial]
+fn participant_access_test() -> anyhow::Result<()> {
+    let (contributor1, _, _) = create_contributor("1");
+    let (contributor2, _, _) = create_contributor("2");
+    let (contributor3, _, _) = create_contributor("3");
+    let contributor_ip = IpAddr::V4("0.0.0.1".parse().unwrap());
+
+    let parameters = Parameters::Custom(Settings::new(
+        ContributionMode::Chunked,
+        ProvingSystem::Groth16,
+        CurveKind::Bls12_377,
+        6,  /* power */
+        16, /* batch_size */
+        16, /* chunk_size */
+    ));
+    let testing_deployment: Testing =
+        Testing::from(parameters).participant_access(ParticipantAccess::Allow(vec![contributor1.clone()]));
+    let environment = initialize_test_environment(&Environment::from(testing_deployment));
+
+    // Instantiate a coordinator.
+    let mut coordinator = Coordinator::new(environment, Arc::new(Dummy))?;
+
+    // Initialize the ceremony to round 0.
+    coordinator.initialize()?;
+
+    // Check that only the allowed contributor can join the queue.
+    assert!(matches!(
+        coordinator.add_to_queue(contributor2.clone(), Some(contributor_ip), 10),
+        Err(CoordinatorError::ParticipantNotAllowed)
+    ));
+    coordinator.add_to_queue(contributor1, Some(contributor_ip), 10)?;
+
+    // Check that a denied contributor is rejected once the access is replaced.
+    coordinator.set_participant_access(ParticipantAccess::Deny(vec![contributor2.clone()]));
+    assert!(matches!(
+        coordinator.add_to_queue(contributor2, Some(contributor_ip), 10),
+        Err(CoordinatorError::ParticipantNotAllowed)
+    ));
+    coordinator.add_to_queue(contributor3, Some(contributor_ip), 10)?;
+    assert_eq!(2, coordinator.number_of_queue_contributors());
+
+    Ok(())
+}
+
+/// Test that joining the queue returns the position of the contributor,
+/// accounting for the priority tiers of the queue.
+#[test]
+#[serial]
+fn add_to_queue_position_test() -> anyhow::Result<()> {
+    let (contributor1, _, _) = create_contributor("1");
+    let (contributor2, _, _) = create_contributor("2");
+    let (contributor3, _, _) = create_contributor("3");
+    let contributor_ip = IpAddr::V4("0.0.0.1".parse().unwrap());
+
+    let parameters = Parameters::Custom(Settings::new(
+        ContributionMode::Chunked,
+        ProvingSystem::Groth16,
+        CurveKind::Bls12_377,
+        6,  /* power */
+        16, /* batch_size */
+        16, /* chunk_size */
+    ));
+    let testing_deployment: Testing =
+        Testing::from(parameters).queue_priority_contributors(&[(contributor3.clone(), 1)]);
+    let environment = initialize_test_environment(&Environment::from(testing_deployment));
+
+    // Instantiate a coordinator.
+    let mut coordinator = Coordinator::new(environment, Arc::new(Dummy))?;
+
+    // Initialize the ceremony to round 0.
+    coordinator.initialize()?;
+
+    let position = coordinator.add_to_queue(contributor1, Some(contributor_ip), 10)?;
+    assert_eq!((1, 1), (position.position, position.queue_size));
+
+    let position = coordinator.add_to_queue(contributor2, Some(contributor_ip), 10)?;
+    assert_eq!((2, 2), (position.position, position.queue_size));
+
+    // Check that a priority contributor is placed ahead of the others.
+    let position = coordinator.add_to_queue(contributor3, Some(contributor_ip), 10)?;
+    assert_eq!((1, 3), (position.position, position.queue_size));
+
+    Ok(())
+}
+
+/// Test the position of a contributor in the queue, as the queue changes
+/// and once the contributor leaves it for a round.
+#[test]
+#[serial]
+fn queue_position_test() -> anyhow::Result<()> {
+    let (contributor1, _, _) = create_contributor("1");
+    let (contributor2, _, _) = create_contributor("2");
+    let contributor_ip = IpAddr::V4("0.0.0.1".parse().unwrap());
+
+    let parameters = Parameters::Custom(Settings::new(
+        ContributionMode::Chunked,
+        ProvingSystem::Groth16,
+        CurveKind::Bls12_377,
+        6,  /* power */
+        16, /* batch_size */
+        16, /* chunk_size */
+    ));
+    let environment = initialize_test_environment(&Testing::from(parameters).into());
+
+    // Instantiate a coordinator.
+    let mut coordinator = Coordinator::new(environment, Arc::new(Dummy))?;
+
+    // Initialize the ceremony to round 0.
+    coordinator.initialize()?;
+
+    // Check that a contributor outside the queue has no position.
+    assert_eq!(None, coordinator.queue_position(&contributor1));
+
+    coordinator.add_to_queue(contributor1.clone(), Some(contributor_ip), 10)?;
+    coordinator.add_to_queue(contributor2.clone(), Some(contributor_ip), 10)?;
+    assert_eq!(Some((1, 2)), coordinator.queue_position(&contributor1));
+    assert_eq!(Some((2, 2)), coordinator.queue_position(&contributor2));
+
+    // Check that the contributors behind move up when a contributor leaves the queue.
+    coordinator.remove_from_queue(&contributor1)?;
+    assert_eq!(None, coordinator.queue_position(&contributor1));
+    assert_eq!(Some((1, 1)), coordinator.queue_position(&contributor2));
+
+    // Check that a contributor has no position once they join the round.
+    coordinator.update()?;
+    assert!(coordinator.is_current_contributor(&contributor2));
+    assert_eq!(None, coordinator.queue_position(&contributor2));
+
+    Ok(())
+}
+
+/// Test that the event callback is called on every event in the lifecycle of a round.
+#[test]
+#[serial]
+fn event_callback_test() -> anyhow::Result<()> {
+    let parameters = Parameters::Custom(Settings::new(
+        ContributionMode::Chunked,
+        ProvingSystem::Groth16,
+        CurveKind::Bls12_377,
+        6,  /* power */
+        16, /* batch_size */
+        16, /* chunk_size */
+    ));
+    let environment = initialize_test_environment(&Testing::from(parameters).into());
+    let number_of_chunks = environment.number_of_chunks() as usize;
+
+    // Instantiate a coordinator, which records its events.
+    let mut coordinator = Coordinator::new(environment, Arc::new(Dummy))?;
+    let events = Arc::new(Mutex::new(vec![]));
+    let recorded_events = events.clone();
+    coordinator.set_event_callback(Arc::new(move |event| recorded_events.lock().unwrap().push(event)));
+
+    // Initialize the ceremony to round 0.
+    coordinator.initialize()?;
+
+    let (contributor, contributor_signing_key, seed) = create_contributor("1");
+    let contributor_ip = IpAddr::V4(Ipv4Addr::UNSPECIFIED);
+    let (verifier, verifier_signing_key) = create_verifier("1");
+    coordinator.add_to_queue(contributor.clone(), Some(contributor_ip), 10)?;
+
+    // Advance the ceremony from round 0 to round 1, and run round 1.
+    coordinator.update()?;
+    for _ in 0..number_of_chunks {
+        coordinator.contribute(&contributor, &contributor_signing_key, &seed)?;
+        verify_task_if_available(&mut coordinator, &verifier, &verifier_signing_key)?;
+    }
+
+    // Update the ceremony from round 1 to round 2.
+    let (contributor2, _, _) = create_contributor("2");
+    coordinator.add_to_queue(contributor2, Some(contributor_ip), 10)?;
+    coordinator.update()?;
+    assert_eq!(2, coordinator.current_round_height()?);
+
+    let events = events.lock().unwrap();
+    let count = |f: fn(&CeremonyEvent) -> bool| events.iter().filter(|event| f(event)).count();
+    assert_eq!(
+        &[
+            CeremonyEvent::RoundFinalized { round_height: 0 },
+            CeremonyEvent::RoundStarted { round_height: 1 }
+        ],
+        &events[..2]
+    );
+    assert_eq!(
+        number_of_chunks,
+        count(|event| matches!(event, CeremonyEvent::ChunkLocked { .. }))
+    );
+    assert_eq!(
+        number_of_chunks,
+        count(|event| matches!(event, CeremonyEvent::ContributionAccepted { .. }))
+    );
+    assert_eq!(
+        number_of_chunks,
+        count(|event| matches!(event, CeremonyEvent::VerificationCompleted { .. }))
+    );
+    assert_eq!(
+        &[
+            CeremonyEvent::RoundFinalized { round_height: 1 },
+            CeremonyEvent::RoundStarted { round_height: 2 }
+        ],
+        &events[events.len() - 2..]
+    );
+
+    Ok(())
+}
+
+/// Test that the round summary follows the progress of the contributions and verifications.
+#[test]
+#[serial]
+fn current_round_summary_test() -> anyhow::Result<()> {
+    let parameters = Parameters::Custom(Settings::new(
+        ContributionMode::Chunked,
+        ProvingSystem::Groth16,
+        CurveKind::Bls12_377,
+        6,  /* power */
+        16, /* batch_size */
+        16, /* chunk_size */
+    ));
+    let environment = initialize_test_environment(&Testing::from(parameters).into());
+    let number_of_chunks = environment.number_of_chunks() as usize;
+
+    // Instantiate a coordinator and initialize the ceremony to round 0.
+    let mut coordinator = Coordinator::new(environment, Arc::new(Dummy))?;
+    coordinator.initialize()?;
+
+    let (contributor, contributor_signing_key, seed) = create_contributor("1");
+    let contributor_ip = IpAddr::V4(Ipv4Addr::UNSPECIFIED);
+    let (verifier, verifier_signing_key) = create_verifier("1");
+    coordinator.add_to_queue(contributor.clone(), Some(contributor_ip), 10)?;
+
+    // Advance the ceremony from round 0 to round 1.
+    coordinator.update()?;
+    let summary = coordinator.current_round_summary()?;
+    assert_eq!(1, summary.round_height);
+    assert_eq!(1, summary.number_of_contributors);
+    assert_eq!(0, summary.number_of_finished_contributors);
+    assert_eq!(0, summary.number_of_pending_verifications);
+    assert!(!summary.is_finished);
+
+    // Check that an unverified contribution is pending.
+    coordinator.contribute(&contributor, &contributor_signing_key, &seed)?;
+    assert_eq!(1, coordinator.current_round_summary()?.number_of_pending_verifications);
+
+    // Run the rest of round 1, and aggregate it.
+    verify_task_if_available(&mut coordinator, &verifier, &verifier_signing_key)?;
+    for _ in 1..number_of_chunks {
+        coordinator.contribute(&contributor, &contributor_signing_key, &seed)?;
+        verify_task_if_available(&mut coordinator, &verifier, &verifier_signing_key)?;
+    }
+    coordinator.update()?;
+
+    let summary = coordinator.current_round_summary()?;
+    assert_eq!(1, summary.round_height);
+    assert_eq!(1, summary.number_of_contributors);
+    assert_eq!(1, summary.number_of_finished_contributors);
+    assert_eq!(0, summary.number_of_pending_verifications);
+    assert!(summary.is_finished);
+    assert!(summary.is_aggregated);
+
+    Ok(())
+}
+
+/// Test that the participants of a round are listed with their roles,
+/// once per role, for the current round and for past rounds.
+#[test]
+#[serial]
+fn participants_in_round_test() -> anyhow::Result<()> {
+    let parameters = Parameters::Custom(Settings::new(
+        ContributionMode::Chunked,
+        ProvingSystem::Groth16,
+        CurveKind::Bls12_377,
+        6,  /* power */
+        16, /* batch_size */
+        16, /* chunk_size */
+    ));
+    let environment = initialize_test_environment(&Testing::from(parameters).into());
+    let number_of_chunks = environment.number_of_chunks() as usize;
+
+    // Instantiate a coordinator and initialize the ceremony to round 0.
+    let mut coordinator = Coordinator::new(environment, Arc::new(Dummy))?;
+    coordinator.initialize()?;
+
+    let (contributor1, contributor_signing_key1, seed1) = create_contributor("1");
+    let (contributor2, contributor_signing_key2, seed2) = create_contributor("2");
+    let (verifier, verifier_signing_key) = create_verifier("1");
+    coordinator.add_to_queue(contributor1.clone(), Some(IpAddr::V4("0.0.0.1".parse().unwrap())), 10)?;
+
+    // Run round 1 with the first contributor.
+    coordinator.update()?;
+    for _ in 0..number_of_chunks {
+        coordinator.contribute(&contributor1, &contributor_signing_key1, &seed1)?;
+        verify_task_if_available(&mut coordinator, &verifier, &verifier_signing_key)?;
+    }
+
+    // Check that only the verified contributions are listed in the current round.
+    let participants = coordinator.participants_in_round(1)?;
+    assert!(participants.contains(&(contributor1.clone(), ParticipantRole::Contributor)));
+    assert!(participants.contains(&(verifier.clone(), ParticipantRole::Verifier)));
+    assert!(!participants.contains(&(contributor2.clone(), ParticipantRole::Contributor)));
+    assert_eq!(
+        1,
+        participants
+            .iter()
+            .filter(|(_, role)| *role == ParticipantRole::Contributor)
+            .count()
+    );
+
+    // Advance to round 2 with the second contributor.
+    coordinator.add_to_queue(contributor2.clone(), Some(IpAddr::V4("0.0.0.2".parse().unwrap())), 10)?;
+    coordinator.update()?;
+    assert_eq!(2, coordinator.current_round_height()?);
+    coordinator.contribute(&contributor2, &contributor_signing_key2, &seed2)?;
+    verify_task_if_available(&mut coordinator, &verifier, &verifier_signing_key)?;
+
+    // Check that the past round is unchanged, and that the current round lists the new contributor.
+    assert_eq!(participants, coordinator.participants_in_round(1)?);
+    let participants = coordinator.participants_in_round(2)?;
+    assert!(participants.contains(&(contributor2, ParticipantRole::Contributor)));
+    assert!(!participants.contains(&(contributor1, ParticipantRole::Contributor)));
+
+    // Check that a round that does not exist is an error.
+    assert!(coordinator.participants_in_round(3).is_err());
+
+    Ok(())
+}
+
+/// Test that the timeline of a contributor follows them from the queue
+/// through the round they finish.
+#[test]
+#[serial]
+fn participant_timeline_test() -> anyhow::Result<()> {
+    let parameters = Parameters::Custom(Settings::new(
+        ContributionMode::Chunked,
+        ProvingSystem::Groth16,
+        CurveKind::Bls12_377,
+        6,  /* power */
+        16, /* batch_size */
+        16, /* chunk_size */
+    ));
+    let environment = initialize_test_environment(&Testing::from(parameters).into());
+    let number_of_chunks = environment.number_of_chunks() as usize;
+
+    // Instantiate a coordinator and initialize the ceremony to round 0.
+    let mut coordinator = Coordinator::new(environment, Arc::new(Dummy))?;
+    coordinator.initialize()?;
+
+    let (contributor, contributor_signing_key, seed) = create_contributor("1");
+    let (verifier, verifier_signing_key) = create_verifier("1");
+    let events = |coordinator: &Coordinator| -> Vec<ParticipantEvent> {
+        let timeline = coordinator.participant_timeline(&contributor);
+        assert!(timeline.windows(2).all(|pair| pair[0].time <= pair[1].time));
+        timeline.into_iter().map(|entry| entry.event).collect()
+    };
+    assert!(events(&coordinator).is_empty());
+
+    // Check that joining the queue is recorded.
+    coordinator.add_to_queue(contributor.clone(), Some(IpAddr::V4(Ipv4Addr::UNSPECIFIED)), 10)?;
+    assert_eq!(vec![ParticipantEvent::JoinedQueue], events(&coordinator));
+
+    // Check that the start of the round is recorded.
+    coordinator.update()?;
+    let timeline = events(&coordinator);
+    assert!(timeline.contains(&ParticipantEvent::SelectedForRound { round_height: 1 }));
+    assert!(timeline.contains(&ParticipantEvent::StartedRound { round_height: 1 }));
+
+    // Check that the acceptance and the verification of a contribution are recorded.
+    coordinator.contribute(&contributor, &contributor_signing_key, &seed)?;
+    let chunk_id = match events(&coordinator).last() {
+        Some(ParticipantEvent::ContributionAccepted {
+            round_height: 1,
+            chunk_id,
+        }) => *chunk_id,
+        event => panic!("Expected an accepted contribution, found {:?}", event),
+    };
+    verify_task_if_available(&mut coordinator, &verifier, &verifier_signing_key)?;
+    assert_eq!(
+        Some(&ParticipantEvent::VerificationCompleted {
+            round_height: 1,
+            chunk_id
+        }),
+        events(&coordinator).last()
+    );
+
+    // Finish the round, and check that it is the last event.
+    for _ in 1..number_of_chunks {
+        coordinator.contribute(&contributor, &contributor_signing_key, &seed)?;
+        verify_task_if_available(&mut coordinator, &verifier, &verifier_signing_key)?;
+    }
+    coordinator.update()?;
+    let timeline = events(&coordinator);
+    assert_eq!(
+        Some(&ParticipantEvent::FinishedRound { round_height: 1 }),
+        timeline.last()
+    );
+
+    // Check that the events of the round are kept once the contributor left the queue.
+    assert_eq!(Some(&ParticipantEvent::JoinedQueue), timeline.first());
+    let count = |f: fn(&ParticipantEvent) -> bool| timeline.iter().filter(|event| f(event)).count();
+    assert_eq!(
+        number_of_chunks,
+        count(|event| matches!(event, ParticipantEvent::ContributionAccepted { .. }))
+    );
+    assert_eq!(
+        number_of_chunks,
+        count(|event| matches!(event, ParticipantEvent::VerificationCompleted { .. }))
+    );
+
+    Ok(())
+}
+
+/// Test that joining the queue again updates the queue entry and keeps the
+/// position, and that a current contributor cannot join the queue if the
+/// environment forbids it.
+#[test]
+#[serial]
+fn add_to_queue_twice_test() -> anyhow::Result<()> {
+    let (contributor1, _, _) = create_contributor("1");
+    let (contributor2, _, _) = create_contributor("2");
+    let contributor_1_ip = IpAddr::V4("0.0.0.1".parse().unwrap());
+    let contributor_2_ip = IpAddr::V4("0.0.0.2".parse().unwrap());
+
+    let parameters = Parameters::Custom(Settings::new(
+        ContributionMode::Chunked,
+        ProvingSystem::Groth16,
+        CurveKind::Bls12_377,
+        6,  /* power */
+        16, /* batch_size */
+        16, /* chunk_size */
+    ));
+    let testing_deployment = Testing::from(parameters).allow_current_contributors_in_queue(false);
+    let environment = initialize_test_environment(&testing_deployment.into());
+
+    // Instantiate a coordinator.
+    let mut coordinator = Coordinator::new(environment, Arc::new(Dummy))?;
+
+    // Initialize the ceremony to round 0.
+    coordinator.initialize()?;
+
+    // Check that joining again keeps the position.
+    coordinator.add_to_queue(contributor1.clone(), Some(contributor_1_ip), 10)?;
+    coordinator.add_to_queue(contributor2.clone(), Some(contributor_2_ip), 10)?;
+    let position = coordinator.add_to_queue(contributor1.clone(), Some(contributor_1_ip), 10)?;
+    assert_eq!((1, 2), (position.position, position.queue_size));
+    assert_eq!(Some((1, 2)), coordinator.queue_position(&contributor1));
+
+    // Check that joining again from the IP of another contributor updates the IP and
+    // reliability score of the queue entry, and keeps the time of joining and the position.
+    let queue_entry = |coordinator: &Coordinator| {
+        coordinator
+            .queue_contributors()
+            .into_iter()
+            .find(|(participant, _)| *participant == contributor1)
+            .map(|(_, entry)| entry)
+            .unwrap()
+    };
+    let (_, _, _, joined) = queue_entry(&coordinator);
+    let position = coordinator.add_to_queue(contributor1.clone(), Some(contributor_2_ip), 10)?;
+    assert_eq!((1, 2), (position.position, position.queue_size));
+    let (reliability_score, _, _, rejoined) = queue_entry(&coordinator);
+    assert_eq!(0, reliability_score);
+    assert_eq!(joined, rejoined);
+    assert!(!coordinator.state().is_duplicate_ip(&contributor_1_ip));
+
+    // Update the ceremony to round 1, and check that a current contributor cannot join.
+    coordinator.update()?;
+    assert!(coordinator.is_current_contributor(&contributor1));
+    assert!(matches!(
+        coordinator.add_to_queue(contributor1, Some(contributor_1_ip), 10),
+        Err(CoordinatorError::AlreadyContributing)
+    ));
+
+    Ok(())
+}
+
+/// Test that a contributor can lock a chunk of their choosing, and
+/// that the chunk is no longer assignable to them once locked.
+#[test]
+#[serial]
+fn try_lock_for_chunk_test() -> anyhow::Result<()> {
+    let (contributor, _, _) = create_contributor("1");
+    let contributor_ip = IpAddr::V4("0.0.0.1".parse().unwrap());
+
+    let parameters = Parameters::Custom(Settings::new(
+        ContributionMode::Chunked,
+        ProvingSystem::Groth16,
+        CurveKind::Bls12_377,
+        6,  /* power */
+        16, /* batch_size */
+        16, /* chunk_size */
+    ));
+    let environment = initialize_test_environment(&Testing::from(parameters).into());
+    let last_chunk_id = environment.number_of_chunks() - 1;
+
+    // Instantiate a coordinator.
+    let mut coordinator = Coordinator::new(environment, Arc::new(Dummy))?;
+
+    // Initialize the ceremony to round 0.
+    coordinator.initialize()?;
+    coordinator.add_to_queue(contributor.clone(), Some(contributor_ip), 10)?;
+
+    // Update the ceremony to round 1.
+    coordinator.update()?;
+    assert_eq!(1, coordinator.current_round_height()?);
+
+    // Check that the contributor can lock the last chunk, ahead of its turn.
+    let (chunk_id, locked_locators) = coordinator.try_lock_for_chunk(&contributor, last_chunk_id)?;
+    assert_eq!(last_chunk_id, chunk_id);
+
+    // Check that the initial challenge of the ceremony has no previous contribution hash.
+    assert_eq!(None, locked_locators.previous_contribution_hash());
+
+    // Check that the same chunk cannot be locked again.
+    assert!(matches!(
+        coordinator.try_lock_for_chunk(&contributor, last_chunk_id),
+        Err(CoordinatorError::ParticipantUnauthorizedForChunkId { chunk_id }) if chunk_id == last_chunk_id
+    ));
+
+    // Check that the auto-assigned path still locks another chunk.
+    let (chunk_id, _) = coordinator.try_lock(&contributor)?;
+    assert_ne!(last_chunk_id, chunk_id);
+
+    Ok(())
+}
+
+/// Test that a lock carries the hash of the previous contribution,
+/// which the challenge to download begins with.
+#[test]
+#[serial]
+fn try_lock_previous_contribution_hash_test() -> anyhow::Result<()> {
+    let (contributor1, contributor_signing_key1, seed1) = create_contributor("1");
+    let (contributor2, _, _) = create_contributor("2");
+    let (verifier, verifier_signing_key) = create_verifier("1");
+
+    let parameters = Parameters::Custom(Settings::new(
+        ContributionMode::Chunked,
+        ProvingSystem::Groth16,
+        CurveKind::Bls12_377,
+        6,  /* power */
+        16, /* batch_size */
+        16, /* chunk_size */
+    ));
+    let environment = initialize_test_environment(&Testing::from(parameters).into());
+
+    // Instantiate a coordinator.
+    let mut coordinator = Coordinator::new(environment, Arc::new(Dummy))?;
+
+    // Initialize the ceremony to round 0.
+    coordinator.initialize()?;
+    coordinator.add_to_queue(contributor1.clone(), Some(IpAddr::V4("0.0.0.1".parse().unwrap())), 10)?;
+    coordinator.add_to_queue(contributor2.clone(), Some(IpAddr::V4("0.0.0.2".parse().unwrap())), 10)?;
+
+    // Update the ceremony to round 1.
+    coordinator.update()?;
+    assert_eq!(1, coordinator.current_round_height()?);
+
+    // Contribute to a chunk with the first contributor, and verify the contribution.
+    coordinator.contribute(&contributor1, &contributor_signing_key1, &seed1)?;
+    let chunk_id = coordinator
+        .get_pending_verifications()
+        .keys()
+        .next()
+        .unwrap()
+        .chunk_id();
+    verify_task_if_available(&mut coordinator, &verifier, &verifier_signing_key)?;
+
+    // Check that the second contributor gets the hash the challenge begins with.
+    let (_, locked_locators) = coordinator.try_lock_for_chunk(&contributor2, chunk_id)?;
+    let challenge = coordinator.get_contribution_file(&locked_locators.current_contribution(), false)?;
+    assert_eq!(
+        Some(hex::encode(&challenge[0..64]).as_str()),
+        locked_locators.previous_contribution_hash()
+    );
+
+    Ok(())
+}
+
+/// Test that contributors who join together start on distinct chunks,
+/// rather than all waiting on the lowest chunk.
+#[test]
+#[serial]
+fn try_lock_spreads_contributors_test() -> anyhow::Result<()> {
+    let parameters = Parameters::Custom(Settings::new(
+        ContributionMode::Chunked,
+        ProvingSystem::Groth16,
+        CurveKind::Bls12_377,
+        6,  /* power */
+        16, /* batch_size */
+        16, /* chunk_size */
+    ));
+    let environment = initialize_test_environment(&Testing::from(parameters).into());
+
+    // Instantiate a coordinator, and initialize the ceremony to round 0.
+    let mut coordinator = Coordinator::new(environment, Arc::new(Dummy))?;
+    coordinator.initialize()?;
+
+    let contributors: Vec<Participant> = ["1", "2", "3"].iter().map(|id| create_contributor(id).0).collect();
+    for (index, contributor) in contributors.iter().enumerate() {
+        let contributor_ip = IpAddr::V4(Ipv4Addr::new(0, 0, 0, index as u8 + 1));
+        coordinator.add_to_queue(contributor.clone(), Some(contributor_ip), 10)?;
+    }
+
+    // Update the ceremony to round 1.
+    coordinator.update()?;
+    assert_eq!(1, coordinator.current_round_height()?);
+
+    // Check that every contributor can lock a chunk right away, each a different one.
+    let mut chunk_ids = HashSet::new();
+    for contributor in &contributors {
+        let (chunk_id, _) = coordinator.try_lock(contributor)?;
+        assert!(chunk_ids.insert(chunk_id));
+    }
+    assert_eq!(contributors.len(), chunk_ids.len());
+
+    Ok(())
+}
+
+/// Test that a read-only coordinator serves the state of the primary coordinator
+/// from the shared storage, and refuses every write.
+#[test]
+#[serial]
+fn read_only_coordinator_test() -> anyhow::Result<()> {
+    let parameters = Parameters::Custom(Settings::new(
+        ContributionMode::Chunked,
+        ProvingSystem::Groth16,
+        CurveKind::Bls12_377,
+        6,  /* power */
+        16, /* batch_size */
+        16, /* chunk_size */
+    ));
+    let environment = initialize_test_environment(&Testing::from(parameters.clone()).into());
+
+    // Instantiate the primary coordinator, and run it to round 1.
+    let mut coordinator = Coordinator::new(environment, Arc::new(Dummy))?;
+    coordinator.initialize()?;
+
+    let (contributor, contributor_signing_key, seed) = create_contributor("1");
+    let contributor_ip = IpAddr::V4(Ipv4Addr::UNSPECIFIED);
+    coordinator.add_to_queue(contributor.clone(), Some(contributor_ip), 10)?;
+    coordinator.update()?;
+
+    // Instantiate a read-only coordinator on the same storage.
+    let read_only_environment: Environment = Testing::from(parameters).read_only(true).into();
+    let mut replica = Coordinator::new(read_only_environment, Arc::new(Dummy))?;
+    replica.initialize()?;
+    assert_eq!(coordinator.current_round_summary()?, replica.current_round_summary()?);
+
+    // Check that the read-only coordinator refuses writes.
+    let (contributor2, _, _) = create_contributor("2");
+    assert!(matches!(
+        replica.add_to_queue(contributor2, Some(contributor_ip), 10),
+        Err(CoordinatorError::ReadOnlyMode)
+    ));
+    assert!(matches!(
+        replica.try_lock(&contributor),
+        Err(CoordinatorError::ReadOnlyMode)
+    ));
+    assert!(matches!(
+        replica.heartbeat(&contributor),
+        Err(CoordinatorError::ReadOnlyMode)
+    ));
+
+    // Check that the read-only coordinator refuses writes before changing its state.
+    assert!(matches!(replica.pause(), Err(CoordinatorError::ReadOnlyMode)));
+    assert!(!replica.is_paused());
+    assert!(matches!(
+        replica.drop_participant(&contributor),
+        Err(CoordinatorError::ReadOnlyMode)
+    ));
+    assert!(replica.is_current_contributor(&contributor));
+    assert!(matches!(replica.reset_round(), Err(CoordinatorError::ReadOnlyMode)));
+    assert_eq!(coordinator.current_round_summary()?, replica.current_round_summary()?);
+
+    // Check that the read-only coordinator follows the primary coordinator on update.
+    coordinator.contribute(&contributor, &contributor_signing_key, &seed)?;
+    assert_ne!(coordinator.current_round_summary()?, replica.current_round_summary()?);
+    replica.update()?;
+    assert_eq!(coordinator.current_round_summary()?, replica.current_round_summary()?);
+    assert_eq!(1, replica.current_round_summary()?.number_of_pending_verifications);
+
+    // Check that the read-only coordinator follows a replacement of the verifiers.
+    let new_verifier = Participant::new_verifier("rotated-verifier");
+    coordinator.set_coordinator_verifiers(vec![new_verifier.clone()])?;
+    assert!(!replica.is_coordinator_verifier(&new_verifier));
+    replica.update()?;
+    assert!(replica.is_coordinator_verifier(&new_verifier));
+    assert_eq!(&vec![new_verifier], replica.environment().coordinator_verifiers());
+
+    Ok(())
+}
+
+/// Test that the coordinator loop stops once the shutdown signal is sent,
+/// finishing the verification in progress without starting another one.
+#[tokio::test]
+#[serial]
+async fn run_until_shutdown_test() -> anyhow::Result<()> {
+    let parameters = Parameters::Custom(Settings::new(
+        ContributionMode::Chunked,
+        ProvingSystem::Groth16,
+        CurveKind::Bls12_377,
+        6,  /* power */
+        16, /* batch_size */
+        16, /* chunk_size */
+    ));
+    let environment = initialize_test_environment(&Testing::from(parameters).into());
+    let number_of_chunks = environment.number_of_chunks() as usize;
+
+    // Instantiate a coordinator.
+    let mut coordinator = Coordinator::new(environment, Arc::new(Dummy))?;
+
+    // Initialize the ceremony to round 0.
+    coordinator.initialize()?;
+    let (contributor, contributor_signing_key, seed) = create_contributor("1");
+    let (verifier, verifier_signing_key) = create_verifier("1");
+    coordinator.add_to_queue(contributor.clone(), Some(IpAddr::V4("0.0.0.1".parse().unwrap())), 10)?;
+
+    // Update the ceremony to round 1, and contribute to every chunk.
+    coordinator.update()?;
+    for _ in 0..number_of_chunks {
+        coordinator.contribute(&contributor, &contributor_signing_key, &seed)?;
+    }
+    assert_eq!(number_of_chunks, coordinator.get_pending_verifications().len());
+    let coordinator = Arc::new(RwLock::new(coordinator));
+
+    // Run a loop which verifies a contribution in each iteration, and shut it down during the first one.
+    let (shutdown_sender, shutdown_receiver) = watch::channel(false);
+    let ceremony = tokio::spawn(run_until_shutdown(
+        coordinator.clone(),
+        Duration::from_secs(60),
+        shutdown_receiver,
+        move |coordinator| {
+            shutdown_sender.send(true)?;
+            verify_task_if_available(coordinator, &verifier, &verifier_signing_key)
+        },
+    ));
+
+    // Check that the loop stops without waiting for the next iteration.
+    tokio::time::timeout(Duration::from_secs(30), ceremony).await??;
+
+    // Check that the verification in progress finished, and that no other one was started.
+    let mut coordinator = coordinator.write().await;
+    assert_eq!(number_of_chunks - 1, coordinator.get_pending_verifications().len());
+    let current_round = coordinator.current_round()?;
+    for task in coordinator.get_pending_verifications().keys() {
+        let chunk = current_round.chunk(task.chunk_id())?;
+        assert!(!chunk.get_contribution(task.contribution_id())?.is_verified());
+        assert_eq!(&None, chunk.lock_holder());
+    }
+
+    // Check that the state can be saved once the loop has stopped.
+    coordinator.shutdown()?;
+
+    Ok(())
+}
+
+/// Test that the coordinator verifiers can be replaced without a restart,
+/// and that the replacement is kept across restarts.
+#[test]
+#[serial]
+fn replace_coordinator_verifiers_test() -> anyhow::Result<()> {
+    let parameters = Parameters::Custom(Settings::new(
+        ContributionMode::Chunked,
+        ProvingSystem::Groth16,
+        CurveKind::Bls12_377,
+        6,  /* power */
+        16, /* batch_size */
+        16, /* chunk_size */
+    ));
+    let environment = initialize_test_environment(&Testing::from(parameters).into());
+
+    // Instantiate a coordinator.
+    let mut coordinator = Coordinator::new(environment.clone(), Arc::new(Dummy))?;
+
+    // Initialize the ceremony to round 0.
+    coordinator.initialize()?;
+
+    let old_verifier = environment.coordinator_verifiers()[0].clone();
+    let new_verifier = Participant::new_verifier("rotated-verifier");
+    assert!(coordinator.is_coordinator_verifier(&old_verifier));
+
+    // Check that only verifiers can be set as coordinator verifiers.
+    let (contributor1, _, _) = create_contributor("1");
+    assert!(matches!(
+        coordinator.set_coordinator_verifiers(vec![contributor1]),
+        Err(CoordinatorError::ExpectedVerifier)
+    ));
+    assert!(matches!(
+        coordinator.set_coordinator_verifiers(vec![]),
+        Err(CoordinatorError::VerifierMissing)
+    ));
+
+    // Replace the verifiers, and check that the old verifier is rejected.
+    coordinator.set_coordinator_verifiers(vec![new_verifier.clone()])?;
+    assert!(coordinator.is_coordinator_verifier(&new_verifier));
+    assert!(!coordinator.is_coordinator_verifier(&old_verifier));
+    assert!(matches!(
+        coordinator.claim_verification(&old_verifier),
+        Err(CoordinatorError::ParticipantUnauthorized)
+    ));
+    assert!(
+        coordinator
+            .participant_from_address(&old_verifier.address())
+            .is_contributor()
+    );
+
+    // Check that the replacement is kept after a restart.
+    let coordinator = Coordinator::new(environment, Arc::new(Dummy))?;
+    assert!(coordinator.is_coordinator_verifier(&new_verifier));
+    assert!(!coordinator.is_coordinator_verifier(&old_verifier));
+    assert!(
+        coordinator
+            .participant_from_address(&new_verifier.address())
+            .is_verifier()
+    );
+
+    Ok(())
+}
+
+/// Test that the verified challenges of the current round are served
+/// from the cache, and only when caching is enabled.
+#[test]
+#[serial]
+fn get_challenge_cache_test() -> anyhow::Result<()> {
+    let parameters = Parameters::Custom(Settings::new(
+        ContributionMode::Chunked,
+        ProvingSystem::Groth16,
+        CurveKind::Bls12_377,
+        6,  /* power */
+        16, /* batch_size */
+        16, /* chunk_size */
+    ));
+
+    for cache_challenges in [true, false] {
+        let environment = initialize_test_environment(
+            &Testing::from(parameters.clone())
+                .cache_challenges(cache_challenges)
+                .into(),
+        );
+
+        // Instantiate a coordinator.
+        let mut coordinator = Coordinator::new(environment, Arc::new(Dummy))?;
+
+        // Initialize the ceremony to round 0.
+        coordinator.initialize()?;
+
+        let (contributor1, _, _) = create_contributor("1");
+        let contributor_1_ip = IpAddr::V4("0.0.0.1".parse().unwrap());
+        coordinator.add_to_queue(contributor1, Some(contributor_1_ip), 10)?;
+
+        // Update the ceremony to round 1.
+        coordinator.update()?;
+        assert_eq!(1, coordinator.current_round_height()?);
+
+        // Check that the challenge matches the verified contribution file in storage.
+        let challenge = coordinator.get_challenge(1, 0)?;
+        let locator = ContributionLocator::new(1, 0, 0, true);
+        assert_eq!(coordinator.get_contribution_file(&locator, false)?, *challenge);
+
+        // Check that the repeat request is served from the cache, if enabled.
+        let repeat = coordinator.get_challenge(1, 0)?;
+        assert_eq!(challenge, repeat);
+        assert_eq!(cache_challenges, Arc::ptr_eq(&challenge, &repeat));
+    }
+
+    Ok(())
+}
+
 /// Test that a participant can remain in the queue by sending heartbeats.
 #[test]
 #[serial]
@@ -86,6 +86,17 @@ impl<E: PairingEngine> MPCParameters<E> {
     /// The resulting parameters are unsafe to use until there are contributions (see `contribute()`).
     #[cfg(not(feature = "wasm"))]
     pub fn new(assembly: KeypairAssembly<E>, params: Groth16Params<E>) -> Result<MPCParameters<E>> {
+        Self::new_with_progress(assembly, params, &|_| {})
+    }
+
+    /// Create new Groth16 parameters like `new()`, calling `progress` with the number of
+    /// QAP polynomials evaluated since its last call. The polynomials add up to `4 * assembly.at.len()`.
+    #[cfg(not(feature = "wasm"))]
+    pub fn new_with_progress(
+        assembly: KeypairAssembly<E>,
+        params: Groth16Params<E>,
+        progress: &(dyn Fn(u64) + Sync),
+    ) -> Result<MPCParameters<E>> {
         // Evaluate the QAP against the coefficients created from phase 1
         let (a_g1, b_g1, b_g2, gamma_abc_g1, l) = eval::<E>(
             // Lagrange coeffs for Tau, read in from Phase 1
@@ -99,6 +110,7 @@ impl<E: PairingEngine> MPCParameters<E> {
             &assembly.ct,
             // Helper
             assembly.num_public_variables,
+            progress,
         );
 
         // Reject unconstrained elements, so that
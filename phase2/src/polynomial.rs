@@ -7,6 +7,9 @@ use rayon::prelude::*;
 /// Evaluates and returns the provided QAP Polynomial vectors at the provided coefficients.
 /// Format: [a_g1, b_g1, b_g2, gamma_abc_g1, l_g1]
 /// The returned points are _affine_
+///
+/// `progress` is called with the number of polynomials evaluated since its last call,
+/// which adds up to `4 * at.len()` once all of them have been evaluated.
 #[allow(clippy::too_many_arguments)]
 #[allow(clippy::type_complexity)]
 pub fn eval<E: PairingEngine>(
@@ -21,6 +24,8 @@ pub fn eval<E: PairingEngine>(
     ct: &[Vec<(E::Fr, Index)>],
     // The number of inputs
     num_inputs: usize,
+    // Reports the number of evaluated polynomials
+    progress: &(dyn Fn(u64) + Sync),
 ) -> (
     Vec<E::G1Affine>,
     Vec<E::G1Affine>,
@@ -29,10 +34,16 @@ pub fn eval<E: PairingEngine>(
     Vec<E::G1Affine>,
 ) {
     // calculate the evaluated polynomials
-    let a_g1 = dot_product_vec(at, coeffs_g1, num_inputs);
-    let b_g1 = dot_product_vec(bt, coeffs_g1, num_inputs);
-    let b_g2 = dot_product_vec(bt, coeffs_g2, num_inputs);
-    let ext = dot_product_ext::<E>((at, beta_coeffs_g1), (bt, alpha_coeffs_g1), (ct, coeffs_g1), num_inputs);
+    let a_g1 = dot_product_vec(at, coeffs_g1, num_inputs, progress);
+    let b_g1 = dot_product_vec(bt, coeffs_g1, num_inputs, progress);
+    let b_g2 = dot_product_vec(bt, coeffs_g2, num_inputs, progress);
+    let ext = dot_product_ext::<E>(
+        (at, beta_coeffs_g1),
+        (bt, alpha_coeffs_g1),
+        (ct, coeffs_g1),
+        num_inputs,
+        progress,
+    );
 
     // break to `gamma_abc_g1` and `l` coeffs
     let (gamma_abc_g1, l) = ext.split_at(num_inputs);
@@ -54,14 +65,17 @@ fn dot_product_ext<E: PairingEngine>(
     (bt, alpha_coeffs_g1): (&[Vec<(E::Fr, Index)>], &[E::G1Affine]),
     (ct, coeffs_g1): (&[Vec<(E::Fr, Index)>], &[E::G1Affine]),
     num_inputs: usize,
+    progress: &(dyn Fn(u64) + Sync),
 ) -> Vec<E::G1Projective> {
     let mut ret = at
         .par_iter()
         .zip(bt.par_iter().zip(ct))
         .map(|(at, (bt, ct))| {
-            dot_product(&at, &beta_coeffs_g1, num_inputs)
+            let sum = dot_product(&at, &beta_coeffs_g1, num_inputs)
                 + &dot_product(&bt, &alpha_coeffs_g1, num_inputs)
-                + &dot_product(&ct, &coeffs_g1, num_inputs)
+                + &dot_product(&ct, &coeffs_g1, num_inputs);
+            progress(1);
+            sum
         })
         .collect::<Vec<_>>();
     E::G1Projective::batch_normalization(&mut ret);
@@ -75,10 +89,15 @@ fn dot_product_vec<C: AffineCurve>(
     input: &[Vec<(C::ScalarField, Index)>],
     coeffs: &[C],
     num_inputs: usize,
+    progress: &(dyn Fn(u64) + Sync),
 ) -> Vec<C::Projective> {
     let mut ret = input
         .par_iter()
-        .map(|row| dot_product(row, coeffs, num_inputs))
+        .map(|row| {
+            let sum = dot_product(row, coeffs, num_inputs);
+            progress(1);
+            sum
+        })
         .collect::<Vec<_>>();
     // Batch normalize
     C::Projective::batch_normalization(&mut ret);
@@ -114,7 +133,10 @@ mod tests {
     use snarkvm_utilities::UniformRand;
 
     use rand::{thread_rng, Rng};
-    use std::ops::Mul;
+    use std::{
+        ops::Mul,
+        sync::atomic::{AtomicU64, Ordering},
+    };
 
     fn gen_input(rng: &mut impl Rng) -> Vec<(Fr, Index)> {
         let scalar = (0..6).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
@@ -157,10 +179,14 @@ mod tests {
         assert_eq!(got, expected);
 
         // it also applies the coefficients vector to each row
-        // in the inputs vector
+        // in the inputs vector, and reports each of them
         let input_vec = vec![input; 10];
-        let got = dot_product_vec(&input_vec, &elements, num_inputs);
-        assert_eq!(got, vec![expected; 10])
+        let evaluated = AtomicU64::new(0);
+        let got = dot_product_vec(&input_vec, &elements, num_inputs, &|n| {
+            evaluated.fetch_add(n, Ordering::Relaxed);
+        });
+        assert_eq!(got, vec![expected; 10]);
+        assert_eq!(10, evaluated.into_inner());
     }
 
     #[test]
@@ -181,6 +207,7 @@ mod tests {
             (&bt, &alpha_coeffs_g1),
             (&ct, &coeffs_g1),
             num_inputs,
+            &|_| {},
         );
 
         // it should be the sum of the dot products
@@ -164,11 +164,31 @@ impl<E: PairingEngine> Groth16Params<E> {
     /// Reads the first `num_constraints` coefficients from the provided processed
     /// Phase 1 transcript with size `phase1_size`.
     pub fn read(
-        reader: &mut [u8],
+        reader: &[u8],
         compressed: UseCompression,
         check_input_for_correctness: CheckForCorrectness,
         phase1_size: usize,
         num_constraints: usize,
+    ) -> Result<Groth16Params<E>> {
+        Self::read_with_progress(
+            reader,
+            compressed,
+            check_input_for_correctness,
+            phase1_size,
+            num_constraints,
+            &|_| {},
+        )
+    }
+
+    /// Reads the coefficients like `read()`, calling `progress` with the number of
+    /// coefficients read since its last call. The coefficients add up to `5 * num_constraints - 1`.
+    pub fn read_with_progress(
+        reader: &[u8],
+        compressed: UseCompression,
+        check_input_for_correctness: CheckForCorrectness,
+        phase1_size: usize,
+        num_constraints: usize,
+        progress: &(dyn Fn(u64) + Sync),
     ) -> Result<Groth16Params<E>> {
         let span = info_span!("Groth16Utils_read");
         let _enter = span.enter();
@@ -179,7 +199,7 @@ impl<E: PairingEngine> Groth16Params<E> {
         let beta_g2 = reader.read_element(compressed, check_input_for_correctness)?;
 
         let position = reader.position() as usize;
-        let reader = &mut &reader.get_mut()[position..];
+        let reader = &reader.get_ref()[position..];
 
         // Split the transcript in the appropriate sections
         let (in_coeffs_g1, in_coeffs_g2, in_alpha_coeffs_g1, in_beta_coeffs_g1, in_h_g1) =
@@ -190,15 +210,14 @@ impl<E: PairingEngine> Groth16Params<E> {
         // note: '??' is used for getting the result from the threaded operation,
         // and then getting the result from the function inside the thread)
         Ok(crossbeam::scope(|s| -> Result<_> {
-            let coeffs_g1 =
-                s.spawn(|_| in_coeffs_g1.read_batch::<E::G1Affine>(compressed, check_input_for_correctness));
-            let coeffs_g2 =
-                s.spawn(|_| in_coeffs_g2.read_batch::<E::G2Affine>(compressed, check_input_for_correctness));
+            let check = check_input_for_correctness;
+            let coeffs_g1 = s.spawn(|_| read_coefficients::<E::G1Affine>(in_coeffs_g1, compressed, check, progress));
+            let coeffs_g2 = s.spawn(|_| read_coefficients::<E::G2Affine>(in_coeffs_g2, compressed, check, progress));
             let alpha_coeffs_g1 =
-                s.spawn(|_| in_alpha_coeffs_g1.read_batch::<E::G1Affine>(compressed, check_input_for_correctness));
+                s.spawn(|_| read_coefficients::<E::G1Affine>(in_alpha_coeffs_g1, compressed, check, progress));
             let beta_coeffs_g1 =
-                s.spawn(|_| in_beta_coeffs_g1.read_batch::<E::G1Affine>(compressed, check_input_for_correctness));
-            let h_g1 = s.spawn(|_| in_h_g1.read_batch::<E::G1Affine>(compressed, check_input_for_correctness));
+                s.spawn(|_| read_coefficients::<E::G1Affine>(in_beta_coeffs_g1, compressed, check, progress));
+            let h_g1 = s.spawn(|_| read_coefficients::<E::G1Affine>(in_h_g1, compressed, check, progress));
 
             let coeffs_g1 = coeffs_g1.join()??;
             debug!("read tau g1 Coefficients");
@@ -227,6 +246,26 @@ impl<E: PairingEngine> Groth16Params<E> {
     }
 }
 
+/// The number of coefficients read between two calls to the progress callback of `read_with_progress`.
+const PROGRESS_CHUNK_SIZE: usize = 1 << 12;
+
+/// Reads a section of the transcript in chunks of `PROGRESS_CHUNK_SIZE` coefficients,
+/// calling `progress` after each chunk.
+fn read_coefficients<G: AffineCurve>(
+    input: &[u8],
+    compressed: UseCompression,
+    check_input_for_correctness: CheckForCorrectness,
+    progress: &(dyn Fn(u64) + Sync),
+) -> Result<Vec<G>> {
+    let mut coefficients = Vec::with_capacity(input.len() / buffer_size::<G>(compressed));
+    for chunk in input.chunks(PROGRESS_CHUNK_SIZE * buffer_size::<G>(compressed)) {
+        let chunk = chunk.read_batch::<G>(compressed, check_input_for_correctness)?;
+        progress(chunk.len() as u64);
+        coefficients.extend(chunk);
+    }
+    Ok(coefficients)
+}
+
 /// Immutable slices with format [AlphaG1, BetaG1, BetaG2, CoeffsG1, CoeffsG2, AlphaCoeffsG1, BetaCoeffsG1, H_G1]
 type SplitBuf<'a> = (&'a [u8], &'a [u8], &'a [u8], &'a [u8], &'a [u8]);
 
@@ -0,0 +1,106 @@
+use snarkvm_dpc::{parameters::testnet2::Testnet2Parameters, Address, PrivateKey};
+
+use age::{armor::ArmoredReader, DecryptError, Decryptor};
+use anyhow::{anyhow, Context, Result};
+use secrecy::{ExposeSecret, SecretString, SecretVec};
+use serde::Deserialize;
+use std::{fs, io::Read, str::FromStr};
+use zeroize::Zeroizing;
+
+// Should be the same as the one from setup1-contributor/src/objects.rs
+// Copied here to reduce the compile time, which is
+// about 50% longer with setup1-contributor included
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AleoSetupKeys {
+    pub encrypted_seed: String,
+    pub encrypted_private_key: String,
+    #[serde(default)]
+    pub work_factor: Option<u8>,
+}
+
+// Should be the same as the one from setup1-contributor/src/setup_keys/mod.rs
+const ARMOR_BEGIN_MARKER: &str = "-----BEGIN AGE ENCRYPTED FILE-----";
+
+/// Returns the bytes of an encrypted key, which may either be
+/// an ASCII-armored age file or the hex encoding of a binary one.
+fn decode_encrypted(encrypted: &str) -> Result<Vec<u8>> {
+    match encrypted.starts_with(ARMOR_BEGIN_MARKER) {
+        true => Ok(encrypted.as_bytes().to_vec()),
+        false => hex::decode(encrypted).map_err(|e| anyhow!("Corrupt ciphertext: {}", e)),
+    }
+}
+
+fn decrypt(passphrase: &SecretString, encrypted: &str, max_work_factor: Option<u8>) -> Result<SecretVec<u8>> {
+    let decoded = SecretVec::new(decode_encrypted(encrypted)?);
+    let decryptor = Decryptor::new(ArmoredReader::new(decoded.expose_secret().as_slice()))
+        .map_err(|e| anyhow!("Corrupt ciphertext: {}", e))?;
+    match decryptor {
+        Decryptor::Passphrase(decryptor) => {
+            // The plaintext is zeroized on drop, including if decryption fails part way.
+            let mut output = Zeroizing::new(vec![]);
+            let mut reader = decryptor.decrypt(passphrase, max_work_factor).map_err(|e| match e {
+                DecryptError::DecryptionFailed | DecryptError::KeyDecryptionFailed => anyhow!("Wrong passphrase"),
+                _ => anyhow!("Corrupt ciphertext: {}", e),
+            })?;
+            reader
+                .read_to_end(&mut output)
+                .map_err(|e| anyhow!("Corrupt ciphertext: {}", e))?;
+            Ok(SecretVec::new(std::mem::take(&mut *output)))
+        }
+        Decryptor::Recipients(_) => Err(anyhow!("Wrong age Decryptor, should be Passphrase, but got Recipients")),
+    }
+}
+
+/// Reads and parses the keys file at `keys_path` without decrypting anything.
+fn read_keys(keys_path: &str) -> Result<AleoSetupKeys> {
+    let file_contents = fs::read(&keys_path).with_context(|| format!("Unable to read {}", keys_path))?;
+    serde_json::from_slice(&file_contents).with_context(|| format!("{} is not a valid keys file", keys_path))
+}
+
+fn read_passphrase() -> Result<SecretString> {
+    age::cli_common::read_secret("Enter your Aleo setup passphrase", "Passphrase", None)
+        .map_err(|e| anyhow!("Error reading passphrase: {}", e))
+}
+
+/// The prefix of the string encoding of a private key.
+const PRIVATE_KEY_PREFIX: &str = "APrivateKey1";
+
+fn decrypt_private_key(keys: &AleoSetupKeys, passphrase: &SecretString) -> Result<PrivateKey<Testnet2Parameters>> {
+    let decrypted = decrypt(passphrase, &keys.encrypted_private_key, keys.work_factor)?;
+    let private_key = std::str::from_utf8(decrypted.expose_secret()).context("Decrypted private key is not UTF-8")?;
+    if !private_key.starts_with(PRIVATE_KEY_PREFIX) {
+        return Err(anyhow!(
+            "Decrypted private key is malformed: it should start with {}",
+            PRIVATE_KEY_PREFIX
+        ));
+    }
+    PrivateKey::from_str(private_key).map_err(|e| anyhow!("Decrypted private key is malformed: {}", e))
+}
+
+/// Reads the keys file, asks for the passphrase and decrypts the private key.
+/// Errors tell apart an unreadable or malformed file, a wrong passphrase and
+/// a corrupt ciphertext.
+pub fn read_private_key(keys_path: &str) -> Result<PrivateKey<Testnet2Parameters>> {
+    let keys = read_keys(keys_path)?;
+    let passphrase = read_passphrase()?;
+    decrypt_private_key(&keys, &passphrase)
+}
+
+/// Reads the keys file, asks for the passphrase and returns the address derived
+/// from the private key. The private key is dropped as soon as the address has
+/// been derived.
+pub fn read_address(keys_path: &str) -> Result<String> {
+    let private_key = read_private_key(keys_path)?;
+    let address = Address::from_private_key(&private_key)
+        .map_err(|e| anyhow!("Unable to derive an address from the private key: {}", e))?;
+    Ok(address.to_string())
+}
+
+pub fn select_default_language() {
+    let default_language: unic_langid::LanguageIdentifier =
+        "en-US".parse().expect("Should parse a language indentifier");
+    age::localizer()
+        .select(&[default_language])
+        .expect("Should select the default language");
+}
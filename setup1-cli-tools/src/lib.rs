@@ -0,0 +1,41 @@
+pub mod keys;
+
+use phase1_coordinator::ContributionFileSignature;
+use setup_utils::calculate_hash;
+use snarkvm_dpc::{parameters::testnet2::Testnet2Parameters, Address};
+use snarkvm_utilities::FromBytes;
+
+use anyhow::{anyhow, Result};
+use std::str::FromStr;
+
+/// Checks that the contribution file signature is signed by the given address,
+/// and that it covers the given contribution file. Returns the hash of the
+/// contribution file, and the reason the check failed, if it did.
+pub fn verify_contribution(
+    contribution: &[u8],
+    signature: &ContributionFileSignature,
+    address: &str,
+) -> Result<(String, Option<String>)> {
+    let address =
+        Address::<Testnet2Parameters>::from_str(address).map_err(|e| anyhow!("Unable to parse the address: {}", e))?;
+
+    let hash = hex::encode(calculate_hash(contribution));
+
+    // Check that the signature is valid for the signed contribution state.
+    let message = signature.get_state().signature_message()?;
+    let aleo_signature = FromBytes::from_bytes_le(&hex::decode(signature.get_signature())?)
+        .map_err(|e| anyhow!("Unable to parse the signature: {}", e))?;
+    if !address.verify_signature(message.as_bytes(), &aleo_signature)? {
+        return Ok((hash, Some(format!("the signature is not valid for {}", address))));
+    }
+
+    // Check that the signed state covers the contribution file, either as
+    // the response or as the next challenge of a verification.
+    let next_challenge_hash = signature.get_next_challenge_hash().as_deref();
+    if hash != signature.get_response_hash() && Some(hash.as_str()) != next_challenge_hash {
+        let reason = "the signed hashes do not match the contribution file".to_string();
+        return Ok((hash, Some(reason)));
+    }
+
+    Ok((hash, None))
+}
@@ -0,0 +1,24 @@
+use setup1_cli_tools::keys;
+
+use structopt::StructOpt;
+
+#[derive(Debug, StructOpt)]
+#[structopt(name = "Validate keys")]
+struct Options {
+    #[structopt(long)]
+    path: String,
+}
+
+fn main() {
+    let options = Options::from_args();
+
+    keys::select_default_language();
+
+    match keys::read_address(&options.path) {
+        Ok(address) => println!("valid {}", address),
+        Err(error) => {
+            eprintln!("invalid: {:#}", error);
+            std::process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use phase1_coordinator::ContributionFileSignature;
+use setup1_cli_tools::verify_contribution;
+
+use anyhow::{Context, Result};
+use structopt::StructOpt;
+
+#[derive(Debug, StructOpt)]
+#[structopt(name = "Verify contribution")]
+struct Options {
+    /// The path of the contribution file.
+    #[structopt(long)]
+    contribution: String,
+    /// The path of the contribution file signature.
+    #[structopt(long)]
+    signature: String,
+    /// The Aleo address of the participant that signed the contribution.
+    #[structopt(long)]
+    address: String,
+}
+
+/// Reads the contribution file and its signature, and checks them with [verify_contribution].
+fn verify(contribution_path: &str, signature_path: &str, address: &str) -> Result<(String, Option<String>)> {
+    let contribution = std::fs::read(contribution_path).context("Unable to read the contribution file")?;
+    let signature: ContributionFileSignature = serde_json::from_slice(
+        &std::fs::read(signature_path).context("Unable to read the contribution file signature")?,
+    )
+    .context("Unable to parse the contribution file signature")?;
+
+    verify_contribution(&contribution, &signature, address)
+}
+
+fn main() {
+    let options = Options::from_args();
+
+    match verify(&options.contribution, &options.signature, &options.address) {
+        Ok((hash, None)) => println!("hash {}\nvalid", hash),
+        Ok((hash, Some(reason))) => {
+            println!("hash {}", hash);
+            eprintln!("invalid: {}", reason);
+            std::process::exit(1);
+        }
+        Err(error) => {
+            eprintln!("Error: {:#}", error);
+            std::process::exit(1);
+        }
+    }
+}
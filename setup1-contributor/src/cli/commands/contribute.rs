@@ -20,10 +20,20 @@ pub struct ContributeOptions {
     pub passphrase: Option<SecretString>,
 
     /// Specify the URL of the ceremony coordinator.
-    #[structopt(long, help = "Coordinator api url")]
-    pub api_url: Url,
+    #[structopt(long, help = "Coordinator api url", required_unless = "dry-run")]
+    pub api_url: Option<Url>,
 
     /// Read seed and private key at the given path.
     #[structopt(long, help = "Path to a file containing seed and private key")]
     pub keys_path: PathBuf,
+
+    /// Mix the contents of the given file into the randomness of the
+    /// contribution, in addition to the stored seed and OS randomness.
+    #[structopt(long, help = "Path to a file of additional entropy")]
+    pub entropy_file: Option<PathBuf>,
+
+    /// Rehearse the contribution against an in-process coordinator
+    /// with tiny parameters, without contacting the ceremony.
+    #[structopt(long, help = "Contribute to a local mock coordinator instead of the ceremony")]
+    pub dry_run: bool,
 }
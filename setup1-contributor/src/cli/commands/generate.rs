@@ -21,4 +21,8 @@ pub struct GenerateOptions {
     /// For example: --keys-path keys.json
     #[structopt(long)]
     pub keys_path: PathBuf,
+    /// Store the encrypted seed and private key as ASCII-armored
+    /// age files instead of hex.
+    #[structopt(long)]
+    pub armor: bool,
 }
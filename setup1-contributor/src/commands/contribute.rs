@@ -1,8 +1,10 @@
 use crate::{
     cli::commands::contribute::ContributeOptions,
+    entropy::{entropy_pool, with_entropy_file},
     errors::ContributeError,
     setup_keys::{
         confirmation_key::{print_key_and_remove_the_file, ConfirmationKey},
+        decode_encrypted,
         AleoSetupKeys,
     },
     utils::{
@@ -14,18 +16,18 @@ use crate::{
     },
 };
 
-use phase1::helpers::converters::CurveKind;
+use phase1::{helpers::converters::CurveKind, Phase1, PublicKey};
 use phase1_cli::contribute;
 use phase1_coordinator::{
     environment::Environment,
     objects::{Chunk, Round},
 };
 use setup1_shared::structures::{ContributorStatus, LockResponse, PublicSettings, TwitterInfo};
-use setup_utils::calculate_hash;
+use setup_utils::{calculate_hash, CheckForCorrectness, UseCompression};
 use snarkvm_curves::{bls12_377::Bls12_377, bw6_761::BW6_761, PairingEngine};
 use snarkvm_dpc::{parameters::testnet2::Testnet2Parameters, Address, PrivateKey, ViewKey};
 
-use age::DecryptError;
+use age::{armor::ArmoredReader, DecryptError};
 use anyhow::{Context, Result};
 use dialoguer::{theme::ColorfulTheme, Confirm, Input};
 use fs_err::File;
@@ -54,6 +56,8 @@ const RESPONSE_FILENAME: &str = "response";
 const DELAY_AFTER_ERROR: Duration = Duration::from_secs(60);
 const DELAY_POLL_CEREMONY: Duration = Duration::from_secs(5);
 const HEARTBEAT_POLL_DELAY: Duration = Duration::from_secs(30);
+/// The response header in which the coordinator hints at the heartbeat interval, in seconds.
+const HEARTBEAT_INTERVAL_HEADER: &str = "X-Heartbeat-Interval";
 
 // Version constants
 const MAJOR: u8 = 0;
@@ -73,7 +77,7 @@ pub struct Contribute {
 
 impl Contribute {
     pub fn new(
-        opts: &ContributeOptions,
+        server_url: &Url,
         environment: &Environment,
         private_key: PrivateKey<Testnet2Parameters>,
         seed: SecretVec<u8>,
@@ -81,7 +85,7 @@ impl Contribute {
         // TODO (raychu86): Pass in pipelining options from the CLI.
 
         Self {
-            server_url: opts.api_url.clone(),
+            server_url: server_url.clone(),
             participant_id: Address::try_from(&private_key).expect("Should have derived an Aleo address"),
             private_key,
             seed: Arc::new(seed),
@@ -89,7 +93,7 @@ impl Contribute {
         }
     }
 
-    async fn run_and_catch_errors<E: PairingEngine>(&mut self) -> Result<()> {
+    async fn run_and_catch_errors<E: PairingEngine + Sync>(&mut self) -> Result<()> {
         println!("Attempting to join the queue...");
 
         loop {
@@ -172,7 +176,7 @@ impl Contribute {
         Ok(())
     }
 
-    async fn run<E: PairingEngine>(&mut self, progress_bar: &ProgressBar) -> Result<()> {
+    async fn run<E: PairingEngine + Sync>(&mut self, progress_bar: &ProgressBar) -> Result<()> {
         loop {
             let status = get_contributor_status(&self.server_url, &self.private_key).await?;
             match status {
@@ -231,8 +235,19 @@ impl Contribute {
             self.download_challenge(chunk_id, lock_response.contribution_id, CHALLENGE_FILENAME, auth_rng)
                 .await?;
 
-            let exposed_seed = self.seed.expose_secret();
-            let seeded_rng = derive_rng_from_seed(&exposed_seed[..]);
+            // Check that the challenge is built on the contribution the coordinator expects, before spending time on it.
+            check_previous_contribution_hash(
+                lock_response.previous_contribution_hash.as_deref(),
+                &read_from_file(CHALLENGE_FILENAME)?,
+            )?;
+
+            // Mix the seed with fresh OS randomness, refusing to contribute if the randomness is weak.
+            let (pool, quality) = entropy_pool(self.seed.expose_secret(), &mut rand::rngs::OsRng)?;
+            info!(
+                "Estimated {:.2} bits of entropy per byte, with a bit bias of {:.1} standard deviations",
+                quality.entropy_per_byte, quality.monobit_deviation
+            );
+            let seeded_rng = derive_rng_from_seed(pool.expose_secret());
             let start = Instant::now();
             remove_file_if_exists(RESPONSE_FILENAME)?;
 
@@ -269,9 +284,11 @@ impl Contribute {
             let challenge_file = read_from_file(CHALLENGE_FILENAME)?;
             let response_file = read_from_file(RESPONSE_FILENAME)?;
 
-            // Hash the challenge and response files.
+            // Verify the response before uploading it, and hash the challenge and response files.
+            progress_bar.set_message(format!("Verifying the contribution to chunk {}...", chunk_id));
+            let response_hash =
+                verify_contribution_locally::<E>(&self.environment, chunk_id, &challenge_file, &response_file)?;
             let challenge_hash = calculate_hash(&challenge_file).to_vec();
-            let response_hash = calculate_hash(&response_file).to_vec();
 
             // Sign the contribution state.
             let view_key = ViewKey::try_from(&self.private_key)?;
@@ -670,13 +687,93 @@ fn get_non_contributed_chunks<'r>(ceremony: &'r Round, participant_id: &str) ->
         .collect()
 }
 
+/// Checks that the previous contribution hash the downloaded challenge begins with
+/// matches the hash the coordinator sent with the lock, if it sent one.
+fn check_previous_contribution_hash(expected: Option<&str>, challenge: &[u8]) -> Result<()> {
+    let found = hex::encode(&challenge[..challenge.len().min(64)]);
+    match expected {
+        Some(expected) if !expected.eq_ignore_ascii_case(&found) => {
+            Err(ContributeError::PreviousContributionHashMismatchError {
+                expected: expected.to_string(),
+                found,
+            }
+            .into())
+        }
+        _ => Ok(()),
+    }
+}
+
+///
+/// Verifies the given response against the challenge it was computed from,
+/// with the same checks the verifiers of the coordinator run, and returns
+/// the hash of the response.
+///
+/// This catches a faulty local computation before the response is uploaded.
+///
+pub(crate) fn verify_contribution_locally<E: PairingEngine + Sync>(
+    environment: &Environment,
+    chunk_id: u64,
+    challenge: &[u8],
+    response: &[u8],
+) -> Result<Vec<u8>> {
+    let parameters = create_parameters_for_chunk::<E>(environment, chunk_id as usize)?;
+    let compressed_input = environment.compressed_inputs();
+    let compressed_output = environment.compressed_outputs();
+
+    // Check that the response was computed from the challenge.
+    let challenge_hash = calculate_hash(challenge);
+    if response.get(0..64) != Some(challenge_hash.as_slice()) {
+        let reason = "the response is not based on the challenge".to_string();
+        return Err(ContributeError::LocalVerificationError(reason).into());
+    }
+
+    // Check the sizes of the files, which the verification below expects.
+    let expected_challenge_size = match compressed_input {
+        UseCompression::Yes => parameters.contribution_size - parameters.public_key_size,
+        UseCompression::No => parameters.accumulator_size,
+    };
+    let expected_response_size = match compressed_output {
+        UseCompression::Yes => parameters.contribution_size,
+        UseCompression::No => parameters.accumulator_size + parameters.public_key_size,
+    };
+    if challenge.len() != expected_challenge_size || response.len() != expected_response_size {
+        let reason = format!(
+            "the challenge and response have {} and {} bytes instead of {} and {}",
+            challenge.len(),
+            response.len(),
+            expected_challenge_size,
+            expected_response_size
+        );
+        return Err(ContributeError::LocalVerificationError(reason).into());
+    }
+
+    let public_key = PublicKey::read(response, compressed_output, &parameters)
+        .map_err(|e| ContributeError::LocalVerificationError(e.to_string()))?;
+    Phase1::verification(
+        challenge,
+        response,
+        &public_key,
+        challenge_hash.as_slice(),
+        compressed_input,
+        compressed_output,
+        CheckForCorrectness::No,
+        CheckForCorrectness::Full,
+        &parameters,
+    )
+    .map_err(|e| ContributeError::LocalVerificationError(e.to_string()))?;
+
+    Ok(calculate_hash(response).to_vec())
+}
+
 struct HeartbeatData {
     server_url: Url,
     private_key: PrivateKey<Testnet2Parameters>,
 }
 
 impl HeartbeatData {
-    async fn heartbeat<R: Rng + CryptoRng>(&self, auth_rng: &mut R) -> Result<()> {
+    /// Sends a heartbeat, and returns the interval until the next one
+    /// if the coordinator hinted at it.
+    async fn heartbeat<R: Rng + CryptoRng>(&self, auth_rng: &mut R) -> Result<Option<Duration>> {
         let heartbeat_path = "/v1/contributor/heartbeat";
         let url = self.server_url.join(heartbeat_path)?;
         let client = reqwest::Client::new();
@@ -689,9 +786,21 @@ impl HeartbeatData {
             .await?
             .error_for_status()?;
 
-        response.error_for_status()?;
+        Ok(heartbeat_interval(response.headers()))
+    }
+}
 
-        Ok(())
+/// Returns the heartbeat interval hinted at in the given response headers, if any.
+fn heartbeat_interval(headers: &http::HeaderMap) -> Option<Duration> {
+    let seconds = headers
+        .get(HEARTBEAT_INTERVAL_HEADER)?
+        .to_str()
+        .ok()?
+        .parse::<u64>()
+        .ok()?;
+    match seconds {
+        0 => None,
+        seconds => Some(Duration::from_secs(seconds)),
     }
 }
 
@@ -705,23 +814,25 @@ fn initiate_heartbeat(server_url: Url, private_key: PrivateKey<Testnet2Parameter
 
         let auth_rng = &mut rand::rngs::OsRng;
         let runtime = tokio::runtime::Runtime::new().unwrap();
+        let mut delay = HEARTBEAT_POLL_DELAY;
         loop {
             tracing::info!("Performing heartbeat.");
-            if let Err(error) = runtime.block_on(heartbeat_data.heartbeat(auth_rng)) {
-                tracing::error!("Error performing heartbeat: {}", error);
+            match runtime.block_on(heartbeat_data.heartbeat(auth_rng)) {
+                Ok(interval) => delay = interval.unwrap_or(HEARTBEAT_POLL_DELAY),
+                Err(error) => tracing::error!("Error performing heartbeat: {}", error),
             }
-            std::thread::sleep(HEARTBEAT_POLL_DELAY);
+            std::thread::sleep(delay);
         }
     });
 }
 
-fn decrypt(passphrase: &SecretString, encrypted: &str) -> Result<Vec<u8>> {
-    let decoded = SecretVec::new(hex::decode(encrypted)?);
-    let decryptor = age::Decryptor::new(decoded.expose_secret().as_slice())?;
+fn decrypt(passphrase: &SecretString, encrypted: &str, max_work_factor: Option<u8>) -> Result<Vec<u8>> {
+    let decoded = SecretVec::new(decode_encrypted(encrypted)?);
+    let decryptor = age::Decryptor::new(ArmoredReader::new(decoded.expose_secret().as_slice()))?;
     let mut output = vec![];
     if let age::Decryptor::Passphrase(decryptor) = decryptor {
         let mut reader = decryptor
-            .decrypt(passphrase, None)
+            .decrypt(passphrase, max_work_factor)
             .map_err(|decrypt_error: DecryptError| match decrypt_error {
                 DecryptError::ExcessiveWork { .. } => anyhow::Error::from(decrypt_error)
                     .context("Perhaps you have forgotten to compile in release mode, or your hardware is too slow?"),
@@ -739,7 +850,7 @@ fn decrypt(passphrase: &SecretString, encrypted: &str) -> Result<Vec<u8>> {
 
 /// Decrypts and reads the private key from the specified `keys_path`,
 /// decrypting using the specified `passphrase`
-fn read_keys<P: Into<PathBuf>>(
+pub(crate) fn read_keys<P: Into<PathBuf>>(
     keys_path: P,
     passphrase: &SecretString,
 ) -> Result<(SecretVec<u8>, PrivateKey<Testnet2Parameters>)> {
@@ -747,8 +858,8 @@ fn read_keys<P: Into<PathBuf>>(
     File::open(keys_path)?.read_to_string(&mut contents)?;
     let keys: AleoSetupKeys = serde_json::from_str(&contents)?;
 
-    let seed = SecretVec::new(decrypt(passphrase, &keys.encrypted_seed)?);
-    let decrypted_private_key = SecretVec::new(decrypt(passphrase, &keys.encrypted_private_key)?);
+    let seed = SecretVec::new(decrypt(passphrase, &keys.encrypted_seed, keys.work_factor)?);
+    let decrypted_private_key = SecretVec::new(decrypt(passphrase, &keys.encrypted_private_key, keys.work_factor)?);
     let private_key = PrivateKey::from_str(std::str::from_utf8(decrypted_private_key.expose_secret())?)?;
 
     Ok((seed, private_key))
@@ -769,7 +880,13 @@ async fn request_coordinator_public_settings(coordinator_url: &Url) -> anyhow::R
 }
 
 pub async fn contribute_subcommand(opts: &ContributeOptions) -> anyhow::Result<()> {
-    let public_settings = request_coordinator_public_settings(&opts.api_url)
+    let api_url = match (&opts.api_url, opts.dry_run) {
+        (_, true) => return crate::commands::dry_run(opts),
+        (Some(api_url), false) => api_url,
+        (None, false) => return Err(anyhow::anyhow!("The coordinator api url is required")),
+    };
+
+    let public_settings = request_coordinator_public_settings(api_url)
         .await
         .map_err(|e| {
             tracing::error!("Failed to fetch the coordinator public settings");
@@ -777,10 +894,10 @@ pub async fn contribute_subcommand(opts: &ContributeOptions) -> anyhow::Result<(
         })
         .with_context(|| "Failed to fetch the coordinator public settings".to_owned())?;
 
-    start_contributor(opts, &public_settings).await
+    start_contributor(opts, api_url, &public_settings).await
 }
 
-async fn start_contributor(opts: &ContributeOptions, public_settings: &PublicSettings) -> Result<()> {
+async fn start_contributor(opts: &ContributeOptions, api_url: &Url, public_settings: &PublicSettings) -> Result<()> {
     let environment = crate::utils::environment_by_setup_kind(&public_settings.setup);
 
     // Initialize tracing logger. Stored to `aleo-setup.log`.
@@ -792,16 +909,17 @@ async fn start_contributor(opts: &ContributeOptions, public_settings: &PublicSet
 
     // Read the stored contribution seed and Aleo private key.
     let (seed, private_key) = read_keys(&opts.keys_path, &passphrase).expect("Unable to load Aleo setup keys");
+    let seed = with_entropy_file(seed, opts.entropy_file.as_deref())?;
 
     let curve_kind = environment.parameters().curve();
 
     // Initialize the contributor.
-    let mut contribute = Contribute::new(opts, &environment, private_key, seed);
+    let mut contribute = Contribute::new(api_url, &environment, private_key, seed);
 
     if public_settings.check_reliability {
         println!("Checking CPU performance, it may take a few minutes");
         tracing::info!("Checking reliability score before joining the queue");
-        crate::reliability::check(&opts.api_url, &contribute.private_key).await?;
+        crate::reliability::check(api_url, &contribute.private_key).await?;
         println!("CPU check complete");
         tracing::info!("Reliability checks completed successfully");
     }
@@ -841,8 +959,27 @@ fn contributor_ids_in_chunk(chunk: &Chunk) -> HashSet<String> {
 
 #[cfg(test)]
 mod test {
-    use super::{chunk_all_verified, contributor_ids_in_chunk};
-    use phase1_coordinator::objects::{Chunk, Participant};
+    use super::{
+        check_previous_contribution_hash,
+        chunk_all_verified,
+        contributor_ids_in_chunk,
+        heartbeat_interval,
+        verify_contribution_locally,
+        HEARTBEAT_INTERVAL_HEADER,
+    };
+    use crate::utils::create_parameters_for_chunk;
+    use phase1_cli::contribute;
+    use phase1_coordinator::{
+        authentication::Dummy,
+        environment::{Environment, Parameters, Testing},
+        objects::{Chunk, Participant},
+        Coordinator,
+    };
+    use setup_utils::{calculate_hash, derive_rng_from_seed};
+    use snarkvm_curves::bls12_377::Bls12_377;
+
+    use fs_err as fs;
+    use std::{sync::Arc, time::Duration};
 
     #[test]
     fn test_participant_ids_in_chunk() {
@@ -885,4 +1022,92 @@ mod test {
         assert!(ids.contains(&contributor1.to_string().replace(".contributor", "")));
         assert!(ids.contains(&contributor2.to_string().replace(".contributor", "")));
     }
+
+    #[test]
+    fn test_heartbeat_interval() {
+        let mut headers = http::HeaderMap::new();
+        assert_eq!(None, heartbeat_interval(&headers));
+
+        headers.insert(HEARTBEAT_INTERVAL_HEADER, "100".parse().unwrap());
+        assert_eq!(Some(Duration::from_secs(100)), heartbeat_interval(&headers));
+
+        headers.insert(HEARTBEAT_INTERVAL_HEADER, "0".parse().unwrap());
+        assert_eq!(None, heartbeat_interval(&headers));
+
+        headers.insert(HEARTBEAT_INTERVAL_HEADER, "soon".parse().unwrap());
+        assert_eq!(None, heartbeat_interval(&headers));
+    }
+
+    #[test]
+    fn test_check_previous_contribution_hash() {
+        let mut challenge = vec![7u8; 128];
+        challenge[..64].copy_from_slice(&calculate_hash(b"previous contribution"));
+        let hash = hex::encode(calculate_hash(b"previous contribution"));
+
+        assert!(check_previous_contribution_hash(None, &challenge).is_ok());
+        assert!(check_previous_contribution_hash(Some(&hash), &challenge).is_ok());
+        assert!(check_previous_contribution_hash(Some(&hash.to_uppercase()), &challenge).is_ok());
+        assert!(check_previous_contribution_hash(Some(&hash), &challenge[1..]).is_err());
+        assert!(check_previous_contribution_hash(Some(&hash), &challenge[..32]).is_err());
+    }
+
+    #[test]
+    fn test_verify_contribution_locally() {
+        let environment: Environment = Testing::from(Parameters::Test3Chunks).into();
+        let challenge = vec![7u8; 128];
+
+        // Check that a response which is not based on the challenge is refused before verification.
+        let response = vec![0u8; 256];
+        let error = verify_contribution_locally::<Bls12_377>(&environment, 0, &challenge, &response).unwrap_err();
+        assert!(error.to_string().contains("not based on the challenge"));
+
+        // Check that a response based on the challenge, but of the wrong size, is refused.
+        let mut response = calculate_hash(&challenge).to_vec();
+        response.resize(256, 0);
+        let error = verify_contribution_locally::<Bls12_377>(&environment, 0, &challenge, &response).unwrap_err();
+        assert!(error.to_string().contains("bytes instead of"));
+    }
+
+    #[test]
+    fn test_verify_contribution_locally_computed() {
+        let directory = std::env::temp_dir().join("setup1-contributor-test-verify-contribution-locally");
+        if directory.exists() {
+            fs::remove_dir_all(&directory).unwrap();
+        }
+        let environment: Environment = Testing::from(Parameters::Test3Chunks)
+            .local_base_directory(directory.to_str().unwrap())
+            .into();
+
+        // Download the challenge of the first chunk from a coordinator in round 1.
+        let participant = Participant::new_contributor("test-contributor");
+        let mut coordinator = Coordinator::new(environment.clone(), Arc::new(Dummy)).unwrap();
+        coordinator.initialize().unwrap();
+        coordinator.add_to_queue(participant, None, 10).unwrap();
+        coordinator.update().unwrap();
+        let challenge_filename = directory.join("challenge").to_string_lossy().to_string();
+        let response_filename = directory.join("response").to_string_lossy().to_string();
+        fs::write(&challenge_filename, &*coordinator.get_challenge(1, 0).unwrap()).unwrap();
+
+        // Compute a contribution on the challenge.
+        contribute(
+            environment.compressed_inputs(),
+            &challenge_filename,
+            environment.compressed_outputs(),
+            &response_filename,
+            environment.check_input_for_correctness(),
+            &create_parameters_for_chunk::<Bls12_377>(&environment, 0).unwrap(),
+            derive_rng_from_seed(&[7u8; 64]),
+        );
+        let challenge = fs::read(&challenge_filename).unwrap();
+        let mut response = fs::read(&response_filename).unwrap();
+        fs::remove_dir_all(&directory).unwrap();
+
+        // Check that the contribution verifies, and that the hash of the response is returned.
+        let hash = verify_contribution_locally::<Bls12_377>(&environment, 0, &challenge, &response).unwrap();
+        assert_eq!(calculate_hash(&response).to_vec(), hash);
+
+        // Check that the contribution no longer verifies once a byte after the challenge hash is corrupted.
+        response[100] ^= 1;
+        assert!(verify_contribution_locally::<Bls12_377>(&environment, 0, &challenge, &response).is_err());
+    }
 }
@@ -0,0 +1,186 @@
+use crate::{
+    cli::commands::contribute::ContributeOptions,
+    commands::contribute::{read_keys, verify_contribution_locally},
+    entropy::{entropy_pool, with_entropy_file},
+    errors::ContributeError,
+    utils::{create_parameters_for_chunk, read_from_file},
+};
+
+use phase1_cli::contribute;
+use phase1_coordinator::{
+    authentication::{Dummy, Signature},
+    environment::{Environment, Parameters, Testing},
+    objects::{ContributionFileSignature, ContributionState},
+    Coordinator,
+    Participant,
+};
+use setup_utils::{calculate_hash, derive_rng_from_seed};
+use snarkvm_curves::bls12_377::Bls12_377;
+use snarkvm_dpc::{parameters::testnet2::Testnet2Parameters, Address, PrivateKey};
+
+use anyhow::{anyhow, Context, Result};
+use fs_err as fs;
+use panic_control::{spawn_quiet, ThreadResultExt};
+use secrecy::{ExposeSecret, SecretVec};
+use std::{convert::TryFrom, path::Path, sync::Arc, time::Instant};
+use tracing::error;
+
+/// Runs the given step of the dry run, and reports how long it took or why it failed
+fn step<T>(name: &str, f: impl FnOnce() -> Result<T>) -> Result<T> {
+    let start = Instant::now();
+    let result = f();
+    let elapsed = start.elapsed().as_millis();
+    match &result {
+        Ok(_) => println!("{:<10} ok in {} ms", name, elapsed),
+        Err(error) => println!("{:<10} failed after {} ms: {:#}", name, elapsed, error),
+    }
+    result.with_context(|| format!("Dry run failed at the {} step", name))
+}
+
+/// Rehearses the contribution flow against an in-process coordinator with
+/// tiny parameters. This exercises the keys, the disk and the real
+/// contribution path, without ever contacting the ceremony.
+pub fn dry_run(opts: &ContributeOptions) -> Result<()> {
+    println!("Dry run against a local mock coordinator, the ceremony will not be contacted\n");
+
+    let result = step("keys", || {
+        let passphrase = crate::setup_keys::read_passphrase(opts.passphrase.clone())?;
+        let (seed, private_key) = read_keys(&opts.keys_path, &passphrase)?;
+        Ok((with_entropy_file(seed, opts.entropy_file.as_deref())?, private_key))
+    })
+    .and_then(|(seed, private_key)| {
+        // The storage of the mock coordinator lives in a directory of its own, which
+        // is removed whether or not the dry run succeeded.
+        let directory = std::env::temp_dir().join(format!("aleo-setup-dry-run-{}", std::process::id()));
+        let result = rehearse(&directory, seed, &private_key);
+        if directory.exists() {
+            fs::remove_dir_all(&directory)?;
+        }
+        result
+    });
+
+    match &result {
+        Ok(_) => println!("\nDry run complete, your environment is ready to contribute"),
+        Err(error) => error!("{:#}", error),
+    }
+    result
+}
+
+/// Rehearses the contribution of the given contributor, with the storage of the
+/// mock coordinator and the challenge and response files in the given directory.
+fn rehearse(directory: &Path, seed: SecretVec<u8>, private_key: &PrivateKey<Testnet2Parameters>) -> Result<()> {
+    let participant = Participant::new_contributor(&Address::try_from(private_key)?.to_string());
+
+    // Start a mock coordinator on a fresh storage, and join its queue.
+    let base_directory = directory
+        .to_str()
+        .ok_or_else(|| anyhow!("The dry run directory {} is not valid UTF-8", directory.display()))?;
+    let environment: Environment = Testing::from(Parameters::Test3Chunks)
+        .local_base_directory(base_directory)
+        .into();
+    let signature = Arc::new(Dummy);
+    let mut coordinator = step("join", || {
+        let mut coordinator = Coordinator::new(environment.clone(), signature.clone())?;
+        coordinator.initialize()?;
+        coordinator.add_to_queue(participant.clone(), None, 10)?;
+        coordinator.update()?;
+        Ok(coordinator)
+    })?;
+
+    let (chunk_id, locked_locators) = step("lock", || Ok(coordinator.try_lock(&participant)?))?;
+
+    let challenge_filename = directory.join("challenge").to_string_lossy().to_string();
+    let response_filename = directory.join("response").to_string_lossy().to_string();
+    step("download", || {
+        let round_height = coordinator.current_round_height()?;
+        let challenge = coordinator.get_challenge(round_height, chunk_id)?;
+        fs::write(&challenge_filename, &*challenge)?;
+        Ok(())
+    })?;
+
+    step("compute", || {
+        let parameters = create_parameters_for_chunk::<Bls12_377>(&environment, chunk_id as usize)?;
+        let compressed_input = environment.compressed_inputs();
+        let compressed_output = environment.compressed_outputs();
+        let check_input_correctness = environment.check_input_for_correctness();
+        let (pool, quality) = entropy_pool(seed.expose_secret(), &mut rand::rngs::OsRng)?;
+        println!("{:<10} {:.2} bits of entropy per byte", "", quality.entropy_per_byte);
+        let seeded_rng = derive_rng_from_seed(pool.expose_secret());
+        let (challenge_filename, response_filename) = (challenge_filename.clone(), response_filename.clone());
+
+        let h = spawn_quiet(move || {
+            contribute(
+                compressed_input,
+                &challenge_filename,
+                compressed_output,
+                &response_filename,
+                check_input_correctness,
+                &parameters,
+                seeded_rng,
+            );
+        });
+        let result = h.join();
+        if result.is_err() {
+            if let Some(panic_value) = result.panic_value_as_str() {
+                error!("Contribute failed: {}", panic_value);
+            }
+            return Err(ContributeError::FailedRunningContributeError.into());
+        }
+        Ok(())
+    })?;
+
+    step("verify", || {
+        let challenge = read_from_file(&challenge_filename)?;
+        let response = read_from_file(&response_filename)?;
+        verify_contribution_locally::<Bls12_377>(&environment, chunk_id, &challenge, &response)?;
+        Ok(())
+    })?;
+
+    step("upload", || {
+        let challenge_hash = calculate_hash(&read_from_file(&challenge_filename)?).to_vec();
+        let response = read_from_file(&response_filename)?;
+        let response_hash = calculate_hash(&response).to_vec();
+
+        // Sign the contribution with the signature scheme of the mock coordinator, so that it accepts it.
+        let contribution_state = ContributionState::new(challenge_hash, response_hash, None)?;
+        let contribution_signature =
+            signature.sign(&participant.address(), &contribution_state.signature_message()?)?;
+        let signed_contribution_state = ContributionFileSignature::new(contribution_signature, contribution_state)?;
+
+        coordinator.write_contribution(
+            &participant,
+            locked_locators.next_contribution(),
+            response,
+            locked_locators.next_contribution_file_signature(),
+            signed_contribution_state,
+        )?;
+        Ok(())
+    })?;
+
+    step("contribute", || Ok(coordinator.try_contribute(&participant, chunk_id)?))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::rehearse;
+
+    use fs_err as fs;
+    use secrecy::SecretVec;
+    use snarkvm_dpc::{parameters::testnet2::Testnet2Parameters, PrivateKey};
+
+    #[test]
+    fn test_rehearse() {
+        let directory = std::env::temp_dir().join("setup1-contributor-test-dry-run");
+        if directory.exists() {
+            fs::remove_dir_all(&directory).unwrap();
+        }
+
+        // Check that the mock coordinator accepts the contribution of a fresh contributor.
+        let private_key = PrivateKey::<Testnet2Parameters>::new(&mut rand::thread_rng());
+        let result = rehearse(&directory, SecretVec::new(vec![7u8; 64]), &private_key);
+        fs::remove_dir_all(&directory).unwrap();
+        result.unwrap();
+    }
+}
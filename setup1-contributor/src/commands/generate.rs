@@ -9,7 +9,7 @@ pub fn generate_keys(opts: GenerateOptions) {
 
     println!("\nDO NOT FORGET YOUR PASSPHRASE!\n\nYou will need your passphrase to access your keys.\n\n");
 
-    let aleo_setup_keys = crate::setup_keys::generate(passphrase);
+    let aleo_setup_keys = crate::setup_keys::generate(passphrase, opts.armor);
 
     file.write_all(&serde_json::to_vec(&aleo_setup_keys).expect("Should have converted setup keys to vector"))
         .expect("Should have written setup keys successfully to file");
@@ -1,5 +1,8 @@
 pub mod contribute;
 pub use contribute::*;
 
+pub mod dry_run;
+pub use dry_run::*;
+
 pub mod generate;
 pub use generate::*;
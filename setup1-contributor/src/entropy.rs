@@ -0,0 +1,127 @@
+use crate::errors::ContributeError;
+
+use anyhow::{Context, Result};
+use fs_err as fs;
+use rand::RngCore;
+use secrecy::{ExposeSecret, SecretVec};
+use setup_utils::calculate_hash;
+use std::path::Path;
+
+/// The number of bytes of OS randomness sampled for each contribution.
+const SAMPLE_SIZE: usize = 4096;
+/// The minimum estimated entropy of a sample, in bits per byte. A uniform
+/// sample of `SAMPLE_SIZE` bytes is expected to estimate close to 7.95.
+const MIN_ENTROPY_PER_BYTE: f64 = 7.5;
+/// The maximum deviation of the number of set bits in a sample from half,
+/// in standard deviations. A uniform sample exceeds it about once in a million.
+const MAX_MONOBIT_DEVIATION: f64 = 5.0;
+
+/// The measured quality of a sample of randomness.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RandomnessQuality {
+    /// The estimated Shannon entropy of the sample, in bits per byte.
+    pub entropy_per_byte: f64,
+    /// The deviation of the number of set bits from half, in standard deviations.
+    pub monobit_deviation: f64,
+}
+
+impl RandomnessQuality {
+    /// Measures the quality of the given sample.
+    pub fn measure(sample: &[u8]) -> Self {
+        let mut counts = [0usize; 256];
+        for byte in sample {
+            counts[*byte as usize] += 1;
+        }
+        let length = sample.len() as f64;
+        let entropy_per_byte = counts
+            .iter()
+            .filter(|count| **count > 0)
+            .map(|count| {
+                let probability = *count as f64 / length;
+                -probability * probability.log2()
+            })
+            .sum();
+
+        let bits = length * 8.0;
+        let ones = sample.iter().map(|byte| byte.count_ones() as f64).sum::<f64>();
+        let monobit_deviation = (ones - bits / 2.0).abs() / (bits.sqrt() / 2.0);
+
+        Self {
+            entropy_per_byte,
+            monobit_deviation,
+        }
+    }
+
+    /// Returns `true` if the sample could plausibly have come from a uniform source.
+    pub fn is_plausible(&self) -> bool {
+        self.entropy_per_byte >= MIN_ENTROPY_PER_BYTE && self.monobit_deviation <= MAX_MONOBIT_DEVIATION
+    }
+}
+
+/// Appends the contents of the given entropy file, if any, to the stored seed.
+pub fn with_entropy_file(seed: SecretVec<u8>, entropy_file: Option<&Path>) -> Result<SecretVec<u8>> {
+    let path = match entropy_file {
+        Some(path) => path,
+        None => return Ok(seed),
+    };
+    let entropy = fs::read(path).with_context(|| "Failed to read the entropy file")?;
+    Ok(SecretVec::new([seed.expose_secret().as_slice(), &entropy].concat()))
+}
+
+///
+/// Samples fresh randomness from the given generator, checks that it is
+/// plausibly uniform, and mixes it with the given seed into the seed of
+/// a contribution.
+///
+/// Returns an error, rather than a weak seed, if the sample fails the checks.
+///
+pub fn entropy_pool<R: RngCore>(
+    seed: &[u8],
+    rng: &mut R,
+) -> Result<(SecretVec<u8>, RandomnessQuality), ContributeError> {
+    let mut sample = vec![0u8; SAMPLE_SIZE];
+    rng.fill_bytes(&mut sample);
+
+    let quality = RandomnessQuality::measure(&sample);
+    if !quality.is_plausible() {
+        return Err(ContributeError::WeakRandomnessError {
+            entropy_per_byte: quality.entropy_per_byte,
+            monobit_deviation: quality.monobit_deviation,
+        });
+    }
+
+    sample.extend_from_slice(seed);
+    let pool = SecretVec::new(calculate_hash(&sample).to_vec());
+    sample.iter_mut().for_each(|byte| *byte = 0);
+
+    Ok((pool, quality))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rand::rngs::{mock::StepRng, OsRng};
+
+    #[test]
+    fn test_os_randomness_is_plausible() {
+        let (first, quality) = entropy_pool(&[1u8; 64], &mut OsRng).unwrap();
+        assert!(quality.is_plausible());
+
+        // Check that the fresh randomness makes every pool different for the same seed.
+        let (second, _) = entropy_pool(&[1u8; 64], &mut OsRng).unwrap();
+        assert_ne!(first.expose_secret(), second.expose_secret());
+    }
+
+    #[test]
+    fn test_weak_randomness_is_refused() {
+        assert!(!RandomnessQuality::measure(&[0u8; SAMPLE_SIZE]).is_plausible());
+
+        // A sample with every byte value equally often, but mostly set bits.
+        let biased: Vec<u8> = (0..SAMPLE_SIZE).map(|i| (i as u8) | 0x80).collect();
+        assert!(!RandomnessQuality::measure(&biased).is_plausible());
+
+        let result = entropy_pool(&[1u8; 64], &mut StepRng::new(0, 1));
+        assert!(matches!(result, Err(ContributeError::WeakRandomnessError { .. })));
+    }
+}
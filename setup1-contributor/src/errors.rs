@@ -6,8 +6,21 @@ pub enum ContributeError {
     CouldNotReadPassphraseError,
     #[error("Failed running contribute")]
     FailedRunningContributeError,
+    #[error("The contribution failed local verification: {0}")]
+    LocalVerificationError(String),
+    #[error(
+        "The challenge begins with the hash {found}, but the coordinator expected the previous contribution {expected}"
+    )]
+    PreviousContributionHashMismatchError { expected: String, found: String },
     #[error("Unsupported decryptor")]
     UnsupportedDecryptorError,
+    #[error(
+        "The randomness is implausibly weak, with {entropy_per_byte:.2} bits of entropy per byte and a bit bias of {monobit_deviation:.1} standard deviations"
+    )]
+    WeakRandomnessError {
+        entropy_per_byte: f64,
+        monobit_deviation: f64,
+    },
 }
 
 #[derive(Debug, Error)]
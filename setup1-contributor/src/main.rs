@@ -3,6 +3,7 @@ use structopt::StructOpt;
 
 mod cli;
 mod commands;
+mod entropy;
 mod errors;
 mod objects;
 mod reliability;
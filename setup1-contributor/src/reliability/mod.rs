@@ -1,19 +1,28 @@
 //! Functions and data structures related to reliability score checks
 
 use anyhow::{anyhow, Result};
-use futures_util::{SinkExt, StreamExt};
+use futures_util::{Sink, SinkExt, Stream, StreamExt};
 use http::Request;
 use setup1_shared::reliability::{
     ContributorMessage,
     ContributorMessageName,
     CoordinatorMessage,
     CoordinatorMessageName,
+    ReliabilityCheck,
+    ReliabilityReport,
     MAXIMUM_MESSAGE_SIZE,
 };
 use snarkvm_dpc::{parameters::testnet2::Testnet2Parameters, PrivateKey};
+use std::{
+    fmt::Debug,
+    time::{Duration, Instant},
+};
 use tokio_tungstenite::{
     connect_async_with_config,
-    tungstenite::protocol::{Message, WebSocketConfig},
+    tungstenite::{
+        protocol::{Message, WebSocketConfig},
+        Error as WsError,
+    },
 };
 use url::Url;
 
@@ -45,29 +54,119 @@ fn prepare_request(api_url: &Url, api_path: &str, private_key: &PrivateKey<Testn
         .map_err(Into::into)
 }
 
+/// The reliability checks a contributor takes part in, and how
+/// to reconnect to the coordinator if the connection drops
+pub(crate) struct ReliabilityConfig {
+    pub checks: Vec<ReliabilityCheck>,
+    /// The number of attempts to connect before giving up
+    pub max_connection_attempts: u32,
+    /// The delay before the first reconnection, doubled on each attempt
+    pub initial_backoff: Duration,
+    /// The cap on the delay between reconnections
+    pub max_backoff: Duration,
+}
+
+impl ReliabilityConfig {
+    /// Returns the delay before the next attempt after `attempt` failed ones
+    fn backoff(&self, attempt: u32) -> Duration {
+        let factor = 1u32 << attempt.saturating_sub(1).min(16);
+        self.initial_backoff.saturating_mul(factor).min(self.max_backoff)
+    }
+}
+
+impl Default for ReliabilityConfig {
+    fn default() -> Self {
+        Self {
+            checks: vec![
+                ReliabilityCheck::Latency,
+                ReliabilityCheck::Bandwidth,
+                ReliabilityCheck::Cpu,
+            ],
+            max_connection_attempts: 5,
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(30),
+        }
+    }
+}
+
 /// Check reliability score before starting
 /// to contribute
 pub(crate) async fn check(api_base_url: &Url, private_key: &PrivateKey<Testnet2Parameters>) -> Result<()> {
+    let config = ReliabilityConfig::default();
+    let mut report = ReliabilityReport::default();
+
+    let mut attempt = 0;
+    loop {
+        attempt += 1;
+        match connect_and_run(api_base_url, private_key, &config, &mut report).await {
+            Ok(()) => break,
+            Err(error) if attempt < config.max_connection_attempts => {
+                let backoff = config.backoff(attempt);
+                tracing::warn!(
+                    "Reliability checks interrupted on attempt {} of {}: {:?}. Reconnecting in {:?}",
+                    attempt,
+                    config.max_connection_attempts,
+                    error,
+                    backoff,
+                );
+                tokio::time::sleep(backoff).await;
+            }
+            Err(error) => {
+                return Err(error.context(format!("The coordinator is unreachable after {} attempts", attempt)));
+            }
+        }
+    }
+
+    tracing::info!("Reliability checks are done, score: {}", report.score());
+    Ok(())
+}
+
+/// Opens a WebSocket connection to the coordinator and answers
+/// its challenges until it closes the connection
+async fn connect_and_run(
+    api_base_url: &Url,
+    private_key: &PrivateKey<Testnet2Parameters>,
+    config: &ReliabilityConfig,
+    report: &mut ReliabilityReport,
+) -> Result<()> {
     let api_path = "/v1/contributor/reliability";
     let request = prepare_request(api_base_url, api_path, private_key)?;
 
-    let mut config = WebSocketConfig::default();
-    config.max_frame_size = Some(MAXIMUM_MESSAGE_SIZE);
-    config.max_message_size = Some(MAXIMUM_MESSAGE_SIZE);
+    let mut ws_config = WebSocketConfig::default();
+    ws_config.max_frame_size = Some(MAXIMUM_MESSAGE_SIZE);
+    ws_config.max_message_size = Some(MAXIMUM_MESSAGE_SIZE);
 
-    let (ws_stream, _response) = connect_async_with_config(request, Some(config))
+    let (ws_stream, _response) = connect_async_with_config(request, Some(ws_config))
         .await
         .map_err(|e| anyhow!("Failed to connect to coordinator via WebSocket: {:?}", e))?;
     tracing::trace!("WebSocket handshake has been successfully completed");
 
     let (mut write, mut read) = ws_stream.split();
 
+    run_all(&mut write, &mut read, config, report).await
+}
+
+/// Answers the coordinator challenges for as long as the coordinator
+/// keeps sending them, recording every run in the report. The
+/// coordinator measures the checks from the answers to its own
+/// challenges, so the report is only kept to log the outcome, and
+/// the checks are done once the coordinator closes the connection.
+pub(crate) async fn run_all<W, R>(
+    write_half: &mut W,
+    read_half: &mut R,
+    config: &ReliabilityConfig,
+    report: &mut ReliabilityReport,
+) -> Result<()>
+where
+    W: Sink<Message> + Unpin,
+    W::Error: Debug,
+    R: Stream<Item = Result<Message, WsError>> + Unpin,
+{
     loop {
-        let raw_message = if let Some(next) = read.next().await {
+        let raw_message = if let Some(next) = read_half.next().await {
             next?
         } else {
-            tracing::trace!("Got None in reliability checks, closing the connection");
-            break;
+            return Err(anyhow!("The connection dropped during the reliability checks"));
         };
         if raw_message.is_close() {
             break;
@@ -80,24 +179,69 @@ pub(crate) async fn check(api_base_url: &Url, private_key: &PrivateKey<Testnet2P
         let decoded = CoordinatorMessage::from_slice(&raw_message.into_data()).map_err(|e| anyhow!("{:?}", e))?;
         tracing::trace!("Got message from coordinator: {:?}", decoded.name);
 
-        match decoded.name {
-            CoordinatorMessageName::Ping => latency::check(&mut write, decoded.data).await?,
-            CoordinatorMessageName::BandwidthChallenge => bandwidth::check(&mut write, decoded.data).await?,
-            CoordinatorMessageName::CpuChallenge => cpu::check(&mut write, decoded.data).await?,
+        let check = match decoded.name {
+            CoordinatorMessageName::Ping => ReliabilityCheck::Latency,
+            CoordinatorMessageName::BandwidthChallenge => ReliabilityCheck::Bandwidth,
+            CoordinatorMessageName::CpuChallenge => ReliabilityCheck::Cpu,
             other => {
                 let text = format!(
                     "Wrong message, expected one of Ping | BandwidthChallenge | CpuChallenge, got {:?}",
                     other,
                 );
-                tracing::warn!("{}", text);
-                let error = ContributorMessage {
-                    name: ContributorMessageName::Error,
-                    data: text.into_bytes(),
-                };
-                let response = Message::binary(error.to_vec());
-                write.send(response).await.map_err(|e| anyhow!("{:?}", e))?;
+                send_error(write_half, text).await?;
+                continue;
             }
+        };
+        if !config.checks.contains(&check) {
+            send_error(write_half, format!("The {:?} check is disabled", check)).await?;
+            continue;
         }
+
+        let started = Instant::now();
+        let result = match check {
+            ReliabilityCheck::Latency => latency::check(write_half, decoded.data).await,
+            ReliabilityCheck::Bandwidth => bandwidth::check(write_half, decoded.data).await,
+            ReliabilityCheck::Cpu => cpu::check(write_half, decoded.data).await,
+        };
+        report.record(check, started.elapsed(), result.err().map(|e| e.to_string()));
     }
+
+    for check in config.checks.iter().filter(|check| !report.contains(**check)) {
+        tracing::warn!(
+            "The coordinator closed the connection without running the {:?} check",
+            check
+        );
+    }
+    Ok(())
+}
+
+async fn send_error<W>(write_half: &mut W, text: String) -> Result<()>
+where
+    W: Sink<Message> + Unpin,
+    W::Error: Debug,
+{
+    tracing::warn!("{}", text);
+    let error = ContributorMessage {
+        name: ContributorMessageName::Error,
+        data: text.into_bytes(),
+    };
+    let response = Message::binary(error.to_vec());
+    write_half.send(response).await.map_err(|e| anyhow!("{:?}", e))?;
     Ok(())
 }
+
+#[cfg(test)]
+mod test {
+    use super::ReliabilityConfig;
+    use std::time::Duration;
+
+    #[test]
+    fn test_backoff_doubles_up_to_the_cap() {
+        let config = ReliabilityConfig::default();
+        assert_eq!(config.backoff(1), Duration::from_secs(1));
+        assert_eq!(config.backoff(2), Duration::from_secs(2));
+        assert_eq!(config.backoff(5), Duration::from_secs(16));
+        assert_eq!(config.backoff(6), Duration::from_secs(30));
+        assert_eq!(config.backoff(100), Duration::from_secs(30));
+    }
+}
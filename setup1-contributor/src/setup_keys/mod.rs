@@ -5,7 +5,7 @@ use age::{
     cli_common::Passphrase,
     Encryptor,
 };
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use rand::{rngs::OsRng, RngCore};
 use secrecy::{ExposeSecret, SecretString, SecretVec};
 use serde::{Deserialize, Serialize};
@@ -15,12 +15,21 @@ use crate::errors::ContributeError;
 
 pub mod confirmation_key;
 
+/// The first line of an ASCII-armored age file.
+const ARMOR_BEGIN_MARKER: &str = "-----BEGIN AGE ENCRYPTED FILE-----";
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct AleoSetupKeys {
     pub encrypted_seed: String,
     pub encrypted_private_key: String,
     pub address: String,
+    /// The scrypt work factor the keys were encrypted with. age calibrates
+    /// it to the machine that generated the keys, and decryption refuses
+    /// files which need more work than this. Keys generated before it was
+    /// recorded fall back to age's own limit.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub work_factor: Option<u8>,
 }
 
 struct UnencryptedKeys {
@@ -28,29 +37,66 @@ struct UnencryptedKeys {
     private_key: PrivateKey<Testnet2Parameters>,
 }
 
-fn encrypt(passphrase: SecretString, secret: &[u8]) -> Result<String> {
+/// Encrypts the secret with the passphrase, and returns it either as
+/// an ASCII-armored age file, or as the hex encoding of a binary age file,
+/// along with the scrypt work factor age picked for it.
+fn encrypt(passphrase: SecretString, secret: &[u8], armor: bool) -> Result<(String, u8)> {
     let encryptor = Encryptor::with_user_passphrase(passphrase);
     let mut encrypted_output = vec![];
-    let mut writer = encryptor.wrap_output(ArmoredWriter::wrap_output(&mut encrypted_output, Format::Binary)?)?;
+    let mut writer = encryptor.wrap_output(&mut encrypted_output)?;
     writer.write_all(secret)?;
     writer.finish()?;
-    let encrypted_secret = hex::encode(&encrypted_output);
-    Ok(encrypted_secret)
+    let work_factor = scrypt_work_factor(&encrypted_output).ok_or_else(|| anyhow!("Missing scrypt stanza"))?;
+    let encrypted_secret = match armor {
+        true => {
+            let mut armored_output = vec![];
+            let mut writer = ArmoredWriter::wrap_output(&mut armored_output, Format::AsciiArmor)?;
+            writer.write_all(&encrypted_output)?;
+            writer.finish()?;
+            String::from_utf8(armored_output)?
+        }
+        false => hex::encode(&encrypted_output),
+    };
+    Ok((encrypted_secret, work_factor))
+}
+
+/// Returns the scrypt work factor (log2 of N) from the header of
+/// a binary age file encrypted with a passphrase.
+fn scrypt_work_factor(encrypted: &[u8]) -> Option<u8> {
+    encrypted
+        .split(|byte| *byte == b'\n')
+        .take_while(|line| !line.starts_with(b"---"))
+        .filter_map(|line| std::str::from_utf8(line).ok())
+        .find_map(|line| match line.split(' ').collect::<Vec<_>>()[..] {
+            ["->", "scrypt", _salt, work_factor] => work_factor.parse().ok(),
+            _ => None,
+        })
 }
 
-fn encrypt_keys(unencrypted: &UnencryptedKeys, passphrase: SecretString) -> AleoSetupKeys {
+/// Returns the bytes of an age file encrypted by `encrypt`, which may
+/// either be ASCII-armored or hex encoded.
+pub fn decode_encrypted(encrypted: &str) -> Result<Vec<u8>> {
+    match encrypted.starts_with(ARMOR_BEGIN_MARKER) {
+        true => Ok(encrypted.as_bytes().to_vec()),
+        false => Ok(hex::decode(encrypted)?),
+    }
+}
+
+fn encrypt_keys(unencrypted: &UnencryptedKeys, passphrase: SecretString, armor: bool) -> AleoSetupKeys {
     let address = Address::from_private_key(&unencrypted.private_key)
         .expect("Should have derived an Aleo address")
         .to_string();
-    let encrypted_seed =
-        encrypt(passphrase.clone(), unencrypted.seed.expose_secret()).expect("Should have encrypted Aleo seed");
-    let encrypted_private_key =
-        encrypt(passphrase, unencrypted.private_key.to_string().as_bytes()).expect("Should have encrypted private key");
+    let (encrypted_seed, seed_work_factor) =
+        encrypt(passphrase.clone(), unencrypted.seed.expose_secret(), armor).expect("Should have encrypted Aleo seed");
+    let (encrypted_private_key, private_key_work_factor) =
+        encrypt(passphrase, unencrypted.private_key.to_string().as_bytes(), armor)
+            .expect("Should have encrypted private key");
 
     AleoSetupKeys {
         encrypted_seed,
         encrypted_private_key,
         address,
+        work_factor: Some(seed_work_factor.max(private_key_work_factor)),
     }
 }
 
@@ -63,9 +109,9 @@ fn generate_unencrypted() -> UnencryptedKeys {
     UnencryptedKeys { seed, private_key }
 }
 
-pub fn generate(passphrase: SecretString) -> AleoSetupKeys {
+pub fn generate(passphrase: SecretString, armor: bool) -> AleoSetupKeys {
     let unencrypted = generate_unencrypted();
-    encrypt_keys(&unencrypted, passphrase)
+    encrypt_keys(&unencrypted, passphrase, armor)
 }
 
 /// If `cli_passphrase` is `None`, request passphrase via pinentry or tty
@@ -94,3 +140,19 @@ pub fn read_or_generate_passphrase(cli_passphrase: Option<SecretString>) -> Secr
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::scrypt_work_factor;
+
+    #[test]
+    fn test_scrypt_work_factor() {
+        let header =
+            b"age-encryption.org/v1\n-> scrypt rF0/NLdqmeVo3khMfqvZxw 18\nZ8VgDwZXNtt0\n--- mac\n-> scrypt x 30";
+        assert_eq!(Some(18), scrypt_work_factor(header));
+        assert_eq!(
+            None,
+            scrypt_work_factor(b"age-encryption.org/v1\n-> X25519 abc\nxyz\n--- mac\n")
+        );
+    }
+}
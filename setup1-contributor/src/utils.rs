@@ -159,3 +159,43 @@ pub fn environment_by_setup_kind(kind: &SetupKind) -> Environment {
         SetupKind::Universal => universal_environment(),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::sign_contribution_state;
+    use setup1_cli_tools::verify_contribution;
+    use setup_utils::calculate_hash;
+    use snarkvm_dpc::{parameters::testnet2::Testnet2Parameters, Address, PrivateKey, ViewKey};
+
+    #[test]
+    fn test_sign_contribution_state_verifies() {
+        let mut rng = rand::thread_rng();
+        let private_key = PrivateKey::<Testnet2Parameters>::new(&mut rng);
+        let view_key = ViewKey::from_private_key(&private_key).unwrap();
+        let address = Address::from_private_key(&private_key).unwrap().to_string();
+
+        let challenge = vec![1u8; 128];
+        let response = vec![2u8; 128];
+        let signature = sign_contribution_state(
+            &view_key.to_string(),
+            &calculate_hash(&challenge),
+            &calculate_hash(&response),
+            None,
+            &mut rng,
+        )
+        .unwrap();
+
+        // Check that the verify-contribution tool accepts the signature of the response.
+        let (hash, failure) = verify_contribution(&response, &signature, &address).unwrap();
+        assert_eq!(hex::encode(calculate_hash(&response)), hash);
+        assert_eq!(None, failure);
+
+        // Check that the tool rejects the signature for another file, or another address.
+        let (_, failure) = verify_contribution(&challenge, &signature, &address).unwrap();
+        assert!(failure.is_some());
+        let other_private_key = PrivateKey::<Testnet2Parameters>::new(&mut rng);
+        let other_address = Address::from_private_key(&other_private_key).unwrap().to_string();
+        let (_, failure) = verify_contribution(&response, &signature, &other_address).unwrap();
+        assert!(failure.is_some());
+    }
+}
@@ -10,6 +10,21 @@ type Error = Box<dyn std::error::Error + Send + Sync>;
 /// additional needs which may arise in the future
 pub const MAXIMUM_MESSAGE_SIZE: usize = 101 * 1024 * 1024;
 
+/// Version of the reliability protocol, prefixed to every message.
+/// Bump it whenever the message names or their data change.
+pub const PROTOCOL_VERSION: u16 = 1;
+
+fn check_version(version: u16) -> Result<(), Error> {
+    if version != PROTOCOL_VERSION {
+        return Err(format!(
+            "Protocol version mismatch: expected {}, got {}",
+            PROTOCOL_VERSION, version,
+        )
+        .into());
+    }
+    Ok(())
+}
+
 pub trait MessageName: Sized {
     fn from_str(input: &str) -> Result<Self, String>;
     fn as_bytes(&self) -> &'static [u8];
@@ -21,8 +36,16 @@ pub struct Message<T: MessageName> {
 }
 
 /// Message format:
-/// 1 byte name length | name | 4 bytes data length | data
+/// 2 bytes protocol version | 1 byte name length | name | 4 bytes data length | data
 impl<T: MessageName> Message<T> {
+    /// Read the protocol version and check it matches this one
+    #[cfg(feature = "async_message")]
+    pub async fn read_version<R: AsyncRead + Unpin>(reader: &mut R) -> Result<(), Error> {
+        let mut version_buffer = [0u8; 2];
+        reader.read_exact(&mut version_buffer).await?;
+        check_version(u16::from_be_bytes(version_buffer))
+    }
+
     /// Read message name as Vec of bytes
     #[cfg(feature = "async_message")]
     pub async fn read_name<R: AsyncRead + Unpin>(reader: &mut R) -> Result<Vec<u8>, Error> {
@@ -55,11 +78,19 @@ impl<T: MessageName> Message<T> {
     /// Write entire message to a provided writer
     #[cfg(feature = "async_message")]
     pub async fn write<W: AsyncWrite + Unpin>(&self, writer: &mut W) -> Result<(), Error> {
+        self.write_version(writer).await?;
         self.write_name(writer).await?;
         self.write_data(writer).await?;
         Ok(())
     }
 
+    /// Write only the protocol version to a provided writer
+    #[cfg(feature = "async_message")]
+    pub async fn write_version<W: AsyncWrite + Unpin>(&self, writer: &mut W) -> Result<(), Error> {
+        writer.write(&PROTOCOL_VERSION.to_be_bytes()).await?;
+        Ok(())
+    }
+
     /// Write only message name to a provided writer
     #[cfg(feature = "async_message")]
     pub async fn write_name<W: AsyncWrite + Unpin>(&self, writer: &mut W) -> Result<(), Error> {
@@ -81,16 +112,21 @@ impl<T: MessageName> Message<T> {
 
     /// Encodes self as a vector of bytes
     pub fn to_vec(&self) -> Vec<u8> {
+        let version = PROTOCOL_VERSION.to_be_bytes();
         let name = self.name.as_bytes();
         let name_length = (name.len() as u8).to_be_bytes();
         let data_length = (self.data.len() as u32).to_be_bytes();
-        [&name_length, name, &data_length, &self.data].concat()
+        [&version, &name_length, name, &data_length, &self.data].concat()
     }
 
     /// Decodes Self from a slice of bytes
     pub fn from_slice(bytes: &[u8]) -> Result<Self, Error> {
         let mut reader = BufReader::new(bytes);
 
+        let mut version_buffer = [0u8; 2];
+        reader.read_exact(&mut version_buffer)?;
+        check_version(u16::from_be_bytes(version_buffer))?;
+
         let mut name_length_buffer = [0u8; 1];
         reader.read_exact(&mut name_length_buffer)?;
         let name_length = u8::from_be_bytes(name_length_buffer);
@@ -133,3 +169,15 @@ fn message_round_trip() {
     assert_eq!(decoded.name, message.name);
     assert_eq!(decoded.data, message.data);
 }
+
+#[test]
+fn message_version_mismatch() {
+    let message = Message {
+        name: ContributorMessageName::Pong,
+        data: vec![],
+    };
+    let mut encoded = message.to_vec();
+    encoded[..2].copy_from_slice(&(PROTOCOL_VERSION + 1).to_be_bytes());
+    let error = Message::<ContributorMessageName>::from_slice(&encoded).err().unwrap();
+    assert!(error.to_string().starts_with("Protocol version mismatch"));
+}
@@ -3,11 +3,13 @@
 mod contributor;
 mod coordinator;
 mod message;
+mod report;
 
 pub use contributor::ContributorMessageName;
 pub use coordinator::CoordinatorMessageName;
 use message::Message;
-pub use message::{MessageName, MAXIMUM_MESSAGE_SIZE};
+pub use message::{MessageName, MAXIMUM_MESSAGE_SIZE, PROTOCOL_VERSION};
+pub use report::{ReliabilityCheck, ReliabilityCheckResult, ReliabilityReport};
 
 pub type ContributorMessage = Message<ContributorMessageName>;
 pub type CoordinatorMessage = Message<CoordinatorMessageName>;
@@ -0,0 +1,114 @@
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// The kind of a reliability check a contributor goes through
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ReliabilityCheck {
+    Bandwidth,
+    Cpu,
+    Latency,
+}
+
+/// The outcome of a single kind of reliability check. If the
+/// coordinator runs a check several times, the results are
+/// merged: it passes only if every run passed, and the time
+/// spent is the sum of all runs.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ReliabilityCheckResult {
+    pub check: ReliabilityCheck,
+    pub passed: bool,
+    pub runs: u32,
+    pub elapsed_millis: u64,
+    pub error: Option<String>,
+}
+
+/// The aggregated results of the reliability checks a contributor
+/// went through. The coordinator builds it from the answers to its
+/// challenges to decide on admission, and the contributor keeps its
+/// own to log the outcome
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ReliabilityReport {
+    pub results: Vec<ReliabilityCheckResult>,
+}
+
+impl ReliabilityReport {
+    /// Records a run of the check, merging it with the previous
+    /// runs of the same check
+    pub fn record(&mut self, check: ReliabilityCheck, elapsed: Duration, error: Option<String>) {
+        let elapsed_millis = elapsed.as_millis() as u64;
+        match self.results.iter_mut().find(|result| result.check == check) {
+            Some(result) => {
+                result.passed &= error.is_none();
+                result.runs += 1;
+                result.elapsed_millis += elapsed_millis;
+                result.error = result.error.take().or(error);
+            }
+            None => self.results.push(ReliabilityCheckResult {
+                check,
+                passed: error.is_none(),
+                runs: 1,
+                elapsed_millis,
+                error,
+            }),
+        }
+    }
+
+    /// Returns true if the check has been run at least once
+    pub fn contains(&self, check: ReliabilityCheck) -> bool {
+        self.results.iter().any(|result| result.check == check)
+    }
+
+    /// Returns the share of the checks which have passed,
+    /// from 0.0 to 1.0
+    pub fn score(&self) -> f64 {
+        if self.results.is_empty() {
+            return 0.0;
+        }
+        let passed = self.results.iter().filter(|result| result.passed).count();
+        passed as f64 / self.results.len() as f64
+    }
+
+    /// Returns the share of the checks which have passed,
+    /// as a percentage from 0 to 100
+    pub fn score_percent(&self) -> u8 {
+        (self.score() * 100.0).round() as u8
+    }
+
+    /// Encodes self as a JSON message to a vector of bytes
+    pub fn encode(&self) -> Result<Vec<u8>, serde_json::Error> {
+        serde_json::to_vec(self)
+    }
+
+    /// Decodes a JSON message from a slice of bytes into Self
+    pub fn decode(bytes: &[u8]) -> Result<Self, serde_json::Error> {
+        serde_json::from_slice(bytes)
+    }
+}
+
+#[test]
+fn report_aggregates_runs() {
+    let mut report = ReliabilityReport::default();
+    report.record(ReliabilityCheck::Latency, Duration::from_millis(10), None);
+    report.record(ReliabilityCheck::Latency, Duration::from_millis(20), None);
+    report.record(
+        ReliabilityCheck::Cpu,
+        Duration::from_millis(5),
+        Some("Timed out".to_string()),
+    );
+    report.record(ReliabilityCheck::Cpu, Duration::from_millis(5), None);
+
+    assert!(report.contains(ReliabilityCheck::Latency));
+    assert!(!report.contains(ReliabilityCheck::Bandwidth));
+    assert_eq!(report.results[0].runs, 2);
+    assert_eq!(report.results[0].elapsed_millis, 30);
+    assert!(!report.results[1].passed);
+    assert_eq!(report.results[1].error.as_deref(), Some("Timed out"));
+    assert_eq!(report.score(), 0.5);
+    assert_eq!(report.score_percent(), 50);
+
+    let decoded = ReliabilityReport::decode(&report.encode().unwrap()).unwrap();
+    assert_eq!(decoded, report);
+}
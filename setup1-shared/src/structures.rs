@@ -98,4 +98,65 @@ pub struct LockResponse {
 
     #[serde(alias = "responseContributionId")]
     pub response_contribution_id: u64,
+
+    /// The hex-encoded hash of the previous contribution, which the challenge
+    /// file begins with, for the participant to check the challenge they
+    /// download against, if known
+    #[serde(alias = "previousContributionHash", default, skip_serializing_if = "Option::is_none")]
+    pub previous_contribution_hash: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ContributorStatus, LockResponse};
+
+    #[test]
+    fn lock_response_wire_format() {
+        let lock_response = LockResponse {
+            chunk_id: 1,
+            contribution_id: 2,
+            locked: true,
+            participant_id: "aleo1contributor.contributor".to_string(),
+            previous_response_locator: "round_1/chunk_1/contribution_1.unverified".to_string(),
+            challenge_locator: "round_1/chunk_1/contribution_1.verified".to_string(),
+            response_locator: "round_1/chunk_1/contribution_2.unverified".to_string(),
+            response_chunk_id: 1,
+            response_contribution_id: 2,
+            previous_contribution_hash: None,
+        };
+        let json = r#"{"chunk_id":1,"contribution_id":2,"locked":true,"participant_id":"aleo1contributor.contributor","previous_response_locator":"round_1/chunk_1/contribution_1.unverified","challenge_locator":"round_1/chunk_1/contribution_1.verified","response_locator":"round_1/chunk_1/contribution_2.unverified","response_chunk_id":1,"response_contribution_id":2}"#;
+        assert_eq!(serde_json::to_string(&lock_response).unwrap(), json);
+        assert_eq!(serde_json::from_str::<LockResponse>(json).unwrap(), lock_response);
+
+        // The camelCase names of older coordinators are still accepted.
+        let camel_case_json = r#"{"chunkId":1,"contributionId":2,"locked":true,"participantId":"aleo1contributor.contributor","previousResponseLocator":"round_1/chunk_1/contribution_1.unverified","challengeLocator":"round_1/chunk_1/contribution_1.verified","responseLocator":"round_1/chunk_1/contribution_2.unverified","responseChunkId":1,"responseContributionId":2}"#;
+        assert_eq!(
+            serde_json::from_str::<LockResponse>(camel_case_json).unwrap(),
+            lock_response
+        );
+
+        // The previous contribution hash is only sent when it is known.
+        let lock_response = LockResponse {
+            previous_contribution_hash: Some("ab".repeat(64)),
+            ..lock_response
+        };
+        let json = serde_json::to_string(&lock_response).unwrap();
+        assert!(json.ends_with(&format!(r#","previous_contribution_hash":"{}"}}"#, "ab".repeat(64))));
+        assert_eq!(serde_json::from_str::<LockResponse>(&json).unwrap(), lock_response);
+    }
+
+    #[test]
+    fn contributor_status_wire_format() {
+        let statuses = [
+            (ContributorStatus::Queue(3, 10), r#"{"Queue":[3,10]}"#),
+            (ContributorStatus::Round, r#""Round""#),
+            (ContributorStatus::Finished, r#""Finished""#),
+            (ContributorStatus::Other, r#""Other""#),
+        ];
+        for (status, json) in statuses.iter() {
+            assert_eq!(serde_json::to_string(status).unwrap(), *json);
+            let decoded = serde_json::from_str::<ContributorStatus>(json).unwrap();
+            assert_eq!(serde_json::to_string(&decoded).unwrap(), *json);
+        }
+    }
 }
@@ -1,8 +1,9 @@
+use super::{new::CurveKind, Manifest};
 use phase2::{chunked_groth16::contribute as chunked_contribute, keypair::PublicKey};
-use setup_utils::Result;
 
 use snarkvm_curves::{bls12_377::Bls12_377, bw6_761::BW6_761};
 
+use anyhow::Context;
 use fs_err::OpenOptions;
 use gumdrop::Options;
 use memmap::MmapOptions;
@@ -26,31 +27,33 @@ pub struct ContributeOpts {
 
     #[options(help = "setup the inner or the outer circuit?")]
     pub is_inner: bool,
+    #[options(help = "read the circuit from the manifest written by `new --manifest`")]
+    pub manifest: Option<String>,
 }
 
-pub fn contribute<R: Rng + CryptoRng>(opts: &ContributeOpts, rng: &mut R) -> Result<()> {
+pub fn contribute<R: Rng + CryptoRng>(opts: &ContributeOpts, rng: &mut R) -> anyhow::Result<()> {
+    let circuit = Manifest::resolve_circuit(opts.manifest.as_deref(), opts.is_inner)?;
+
     let file = OpenOptions::new()
         .read(true)
         .write(true)
         .open(&opts.data)
-        .expect("could not open file for writing the new MPC parameters ");
+        .context("Could not open file for writing the new MPC parameters")?;
     let metadata = file.metadata()?;
     // extend the file by 1 pubkey
-    if opts.is_inner {
-        file.set_len(metadata.len() + PublicKey::<Bls12_377>::size() as u64)?;
-    } else {
-        file.set_len(metadata.len() + PublicKey::<BW6_761>::size() as u64)?;
+    match circuit.curve {
+        CurveKind::Bls12_377 => file.set_len(metadata.len() + PublicKey::<Bls12_377>::size() as u64)?,
+        CurveKind::BW6 => file.set_len(metadata.len() + PublicKey::<BW6_761>::size() as u64)?,
     }
     let mut file = unsafe {
         MmapOptions::new()
             .map_mut(file.file())
-            .expect("unable to create a memory map for input")
+            .context("Unable to create a memory map for input")?
     };
 
-    if opts.is_inner {
-        chunked_contribute::<Bls12_377, _>(&mut file, rng, opts.batch)?;
-    } else {
-        chunked_contribute::<BW6_761, _>(&mut file, rng, opts.batch)?;
+    match circuit.curve {
+        CurveKind::Bls12_377 => chunked_contribute::<Bls12_377, _>(&mut file, rng, opts.batch)?,
+        CurveKind::BW6 => chunked_contribute::<BW6_761, _>(&mut file, rng, opts.batch)?,
     }
 
     Ok(())
@@ -0,0 +1,60 @@
+use thiserror::Error;
+
+/// The errors of the setup2 commands which scripts may need to tell apart,
+/// each of which exits the process with its own code.
+#[derive(Debug, Error)]
+pub enum Setup2Error {
+    #[error("Could not read the phase1 transcript: {source}")]
+    Phase1Missing { path: String, source: std::io::Error },
+
+    #[error("The output file {path} already exists, pass --force to overwrite it or choose another path")]
+    OutputExists { path: String },
+
+    #[error(
+        "Phase 2 size {phase2_size} exceeds the {phase1_coefficients} coefficients (2^{phase1_size}) produced by Phase 1"
+    )]
+    Phase1TooSmall {
+        phase2_size: usize,
+        phase1_coefficients: usize,
+        phase1_size: u32,
+    },
+
+    #[error("The {circuit} circuit requires the {expected} curve, but {selected} was selected")]
+    CurveMismatch {
+        circuit: &'static str,
+        expected: &'static str,
+        selected: &'static str,
+    },
+}
+
+impl Setup2Error {
+    /// Returns the exit code of the process for this error. Exit code 1 is
+    /// left for any other error, and 2 for invalid usage.
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Setup2Error::Phase1Missing { .. } => 3,
+            Setup2Error::OutputExists { .. } => 4,
+            Setup2Error::Phase1TooSmall { .. } => 5,
+            Setup2Error::CurveMismatch { .. } => 6,
+        }
+    }
+}
+
+/// Returns the exit code of the process for the given error.
+pub fn exit_code(error: &anyhow::Error) -> i32 {
+    error.downcast_ref::<Setup2Error>().map_or(1, Setup2Error::exit_code)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exit_code() {
+        let error = Setup2Error::OutputExists {
+            path: "challenge".to_string(),
+        };
+        assert_eq!(4, exit_code(&error.into()));
+        assert_eq!(1, exit_code(&anyhow::anyhow!("any other error")));
+    }
+}
@@ -0,0 +1,135 @@
+use super::new::{circuit_from_str, Circuit, CurveKind};
+
+use anyhow::{anyhow, bail};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use tracing::info;
+
+/// The settings recorded in the manifest written next to a challenge file by `new --manifest`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Manifest {
+    /// The elliptic curve of the parameters.
+    pub curve_type: CurveKind,
+    /// The total number of coefficients (in powers of 2) which were created after processing phase 1.
+    pub phase1_size: u32,
+    /// The size of the phase 2 ceremony of the circuit.
+    pub phase2_size: usize,
+    /// The name of the circuit, as accepted by `circuit_from_str`.
+    pub circuit: String,
+    /// The name of the challenge file.
+    pub output: String,
+    /// The hex-encoded hash of the challenge file.
+    pub output_hash: String,
+}
+
+impl Manifest {
+    /// Reads the manifest at the given path, checking that its curve matches its circuit.
+    pub fn read<P: AsRef<Path>>(path: P) -> anyhow::Result<Self> {
+        let manifest: Self = serde_json::from_slice(&fs_err::read(path.as_ref())?)?;
+
+        let circuit = manifest.circuit()?;
+        if circuit.curve != manifest.curve_type {
+            bail!(
+                "The manifest records the {} circuit, which does not match the {} curve",
+                circuit.name,
+                manifest.curve_type.as_str()
+            );
+        }
+
+        Ok(manifest)
+    }
+
+    /// Writes the manifest to the given path.
+    pub fn write<P: AsRef<Path>>(&self, path: P) -> anyhow::Result<()> {
+        fs_err::write(path.as_ref(), serde_json::to_vec_pretty(self)?)?;
+        Ok(())
+    }
+
+    /// Returns the circuit of the manifest, from the circuits supported by `new`.
+    pub fn circuit(&self) -> anyhow::Result<&'static Circuit> {
+        circuit_from_str(&self.circuit).map_err(|error| anyhow!(error))
+    }
+
+    /// Returns the circuit to setup, from the manifest if one is given. Without a manifest,
+    /// this is the inner circuit if `--is-inner` was given, and the outer circuit otherwise.
+    ///
+    /// Returns an error if `--is-inner` was given with a manifest of another circuit.
+    pub fn resolve_circuit(manifest: Option<&str>, is_inner: bool) -> anyhow::Result<&'static Circuit> {
+        let manifest = match manifest {
+            Some(manifest) => Self::read(manifest)?,
+            None => return circuit_from_str(if is_inner { "inner" } else { "outer" }).map_err(|error| anyhow!(error)),
+        };
+        let circuit = manifest.circuit()?;
+        if is_inner && circuit.name != "inner" {
+            bail!(
+                "--is-inner conflicts with the manifest, which is for the {} circuit",
+                circuit.name
+            );
+        }
+        info!(
+            "Using the {} circuit from the manifest, with a phase1 size of 2^{} and a phase2 size of {}",
+            circuit.name, manifest.phase1_size, manifest.phase2_size
+        );
+        Ok(circuit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn manifest(curve_type: CurveKind, circuit: &str) -> Manifest {
+        Manifest {
+            curve_type,
+            phase1_size: 20,
+            phase2_size: 1024,
+            circuit: circuit.to_string(),
+            output: "challenge".to_string(),
+            output_hash: "00".repeat(64),
+        }
+    }
+
+    #[test]
+    fn test_manifest_round_trip() {
+        let path = std::env::temp_dir().join("setup2-test-manifest-round-trip.json");
+        let expected = manifest(CurveKind::BW6, "outer");
+        expected.write(&path).unwrap();
+
+        let manifest = Manifest::read(&path).unwrap();
+        assert_eq!(expected, manifest);
+        assert_eq!("outer", manifest.circuit().unwrap().name);
+
+        // Check that the curve type is written by the name `--curve-type` accepts.
+        let json: serde_json::Value = serde_json::from_slice(&fs_err::read(&path).unwrap()).unwrap();
+        assert_eq!("bw6", json["curve_type"]);
+
+        fs_err::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_manifest_circuit_mismatch() {
+        let path = std::env::temp_dir().join("setup2-test-manifest-circuit-mismatch.json");
+        manifest(CurveKind::Bls12_377, "outer").write(&path).unwrap();
+
+        assert!(Manifest::read(&path).is_err());
+
+        fs_err::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_resolve_circuit() {
+        let path = std::env::temp_dir().join("setup2-test-resolve-circuit.json");
+        manifest(CurveKind::BW6, "outer").write(&path).unwrap();
+        let path = path.to_string_lossy().to_string();
+
+        assert_eq!("inner", Manifest::resolve_circuit(None, true).unwrap().name);
+        assert_eq!("outer", Manifest::resolve_circuit(None, false).unwrap().name);
+        assert_eq!(
+            CurveKind::BW6,
+            Manifest::resolve_circuit(Some(&path), false).unwrap().curve
+        );
+        assert!(Manifest::resolve_circuit(Some(&path), true).is_err());
+
+        fs_err::remove_file(&path).unwrap();
+    }
+}
@@ -4,6 +4,12 @@ mod new;
 mod contribute;
 pub use contribute::{contribute, ContributeOpts};
 
+mod errors;
+pub use errors::{exit_code, Setup2Error};
+
+mod manifest;
+pub use manifest::Manifest;
+
 mod verify;
 pub use verify::{verify, VerifyOpts};
 
@@ -1,5 +1,6 @@
+use super::{Manifest, Setup2Error};
 use phase2::parameters::{circuit_to_qap, MPCParameters};
-use setup_utils::{log_2, CheckForCorrectness, Groth16Params, UseCompression};
+use setup_utils::{calculate_hash, CheckForCorrectness, Groth16Params, UseCompression};
 use snarkvm_algorithms::{SNARK, SRS};
 use snarkvm_curves::{bls12_377::Bls12_377, bw6_761::BW6_761, PairingEngine};
 use snarkvm_dpc::{
@@ -9,11 +10,16 @@ use snarkvm_dpc::{
 use snarkvm_fields::Field;
 use snarkvm_r1cs::{ConstraintCounter, ConstraintSynthesizer};
 
+use anyhow::{anyhow, bail, Context};
 use fs_err::OpenOptions;
 use gumdrop::Options;
+use indicatif::{ProgressBar, ProgressDrawTarget, ProgressStyle};
 use memmap::MmapOptions;
 use rand::{RngCore, SeedableRng};
 use rand_chacha::ChaChaRng;
+use serde::{Deserialize, Serialize};
+use std::{io, path::PathBuf};
+use tracing::info;
 
 type AleoInner = <Testnet2Parameters as Parameters>::InnerCurve;
 type AleoOuter = <Testnet2Parameters as Parameters>::OuterCurve;
@@ -25,12 +31,24 @@ const COMPRESSION: UseCompression = UseCompression::No;
 pub const SEED_LENGTH: usize = 32;
 pub type Seed = [u8; SEED_LENGTH];
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum CurveKind {
+    #[serde(rename = "bls12_377")]
     Bls12_377,
+    #[serde(rename = "bw6")]
     BW6,
 }
 
+impl CurveKind {
+    /// Returns the name of the curve, as accepted by `curve_from_str`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CurveKind::Bls12_377 => "bls12_377",
+            CurveKind::BW6 => "bw6",
+        }
+    }
+}
+
 pub fn curve_from_str(src: &str) -> std::result::Result<CurveKind, String> {
     let curve = match src.to_lowercase().as_str() {
         "bls12_377" => CurveKind::Bls12_377,
@@ -40,6 +58,40 @@ pub fn curve_from_str(src: &str) -> std::result::Result<CurveKind, String> {
     Ok(curve)
 }
 
+/// A circuit which `new` can create the parameters of.
+#[derive(Debug)]
+pub struct Circuit {
+    /// The name of the circuit, as accepted by `circuit_from_str`.
+    pub name: &'static str,
+    /// The curve the circuit is defined over.
+    pub curve: CurveKind,
+    /// Synthesizes a blank instance of the circuit and generates its parameters.
+    generate: fn(&NewOpts) -> anyhow::Result<()>,
+}
+
+/// The circuits supported by `new`, `contribute` and `verify`. A new circuit over
+/// one of the supported curves only needs to be added here.
+const CIRCUITS: &[Circuit] = &[
+    Circuit {
+        name: "inner",
+        curve: CurveKind::Bls12_377,
+        generate: generate_inner,
+    },
+    Circuit {
+        name: "outer",
+        curve: CurveKind::BW6,
+        generate: generate_outer,
+    },
+];
+
+pub fn circuit_from_str(src: &str) -> std::result::Result<&'static Circuit, String> {
+    let name = src.to_lowercase();
+    CIRCUITS.iter().find(|circuit| circuit.name == name).ok_or_else(|| {
+        let names: Vec<_> = CIRCUITS.iter().map(|circuit| circuit.name).collect();
+        format!("unsupported circuit, expected one of {}.", names.join(", "))
+    })
+}
+
 #[derive(Debug, Options, Clone)]
 pub struct NewOpts {
     help: bool,
@@ -49,56 +101,163 @@ pub struct NewOpts {
     pub phase1_size: u32,
     #[options(help = "the challenge file name to be created", default = "challenge")]
     pub output: String,
+    #[options(help = "the directory to create the challenge file in, if not the current directory")]
+    pub output_dir: Option<String>,
+    #[options(help = "write a JSON manifest describing the challenge file next to it")]
+    pub manifest: bool,
+    #[options(help = "overwrite the challenge file if it already exists")]
+    pub force: bool,
 
     #[options(
-        help = "the elliptic curve to use",
-        default = "bls12_377",
+        help = "the elliptic curve to use, which must match the circuit (bls12_377 for inner, bw6 for outer)",
         parse(try_from_str = "curve_from_str")
     )]
-    pub curve_type: CurveKind,
+    pub curve_type: Option<CurveKind>,
 
     #[options(help = "setup the inner or the outer circuit?")]
     pub is_inner: bool,
+
+    #[options(
+        help = "the name of the circuit to setup (inner or outer), in place of --is-inner",
+        parse(try_from_str = "circuit_from_str")
+    )]
+    pub circuit: Option<&'static Circuit>,
+
+    #[options(help = "fully check the phase1 transcript for correctness while reading it")]
+    pub check_correctness: bool,
+
+    #[options(help = "do not show progress while generating the parameters")]
+    pub quiet: bool,
+
+    #[options(
+        help = "only check that the phase2 size in the manifest matches the circuit (for the outer circuit, this still runs the setup and a proof of the inner circuit)"
+    )]
+    pub verify_only: bool,
+}
+
+impl NewOpts {
+    /// Returns the circuit to generate the parameters of.
+    ///
+    /// Without `--circuit`, this is the inner circuit if `--is-inner` was given,
+    /// and the outer circuit otherwise.
+    pub fn circuit(&self) -> anyhow::Result<&'static Circuit> {
+        match self.circuit {
+            Some(circuit) if self.is_inner && circuit.name != "inner" => {
+                bail!("--is-inner cannot be given with the {} circuit", circuit.name)
+            }
+            Some(circuit) => Ok(circuit),
+            None => Manifest::resolve_circuit(None, self.is_inner),
+        }
+    }
+
+    /// Returns the curve to generate the parameters over.
+    ///
+    /// Each circuit is defined over its own curve, so this returns an error
+    /// if the given curve type does not match the circuit.
+    pub fn curve(&self) -> anyhow::Result<CurveKind> {
+        let circuit = self.circuit()?;
+        match self.curve_type {
+            Some(curve_type) if curve_type != circuit.curve => Err(Setup2Error::CurveMismatch {
+                circuit: circuit.name,
+                expected: circuit.curve.as_str(),
+                selected: curve_type.as_str(),
+            }
+            .into()),
+            _ => Ok(circuit.curve),
+        }
+    }
+
+    /// Returns the correctness check to perform when reading the phase1 transcript.
+    ///
+    /// By default, no check is performed, as the transcript has been processed by the coordinator.
+    pub fn check_for_correctness(&self) -> CheckForCorrectness {
+        match self.check_correctness {
+            true => CheckForCorrectness::Full,
+            false => CheckForCorrectness::No,
+        }
+    }
+
+    /// Returns the path of the challenge file to be created.
+    pub fn output_path(&self) -> PathBuf {
+        match &self.output_dir {
+            Some(output_dir) => PathBuf::from(output_dir).join(&self.output),
+            None => PathBuf::from(&self.output),
+        }
+    }
+
+    /// Returns the number of coefficients produced by Phase 1, which is `2^phase1_size`.
+    ///
+    /// This returns an error if the number of coefficients does not fit in a `usize`.
+    pub fn phase1_coefficients(&self) -> anyhow::Result<usize> {
+        phase1_coefficients(self.phase1_size)
+    }
+
+    /// Returns the path of the manifest, which is the challenge file path with a `.json` extension.
+    pub fn manifest_path(&self) -> PathBuf {
+        let mut manifest_path = self.output_path().into_os_string();
+        manifest_path.push(".json");
+        manifest_path.into()
+    }
 }
 
 pub fn new(opt: &NewOpts) -> anyhow::Result<()> {
-    if opt.is_inner {
-        let circuit = InnerCircuit::<Testnet2Parameters>::blank();
-        generate_params::<AleoInner, ZexeInner, _>(opt, circuit)
-    } else {
-        let mut seed: Seed = [0; SEED_LENGTH];
-        rand::thread_rng().fill_bytes(&mut seed[..]);
-        let rng = &mut ChaChaRng::from_seed(seed);
-        let dpc = Testnet2DPC::load(false)?;
-
-        let noop_circuit = dpc
-            .noop_program
-            .find_circuit_by_index(0)
-            .ok_or(DPCError::MissingNoopCircuit)?;
-        let private_program_input = dpc.noop_program.execute_blank(noop_circuit.circuit_id())?;
-
-        let inner_snark_parameters = <Testnet2Parameters as Parameters>::InnerSNARK::setup(
-            &InnerCircuit::<Testnet2Parameters>::blank(),
-            &mut SRS::CircuitSpecific(rng),
-        )?;
-
-        let inner_snark_vk: <<Testnet2Parameters as Parameters>::InnerSNARK as SNARK>::VerifyingKey =
-            inner_snark_parameters.1.clone().into();
-        let inner_snark_proof = <Testnet2Parameters as Parameters>::InnerSNARK::prove(
-            &inner_snark_parameters.0,
-            &InnerCircuit::<Testnet2Parameters>::blank(),
-            rng,
-        )?;
-
-        let circuit =
-            OuterCircuit::<Testnet2Parameters>::blank(inner_snark_vk, inner_snark_proof, private_program_input);
-        generate_params::<AleoOuter, ZexeOuter, _>(opt, circuit)
-    }
-}
-
-/// Returns the number of powers required for the Phase 2 ceremony
-/// = log2(aux + inputs + constraints)
-fn ceremony_size<F: Field, C: Clone + ConstraintSynthesizer<F>>(circuit: &C) -> usize {
+    // Check the phase1 size before any circuit is synthesized.
+    opt.phase1_coefficients()?;
+
+    // Check that the curve matches the circuit.
+    opt.curve()?;
+
+    (opt.circuit()?.generate)(opt)
+}
+
+/// Generates the parameters of the inner circuit of Testnet2.
+fn generate_inner(opt: &NewOpts) -> anyhow::Result<()> {
+    let circuit = InnerCircuit::<Testnet2Parameters>::blank();
+    generate_params::<AleoInner, ZexeInner, _>(opt, circuit)
+}
+
+/// Generates the parameters of the outer circuit of Testnet2, which
+/// verifies a proof of the inner circuit.
+///
+/// The outer circuit can only be synthesized from a proof of the inner circuit,
+/// so this runs the setup of the inner circuit and proves it before the outer circuit
+/// is built. This cost is also paid by `--verify-only`, which counts the constraints
+/// of the outer circuit.
+fn generate_outer(opt: &NewOpts) -> anyhow::Result<()> {
+    let mut seed: Seed = [0; SEED_LENGTH];
+    rand::thread_rng().fill_bytes(&mut seed[..]);
+    let rng = &mut ChaChaRng::from_seed(seed);
+    let dpc = Testnet2DPC::load(false)?;
+
+    let noop_circuit = dpc
+        .noop_program
+        .find_circuit_by_index(0)
+        .ok_or(DPCError::MissingNoopCircuit)?;
+    let private_program_input = dpc.noop_program.execute_blank(noop_circuit.circuit_id())?;
+
+    let inner_snark_parameters = <Testnet2Parameters as Parameters>::InnerSNARK::setup(
+        &InnerCircuit::<Testnet2Parameters>::blank(),
+        &mut SRS::CircuitSpecific(rng),
+    )?;
+
+    let inner_snark_vk: <<Testnet2Parameters as Parameters>::InnerSNARK as SNARK>::VerifyingKey =
+        inner_snark_parameters.1.clone().into();
+    let inner_snark_proof = <Testnet2Parameters as Parameters>::InnerSNARK::prove(
+        &inner_snark_parameters.0,
+        &InnerCircuit::<Testnet2Parameters>::blank(),
+        rng,
+    )?;
+
+    let circuit = OuterCircuit::<Testnet2Parameters>::blank(inner_snark_vk, inner_snark_proof, private_program_input);
+    generate_params::<AleoOuter, ZexeOuter, _>(opt, circuit)
+}
+
+/// Returns the size of the Phase 2 ceremony for the given circuit, which is the
+/// smallest power of two that is at least max(constraints, aux + inputs + 1).
+fn ceremony_size<F: Field, C: Clone + ConstraintSynthesizer<F>>(
+    circuit: &C,
+    phase1_size: u32,
+) -> anyhow::Result<usize> {
     let mut counter = ConstraintCounter {
         num_public_variables: 0,
         num_private_variables: 0,
@@ -107,58 +266,323 @@ fn ceremony_size<F: Field, C: Clone + ConstraintSynthesizer<F>>(circuit: &C) ->
     circuit
         .clone()
         .generate_constraints(&mut counter)
-        .expect("could not calculate number of required constraints");
-    let phase2_size = std::cmp::max(
+        .map_err(|error| anyhow!("Could not calculate the number of required constraints: {}", error))?;
+    let required_size = std::cmp::max(
         counter.num_constraints,
         counter.num_private_variables + counter.num_public_variables + 1,
     );
-    let power = log_2(phase2_size) as u32;
+    let phase2_size = round_phase2_size(required_size, phase1_size)?;
+    info!(
+        "The circuit requires a Phase 2 size of {}, which is rounded up to {}",
+        required_size, phase2_size
+    );
+
+    Ok(phase2_size)
+}
 
-    // get the nearest power of 2
-    if phase2_size < 2usize.pow(power) {
-        2usize.pow(power + 1)
-    } else {
-        phase2_size
+/// Rounds the required size up to the nearest power of two, and checks that
+/// Phase 1 produced enough coefficients for a Phase 2 ceremony of that size.
+fn round_phase2_size(required_size: usize, phase1_size: u32) -> anyhow::Result<usize> {
+    let phase2_size = required_size.checked_next_power_of_two().ok_or_else(|| {
+        anyhow!(
+            "Phase 2 size {} overflows when rounded to a power of two",
+            required_size
+        )
+    })?;
+    let phase1_coefficients = phase1_coefficients(phase1_size)?;
+    if phase2_size > phase1_coefficients {
+        return Err(Setup2Error::Phase1TooSmall {
+            phase2_size,
+            phase1_coefficients,
+            phase1_size,
+        }
+        .into());
     }
+    Ok(phase2_size)
+}
+
+/// Returns the number of coefficients produced by a Phase 1 of the given size,
+/// or an error if `2^phase1_size` does not fit in a `usize`.
+fn phase1_coefficients(phase1_size: u32) -> anyhow::Result<usize> {
+    2usize.checked_pow(phase1_size).ok_or_else(|| {
+        anyhow!(
+            "Phase 1 size 2^{} is too large, it must be less than {}",
+            phase1_size,
+            usize::BITS
+        )
+    })
 }
 
 pub fn generate_params<Aleo: PairingEngine, Zexe: PairingEngine, C: Clone + ConstraintSynthesizer<Aleo::Fr>>(
     opt: &NewOpts,
     circuit: C,
 ) -> anyhow::Result<()> {
-    let phase1_transcript = OpenOptions::new()
-        .read(true)
-        .write(true)
-        .open(&opt.phase1)
-        .expect("could not read phase 1 transcript file");
-    let mut phase1_transcript = unsafe {
+    if opt.verify_only {
+        return check_manifest(opt, ceremony_size(&circuit, opt.phase1_size)?);
+    }
+
+    let progress_bar = progress_bar(opt);
+
+    // Check that Phase 1 is large enough for the circuit before reading its transcript.
+    progress_bar.set_message("Counting constraints");
+    let phase2_size = ceremony_size(&circuit, opt.phase1_size)?;
+
+    // The phase 1 transcript is only read, so it is opened and mapped read-only.
+    let phase1_transcript = match OpenOptions::new().read(true).open(&opt.phase1) {
+        Ok(phase1_transcript) => phase1_transcript,
+        Err(source) => {
+            return Err(Setup2Error::Phase1Missing {
+                path: opt.phase1.clone(),
+                source,
+            }
+            .into());
+        }
+    };
+    let phase1_transcript = unsafe {
         MmapOptions::new()
-            .map_mut(phase1_transcript.file())
-            .expect("unable to create a memory map for input")
+            .map(phase1_transcript.file())
+            .context("Unable to create a memory map for the phase 1 transcript")?
     };
-    let mut output = OpenOptions::new()
-        .read(false)
-        .write(true)
-        .create_new(true)
-        .open(&opt.output)
-        .expect("could not open file for writing the MPC parameters ");
 
-    let phase2_size = ceremony_size(&circuit);
+    let mut output = open_output(opt)?;
+
+    progress_bar.set_message("Synthesizing the circuit");
     let keypair = circuit_to_qap::<Aleo, Zexe, _>(circuit)?;
 
     // Read `num_constraints` Lagrange coefficients from the Phase1 Powers of Tau which were
     // prepared for this step. This will fail if Phase 1 was too small.
-    let phase1 = Groth16Params::<Zexe>::read(
-        &mut phase1_transcript,
+    progress_bar.set_message("Reading the phase1 transcript");
+    progress_bar.set_length(5 * phase2_size as u64 - 1);
+    progress_bar.reset();
+    let phase1 = Groth16Params::<Zexe>::read_with_progress(
+        &phase1_transcript,
         COMPRESSION,
-        CheckForCorrectness::No, // No need to check for correctness, since this has been processed by the coordinator.
-        2usize.pow(opt.phase1_size),
+        opt.check_for_correctness(),
+        opt.phase1_coefficients()?,
         phase2_size,
+        &|coefficients| progress_bar.inc(coefficients),
     )?;
 
     // Generate the initial transcript
-    let mpc = MPCParameters::new(keypair, phase1)?;
+    progress_bar.set_message("Generating the MPC parameters");
+    progress_bar.set_length(4 * keypair.at.len() as u64);
+    progress_bar.reset();
+    let mpc = MPCParameters::new_with_progress(keypair, phase1, &|polynomials| progress_bar.inc(polynomials))?;
+
+    progress_bar.set_message("Writing the challenge file");
     mpc.write(&mut output)?;
 
+    if opt.manifest {
+        write_manifest(opt, phase2_size)?;
+    }
+
+    progress_bar.finish_with_message("Done");
+
     Ok(())
 }
+
+/// Opens the challenge file for writing. The challenge file is only
+/// overwritten if `--force` was given.
+fn open_output(opt: &NewOpts) -> anyhow::Result<fs_err::File> {
+    match OpenOptions::new()
+        .read(false)
+        .write(true)
+        .create(opt.force)
+        .truncate(opt.force)
+        .create_new(!opt.force)
+        .open(opt.output_path())
+    {
+        Ok(output) => Ok(output),
+        Err(error) if error.kind() == io::ErrorKind::AlreadyExists => Err(Setup2Error::OutputExists {
+            path: opt.output_path().display().to_string(),
+        }
+        .into()),
+        Err(error) => Err(error).context("Could not open the file for writing the MPC parameters"),
+    }
+}
+
+/// Checks that the phase2 size recorded in the manifest of the challenge file
+/// matches the phase2 size of the circuit.
+fn check_manifest(opt: &NewOpts, phase2_size: usize) -> anyhow::Result<()> {
+    let manifest = Manifest::read(opt.manifest_path())?;
+    if manifest.phase2_size != phase2_size {
+        bail!(
+            "The manifest records a phase2 size of {}, but the circuit requires {}",
+            manifest.phase2_size,
+            phase2_size
+        );
+    }
+    info!("The manifest matches the circuit with a phase2 size of {}", phase2_size);
+
+    Ok(())
+}
+
+/// Returns a progress bar over the coefficients processed by `generate_params`, which
+/// is only drawn if stdout is a terminal and `--quiet` was not given.
+///
+/// The length of the progress bar is set by each step which processes coefficients.
+fn progress_bar(opt: &NewOpts) -> ProgressBar {
+    if opt.quiet {
+        return ProgressBar::hidden();
+    }
+
+    let progress_bar = ProgressBar::new(0);
+    progress_bar.set_draw_target(ProgressDrawTarget::stdout());
+    progress_bar.set_style(
+        ProgressStyle::default_bar().template("[{elapsed_precise}] {bar:40.cyan/blue} {pos}/{len} {msg} (ETA {eta})"),
+    );
+    progress_bar
+}
+
+/// Writes a JSON manifest recording the settings which produced the challenge file,
+/// so that later steps of the ceremony do not need them to be specified again.
+fn write_manifest(opt: &NewOpts, phase2_size: usize) -> anyhow::Result<()> {
+    let output_hash = calculate_hash(&fs_err::read(opt.output_path())?);
+    let manifest = Manifest {
+        curve_type: opt.curve()?,
+        phase1_size: opt.phase1_size,
+        phase2_size,
+        circuit: opt.circuit()?.name.to_string(),
+        output: opt.output.clone(),
+        output_hash: hex::encode(output_hash),
+    };
+    manifest.write(opt.manifest_path())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_opts(is_inner: bool, curve_type: Option<CurveKind>) -> NewOpts {
+        NewOpts {
+            is_inner,
+            curve_type,
+            ..NewOpts::parse_args_default::<&str>(&[]).unwrap()
+        }
+    }
+
+    #[test]
+    fn test_curve_matches_circuit() {
+        assert_eq!(CurveKind::Bls12_377, new_opts(true, None).curve().unwrap());
+        assert_eq!(CurveKind::BW6, new_opts(false, None).curve().unwrap());
+        assert_eq!(
+            CurveKind::Bls12_377,
+            new_opts(true, Some(CurveKind::Bls12_377)).curve().unwrap()
+        );
+        assert_eq!(CurveKind::BW6, new_opts(false, Some(CurveKind::BW6)).curve().unwrap());
+    }
+
+    #[test]
+    fn test_circuit_from_str() {
+        assert_eq!("inner", circuit_from_str("Inner").unwrap().name);
+        assert_eq!(CurveKind::BW6, circuit_from_str("outer").unwrap().curve);
+        assert!(circuit_from_str("posw").is_err());
+
+        // Check that the circuit name takes the place of `--is-inner`.
+        assert_eq!("outer", new_opts(false, None).circuit().unwrap().name);
+        let mut opts = new_opts(false, Some(CurveKind::Bls12_377));
+        opts.circuit = Some(circuit_from_str("inner").unwrap());
+        assert_eq!(CurveKind::Bls12_377, opts.curve().unwrap());
+
+        // Check that `--is-inner` cannot be given with another circuit.
+        opts.is_inner = true;
+        opts.circuit = Some(circuit_from_str("outer").unwrap());
+        assert!(opts.circuit().is_err());
+    }
+
+    #[test]
+    fn test_check_for_correctness() {
+        let mut opts = new_opts(true, None);
+        assert_eq!(CheckForCorrectness::No, opts.check_for_correctness());
+
+        opts.check_correctness = true;
+        assert_eq!(CheckForCorrectness::Full, opts.check_for_correctness());
+    }
+
+    #[test]
+    fn test_check_manifest() {
+        let output_dir = std::env::temp_dir().join("setup2-test-check-manifest");
+        fs_err::create_dir_all(&output_dir).unwrap();
+
+        let mut opts = new_opts(true, None);
+        opts.output_dir = Some(output_dir.to_string_lossy().to_string());
+        let manifest = Manifest {
+            curve_type: CurveKind::Bls12_377,
+            phase1_size: 20,
+            phase2_size: 1024,
+            circuit: "inner".to_string(),
+            output: opts.output.clone(),
+            output_hash: "00".repeat(64),
+        };
+        manifest.write(opts.manifest_path()).unwrap();
+
+        assert!(check_manifest(&opts, 1024).is_ok());
+        assert!(check_manifest(&opts, 2048).is_err());
+
+        fs_err::remove_dir_all(&output_dir).unwrap();
+    }
+
+    #[test]
+    fn test_open_output_force() {
+        let output_dir = std::env::temp_dir().join("setup2-test-open-output");
+        fs_err::create_dir_all(&output_dir).unwrap();
+
+        let mut opts = new_opts(true, None);
+        opts.output_dir = Some(output_dir.to_string_lossy().to_string());
+        fs_err::write(opts.output_path(), b"previous attempt").unwrap();
+
+        // Check that an existing challenge file is kept without `--force`.
+        let error = open_output(&opts).unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<Setup2Error>(),
+            Some(Setup2Error::OutputExists { .. })
+        ));
+        assert_eq!(b"previous attempt".to_vec(), fs_err::read(opts.output_path()).unwrap());
+
+        // Check that an existing challenge file is truncated with `--force`.
+        opts.force = true;
+        open_output(&opts).unwrap();
+        assert!(fs_err::read(opts.output_path()).unwrap().is_empty());
+
+        fs_err::remove_dir_all(&output_dir).unwrap();
+    }
+
+    #[test]
+    fn test_curve_mismatch() {
+        let error = new_opts(true, Some(CurveKind::BW6)).curve().unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<Setup2Error>(),
+            Some(Setup2Error::CurveMismatch { .. })
+        ));
+        assert!(new_opts(false, Some(CurveKind::Bls12_377)).curve().is_err());
+    }
+
+    #[test]
+    fn test_round_phase2_size_exact_power_of_two() {
+        assert_eq!(1024, round_phase2_size(1024, 10).unwrap());
+        assert_eq!(1, round_phase2_size(1, 0).unwrap());
+    }
+
+    #[test]
+    fn test_round_phase2_size_rounds_up() {
+        assert_eq!(1024, round_phase2_size(1023, 10).unwrap());
+        assert_eq!(2048, round_phase2_size(1025, 11).unwrap());
+    }
+
+    #[test]
+    fn test_round_phase2_size_exceeds_phase1() {
+        let error = round_phase2_size(1025, 10).unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<Setup2Error>(),
+            Some(Setup2Error::Phase1TooSmall { phase2_size: 2048, .. })
+        ));
+    }
+
+    #[test]
+    fn test_phase1_size_overflow() {
+        assert_eq!(1 << 28, phase1_coefficients(28).unwrap());
+        assert!(phase1_coefficients(usize::BITS).is_err());
+        assert!(round_phase2_size(1024, usize::BITS).is_err());
+        assert!(round_phase2_size(usize::MAX, 10).is_err());
+    }
+}
@@ -1,8 +1,9 @@
+use super::{new::CurveKind, Manifest};
 use phase2::chunked_groth16::verify as chunked_verify;
-use setup_utils::Result;
 
 use snarkvm_curves::{bls12_377::Bls12_377, bw6_761::BW6_761};
 
+use anyhow::Context;
 use fs_err::OpenOptions;
 use gumdrop::Options;
 use memmap::MmapOptions;
@@ -19,33 +20,36 @@ pub struct VerifyOpts {
     pub batch: usize,
     #[options(help = "setup the inner or the outer circuit?")]
     pub is_inner: bool,
+    #[options(help = "read the circuit from the manifest written by `new --manifest`")]
+    pub manifest: Option<String>,
 }
 
-pub fn verify(opts: &VerifyOpts) -> Result<()> {
+pub fn verify(opts: &VerifyOpts) -> anyhow::Result<()> {
+    let circuit = Manifest::resolve_circuit(opts.manifest.as_deref(), opts.is_inner)?;
+
     let before = OpenOptions::new()
         .read(true)
         .write(true)
         .open(&opts.before)
-        .expect("could not read the previous participant's MPC transcript file");
+        .context("Could not read the previous participant's MPC transcript file")?;
     let mut before = unsafe {
         MmapOptions::new()
             .map_mut(before.file())
-            .expect("unable to create a memory map for input")
+            .context("Unable to create a memory map for input")?
     };
     let after = OpenOptions::new()
         .read(true)
         .write(true)
         .open(&opts.after)
-        .expect("could not read the previous participant's MPC transcript file");
+        .context("Could not read the previous participant's MPC transcript file")?;
     let mut after = unsafe {
         MmapOptions::new()
             .map_mut(after.file())
-            .expect("unable to create a memory map for input")
+            .context("Unable to create a memory map for input")?
     };
-    if opts.is_inner {
-        chunked_verify::<Bls12_377>(&mut before, &mut after, opts.batch)?;
-    } else {
-        chunked_verify::<BW6_761>(&mut before, &mut after, opts.batch)?;
+    match circuit.curve {
+        CurveKind::Bls12_377 => chunked_verify::<Bls12_377>(&mut before, &mut after, opts.batch)?,
+        CurveKind::BW6 => chunked_verify::<BW6_761>(&mut before, &mut after, opts.batch)?,
     }
     Ok(())
 }
@@ -29,28 +29,30 @@ cfg_if! {
 
             let now = Instant::now();
             let res = match command {
-                Command::New(ref opt) => new(&opt).unwrap(),
+                Command::New(ref opt) => new(&opt),
                 Command::Contribute(ref opt) => {
                     // contribute to the randomness
                     let mut rng = get_rng(&user_system_randomness());
-                    contribute(&opt, &mut rng).unwrap()
+                    contribute(&opt, &mut rng)
                 }
-                Command::Beacon(ref opt) => {
+                Command::Beacon(ref opt) => match hex::decode(&opt.beacon_hash) {
                     // use the beacon's randomness
-                    let beacon_hash = hex::decode(&opt.beacon_hash).expect("could not hex decode beacon hash");
-                    let mut rng = get_rng(&beacon_randomness(from_slice(&beacon_hash)));
-                    contribute(&opt, &mut rng).unwrap()
-                }
-                Command::Verify(ref opt) => verify(&opt).unwrap(),
+                    Ok(beacon_hash) => {
+                        let mut rng = get_rng(&beacon_randomness(from_slice(&beacon_hash)));
+                        contribute(&opt, &mut rng)
+                    }
+                    Err(error) => Err(anyhow::anyhow!("Could not hex decode the beacon hash: {}", error)),
+                },
+                Command::Verify(ref opt) => verify(&opt),
             };
 
+            if let Err(error) = res {
+                eprintln!("Error: {:#}", error);
+                process::exit(exit_code(&error));
+            }
+
             let new_now = Instant::now();
-            println!(
-                "Executing {:?} took: {:?}. Result {:?}",
-                opts,
-                new_now.duration_since(now),
-                res,
-            );
+            println!("Executing {:?} took: {:?}", opts, new_now.duration_since(now));
         }
     }
 }